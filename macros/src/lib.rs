@@ -0,0 +1,135 @@
+#![deny(missing_docs)]
+
+//! Macros for [`leptos_declarative`](https://docs.rs/leptos_declarative).
+//!
+//! Please use them through `leptos_declarative`, which re-exports them.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+  parse_macro_input,
+  Data,
+  DeriveInput,
+  Fields,
+};
+
+/// Implements `PortalId` for a type, so it can be used as the `id` of a
+/// portal.
+///
+/// This is all marker structs, such as `struct MyPortal;`, need to be used as
+/// ids. For unit structs, [`Hash`], [`PartialEq`] and [`Eq`] are implemented
+/// as well, since all of their values are equal, so they must not be derived
+/// too. Other types must derive them.
+#[proc_macro_derive(PortalId)]
+pub fn derive_portal_id(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  portal_id(input).into()
+}
+
+fn portal_id(input: DeriveInput) -> TokenStream2 {
+  let ident = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  let is_unit = matches!(
+    &input.data,
+    Data::Struct(data) if matches!(data.fields, Fields::Unit)
+  );
+
+  let marker_impls = is_unit.then(|| {
+    quote! {
+      impl #impl_generics ::std::cmp::PartialEq for #ident #ty_generics
+      #where_clause
+      {
+        fn eq(&self, _: &Self) -> bool {
+          true
+        }
+      }
+
+      impl #impl_generics ::std::cmp::Eq for #ident #ty_generics
+      #where_clause
+      {}
+
+      impl #impl_generics ::std::hash::Hash for #ident #ty_generics
+      #where_clause
+      {
+        fn hash<H: ::std::hash::Hasher>(&self, _: &mut H) {}
+      }
+    }
+  });
+
+  quote! {
+    #marker_impls
+
+    impl #impl_generics ::leptos_declarative::portal::PortalId
+      for #ident #ty_generics
+    #where_clause
+    {}
+  }
+}
+
+/// Implements `PortalId` for an enum, so each variant can be used as the `id`
+/// of a portal.
+///
+/// The enum must also derive [`Hash`], [`PartialEq`] and [`Eq`]. Each variant
+/// is named `Enum::Variant` in the portal inspector, and the enum implements
+/// [`Display`](std::fmt::Display) with the same name.
+#[proc_macro_derive(PortalIds)]
+pub fn derive_portal_ids(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  portal_ids(input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+fn portal_ids(input: DeriveInput) -> syn::Result<TokenStream2> {
+  let Data::Enum(data) = &input.data else {
+    return Err(syn::Error::new_spanned(
+      &input.ident,
+      "`PortalIds` can only be derived for enums",
+    ));
+  };
+
+  let ident = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  let arms = data.variants.iter().map(|variant| {
+    let variant_ident = &variant.ident;
+    let name = format!("{ident}::{variant_ident}");
+
+    let pattern = match &variant.fields {
+      Fields::Named(_) => quote! { Self::#variant_ident { .. } },
+      Fields::Unnamed(_) => quote! { Self::#variant_ident(..) },
+      Fields::Unit => quote! { Self::#variant_ident },
+    };
+
+    quote! { #pattern => #name, }
+  });
+
+  Ok(quote! {
+    impl #impl_generics ::std::fmt::Display for #ident #ty_generics
+    #where_clause
+    {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let name = match self {
+          #(#arms)*
+        };
+
+        f.write_str(name)
+      }
+    }
+
+    impl #impl_generics ::leptos_declarative::portal::PortalId
+      for #ident #ty_generics
+    #where_clause
+    {
+      fn debug_name(&self) -> ::std::string::String {
+        ::std::string::ToString::to_string(self)
+      }
+    }
+  })
+}
@@ -0,0 +1,295 @@
+//! Animated keyed lists for [`leptos`].
+//!
+//! Please refer to [`AnimatedFor`] for usage examples.
+
+use crate::{
+  reduced_motion::use_reduced_motion,
+  transition_group::LeavingList,
+  util::{
+    set_timeout,
+    try_window,
+  },
+};
+use leptos::*;
+use std::{
+  cell::RefCell,
+  hash::Hash,
+  rc::Rc,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <ul>
+      <AnimatedFor
+        each=todos
+        key=|todo| todo.id
+        view=|cx, todo| view! { cx, <span>{todo.title}</span> }
+        tag="li"
+        duration=Duration::from_millis(200)
+        easing="ease-out"
+      />
+    </ul>
+  }
+}
+
+/// Renders a keyed list, like leptos' [`For`], animating items which are
+/// added, removed or moved.
+///
+/// Each item is wrapped in an element, which fades in when the item is
+/// added, fades out before being removed, and slides from it's previous
+/// position to it's new one when the list is reordered, using the
+/// [FLIP](https://aerotwist.com/blog/flip-your-animations/) technique.
+///
+/// Items are only animated in the browser, and not on the initial render
+/// or while the user prefers reduced motion.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (todos, _) = create_signal(cx, vec![(0, "Write docs")]);
+///
+/// view! { cx,
+/// <ul>
+///   <AnimatedFor
+///     each=move || todos.get()
+///     key=|todo| todo.0
+///     view=|cx, todo| view! { cx, <span>{todo.1}</span> }
+///     tag="li"
+///     duration=Duration::from_millis(200)
+///   />
+/// </ul>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn AnimatedFor<IF, I, T, EF, N, KF, K>(
+  cx: Scope,
+  /// Returns the items to render. This should be reactive.
+  each: IF,
+  /// Returns a key which uniquely identifies an item, so items are only
+  /// re-rendered when they are added.
+  key: KF,
+  /// Renders a single item.
+  view: EF,
+  /// The tag of the element wrapping each item. Defaults to `div`.
+  #[prop(optional, into)]
+  tag: Option<String>,
+  /// How long each animation takes. Defaults to 300 milliseconds.
+  #[prop(optional)]
+  duration: Option<Duration>,
+  /// The CSS easing function of the animations. Defaults to `ease`.
+  #[prop(optional, into)]
+  easing: Option<String>,
+) -> impl IntoView
+where
+  IF: Fn() -> I + 'static,
+  I: IntoIterator<Item = T>,
+  EF: Fn(Scope, T) -> N + 'static,
+  N: IntoView,
+  KF: Fn(&T) -> K + 'static,
+  K: Eq + Hash + Clone + 'static,
+  T: Clone + 'static,
+{
+  let tag = tag.unwrap_or_else(|| "div".into());
+  let duration = duration.unwrap_or(Duration::from_millis(300));
+  let transition = format!(
+    "transform {ms}ms {easing}, opacity {ms}ms {easing}",
+    ms = duration.as_millis(),
+    easing = easing.as_deref().unwrap_or("ease"),
+  );
+
+  let reduced_motion = use_reduced_motion(cx);
+  let animate =
+    move || try_window().is_some() && !reduced_motion.get_untracked();
+  let key = Rc::new(key);
+  let flip = Rc::new(RefCell::new(Flip::<K, T>::default()));
+
+  // Keys of removed items whose leave animation finished
+  let left = create_rw_signal(cx, Vec::<K>::new());
+
+  let each = {
+    let key = key.clone();
+    let state = flip.clone();
+
+    move || {
+      let left_keys = left.get();
+
+      let mut items = each()
+        .into_iter()
+        .map(|item| (key(&item), item))
+        .collect::<Vec<_>>();
+
+      let mut flip = state.borrow_mut();
+
+      // This runs right before the DOM is updated, so it's the last chance
+      // to know where items were
+      flip.record_first();
+
+      let leave = animate() && flip.is_mounted;
+
+      flip.list.update(&mut items, leave, &left_keys, |removed| {
+        let removed = removed.clone();
+
+        set_timeout(
+          cx,
+          move || left.update(|left| left.push(removed)),
+          duration,
+        )
+      });
+
+      // Finished animations were handled above
+      left.update_untracked(|left| {
+        left.retain(|left| flip.list.is_leaving(left))
+      });
+
+      drop(flip);
+
+      if animate() {
+        let state = state.clone();
+        let transition = transition.clone();
+
+        request_animation_frame(move || state.borrow_mut().play(&transition));
+      }
+
+      items.into_iter().map(|(_, item)| item).collect::<Vec<_>>()
+    }
+  };
+
+  on_cleanup(cx, {
+    let flip = flip.clone();
+
+    move || flip.borrow_mut().list.clear()
+  });
+
+  let view = {
+    let key = key.clone();
+
+    move |cx, item: T| {
+      let item_key = key(&item);
+
+      let element =
+        html::custom(cx, html::Custom::new(tag.clone())).child(view(cx, item));
+
+      if animate() {
+        let mut state = flip.borrow_mut();
+
+        if state.is_mounted {
+          state.entering.push(item_key.clone());
+        }
+
+        state.elements.push((item_key.clone(), (*element).clone()));
+
+        on_cleanup(cx, {
+          let flip = flip.clone();
+
+          move || {
+            flip
+              .borrow_mut()
+              .elements
+              .retain(|(key, _)| *key != item_key)
+          }
+        });
+      }
+
+      element
+    }
+  };
+
+  view! { cx, <For each key=move |item: &T| key(item) view /> }
+}
+
+/// The state of the animations of an [`AnimatedFor`].
+struct Flip<K, T> {
+  /// The items rendered last, including the ones which are leaving.
+  list: LeavingList<K, T>,
+  /// The wrapper elements of the rendered items.
+  elements: Vec<(K, web_sys::HtmlElement)>,
+  /// Where each item was before the DOM was last updated.
+  firsts: Vec<(K, (f64, f64))>,
+  entering: Vec<K>,
+  /// Whether the initial render was done, which isn't animated.
+  is_mounted: bool,
+}
+
+impl<K, T> Default for Flip<K, T> {
+  fn default() -> Self {
+    Self {
+      list: LeavingList::default(),
+      elements: Vec::new(),
+      firsts: Vec::new(),
+      entering: Vec::new(),
+      is_mounted: false,
+    }
+  }
+}
+
+impl<K: Eq + Clone, T: Clone> Flip<K, T> {
+  fn record_first(&mut self) {
+    self.firsts = self
+      .elements
+      .iter()
+      .map(|(key, element)| {
+        let rect = element.get_bounding_client_rect();
+
+        (key.clone(), (rect.left(), rect.top()))
+      })
+      .collect();
+  }
+
+  /// Moves every item back to where it was, and then animates it to where it
+  /// is now.
+  fn play(&mut self, transition: &str) {
+    for (key, element) in &self.elements {
+      let style = element.style();
+      let _ = style.set_property("transition", "none");
+
+      if self.entering.contains(key) {
+        let _ = style.set_property("opacity", "0");
+      }
+
+      let rect = element.get_bounding_client_rect();
+
+      let first = self
+        .firsts
+        .iter()
+        .find(|(first_key, _)| first_key == key)
+        .map(|(_, first)| *first);
+
+      if let Some((left, top)) = first {
+        let (dx, dy) = (left - rect.left(), top - rect.top());
+
+        if dx != 0.0 || dy != 0.0 {
+          let _ = style
+            .set_property("transform", &format!("translate({dx}px, {dy}px)"));
+        }
+      }
+    }
+
+    // Reading the layout commits the starting positions, so the following
+    // changes are transitioned
+    if let Some((_, element)) = self.elements.first() {
+      let _ = element.offset_width();
+    }
+
+    for (key, element) in &self.elements {
+      let style = element.style();
+      let _ = style.set_property("transition", transition);
+      let _ = style.remove_property("transform");
+
+      if self.list.is_leaving(key) {
+        let _ = style.set_property("opacity", "0");
+      } else {
+        let _ = style.remove_property("opacity");
+      }
+    }
+
+    self.entering.clear();
+    self.firsts.clear();
+    self.is_mounted = true;
+  }
+}
@@ -0,0 +1,162 @@
+//! Animated `if/else` for [`leptos`].
+//!
+//! Please refer to [`AnimatedIf`] for usage examples.
+
+use crate::{
+  error::report,
+  if_::{
+    check_structure,
+    IfBlock,
+  },
+  transition_group::{
+    TransitionGroup,
+    TransitionGroupProps,
+  },
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  rc::Rc,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <AnimatedIf signal=logged_in enter_class="fade-in" leave_class="fade-out">
+      <Then><Avatar /></Then>
+      <Else><LoginButton /></Else>
+    </AnimatedIf>
+  }
+}
+
+/// The `if/else` construct, like [`If`](crate::if_::If), which animates
+/// between the [`Then`](crate::if_::Then), [`ElseIf`](crate::if_::ElseIf)
+/// and [`Else`](crate::if_::Else) arms.
+///
+/// Each arm is wrapped in an element, which gets `enter_class` when the arm
+/// is shown, and `leave_class` when it's hidden. The hidden arm is only
+/// removed once the CSS animation or transition started by `leave_class`
+/// ends, or after `duration`, so two arms are rendered at the same time
+/// while switching. Lay the wrapping elements on top of each other, such as
+/// with a CSS grid, to cross-fade between them.
+///
+/// This is built on a [`TransitionGroup`], so the arm rendered initially
+/// isn't animated, and nothing is animated while the user prefers reduced
+/// motion.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (logged_in, _) = create_signal(cx, false);
+///
+/// view! { cx,
+/// <AnimatedIf
+///   signal=logged_in
+///   enter_class="fade-in"
+///   leave_class="fade-out"
+///   duration=Duration::from_millis(200)
+/// >
+///   <Then>"Welcome back!"</Then>
+///   <Else><button>"Log in"</button></Else>
+/// </AnimatedIf>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn AnimatedIf(
+  cx: Scope,
+  /// The bool signal.
+  #[prop(into)]
+  signal: MaybeSignal<bool>,
+  /// Added to the element wrapping an arm when it's shown.
+  #[prop(optional, into)]
+  enter_class: Option<String>,
+  /// Replaces `enter_class` when an arm is hidden.
+  #[prop(optional, into)]
+  leave_class: Option<String>,
+  /// How long the leave animation takes. When set, hidden arms are removed
+  /// after this long, instead of when their animation ends.
+  #[prop(optional)]
+  duration: Option<Duration>,
+  /// The tag of the element wrapping each arm. Defaults to `div`.
+  #[prop(optional, into)]
+  tag: Option<String>,
+  /// The arms of the condition.
+  ///
+  /// Children must be any
+  /// - [`Then`](crate::if_::Then)
+  /// - [`ElseIf`](crate::if_::ElseIf)
+  /// - [`Else`](crate::if_::Else)
+  ///
+  /// The same rules as for [`If`](crate::if_::If) apply, so [`Then`] must be
+  /// first, and [`Else`] last, if any. Any other child not in the above list
+  /// will not be rendered.
+  ///
+  /// [`Then`]: crate::if_::Then
+  /// [`Else`]: crate::if_::Else
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let signal = create_memo(cx, move |_| signal.get());
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let is_valid = check_structure(&blocks).map_err(report).is_ok();
+
+  let blocks = Rc::new(
+    blocks
+      .into_iter()
+      .filter(|block| block.downcast_ref::<IfBlock>().is_some())
+      .collect::<Vec<_>>(),
+  );
+
+  // The index of the arm to show, if any
+  let shown = {
+    let blocks = blocks.clone();
+
+    move || {
+      if !is_valid {
+        None
+      } else if signal.get() {
+        Some(0)
+      } else {
+        (1..blocks.len()).find(|i| if_block(&blocks, *i).is_true())
+      }
+    }
+  };
+
+  let arm = move |cx: Scope, i: usize| {
+    #[cfg(feature = "performance")]
+    let _measure = crate::performance::measure_switch(cx);
+
+    if_block(&blocks, i).render(cx)
+  };
+
+  TransitionGroup(
+    cx,
+    TransitionGroupProps {
+      each: shown,
+      key: |i: &usize| *i,
+      view: arm,
+      tag,
+      enter_class,
+      leave_class,
+      appear: false,
+      duration,
+    },
+  )
+}
+
+fn if_block(blocks: &[Transparent], i: usize) -> &IfBlock {
+  blocks[i].downcast_ref::<IfBlock>().unwrap()
+}
@@ -0,0 +1,88 @@
+//! Screen reader announcements for [`leptos`].
+//!
+//! Please refer to [`RouteAnnouncer`] for usage examples.
+
+use leptos::*;
+
+api_planning! {
+  let (page, set_page) = create_signal(cx, "Home".to_string());
+
+  view! { cx,
+    <RouteAnnouncer name=page format=Box::new(|name| format!("{name} page")) />
+  }
+}
+
+/// Hides an element visually, while keeping it available to screen readers.
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; \
+                               padding: 0; margin: -1px; overflow: hidden; \
+                               clip: rect(0, 0, 0, 0); white-space: nowrap; \
+                               border: 0;";
+
+/// Announces to screen readers whenever the active view changes.
+///
+/// Client-side navigation and conditional rendering swap large parts of the
+/// page without the browser telling assistive technology about it. This
+/// component renders a visually hidden live region, and announces
+/// `"Navigated to {name}"` into it every time `name` changes.
+///
+/// `name` can come from anything which identifies the active view, such as
+/// the current route or the signal driving an [`If`](crate::if_::If).
+/// Nothing is announced for the initial value.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (logged_in, _) = create_signal(cx, false);
+///
+/// let name = Signal::derive(cx, move || {
+///   if logged_in.get() { "dashboard" } else { "login" }.to_string()
+/// });
+///
+/// view! { cx,
+/// <RouteAnnouncer name format=Box::new(|name| format!("Showing {name}")) />
+/// <If signal=logged_in>
+///   <Then>"Dashboard"</Then>
+///   <Else>"Login"</Else>
+/// </If>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn RouteAnnouncer(
+  cx: Scope,
+  /// The name of the active view.
+  #[prop(into)]
+  name: MaybeSignal<String>,
+  /// Formats the announced message from the name of the active view.
+  #[prop(optional)]
+  format: Option<Box<dyn Fn(&str) -> String>>,
+) -> impl IntoView {
+  let format =
+    format.unwrap_or_else(|| Box::new(|name| format!("Navigated to {name}")));
+
+  let message = create_rw_signal(cx, String::new());
+
+  create_effect(cx, move |prev_name: Option<String>| {
+    let name = name.get();
+
+    if prev_name.is_some() && prev_name.as_ref() != Some(&name) {
+      message.set(format(&name));
+    }
+
+    name
+  });
+
+  view! { cx,
+    <div
+      role="status"
+      aria-live="polite"
+      aria-atomic="true"
+      style=VISUALLY_HIDDEN
+    >
+      {move || message.get()}
+    </div>
+  }
+}
@@ -1,10 +1,179 @@
+//! Declarative suspense for [`leptos`].
+//!
+//! Please refer to [`Async`] for usage examples.
+
+use crate::await_::render_pending;
+use leptos::*;
+use leptos_dom::Transparent;
+use std::rc::Rc;
+
 api_planning! {
   view! { cx,
-    <Await>
-      <Ready>
-      </Ready>
-      <Pending>
-      </Pending>
-    </Await>
+    <Async>
+      <Pending>"Loading..."</Pending>
+      <Ready>{move || user.read(cx).map(|user| user.name)}</Ready>
+      <Failure view=|cx, errors: RwSignal<Errors>| view! { cx,
+        <p>"Something went wrong"</p>
+      } />
+    </Async>
+  }
+}
+
+/// leptos' [`Suspense`] and [`ErrorBoundary`] in the style of [`If`].
+///
+/// Resources read within the [`Ready`] arm are tracked, and the [`Pending`]
+/// arm is shown until all of them have loaded. If the [`Ready`] arm renders
+/// an error, the [`Failure`] arm is shown instead, until it recovers.
+///
+/// Set `transition` to keep showing the [`Ready`] arm while resources are
+/// reloading, like leptos' [`Transition`].
+///
+/// For more docs on allowed child components, check out
+/// [`AsyncProps::children`].
+///
+/// [`If`]: crate::if_::If
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let user = create_local_resource(cx, || (), |_| async { "Jose".to_string() });
+///
+/// view! { cx,
+/// <Async>
+///   <Pending>"Loading user..."</Pending>
+///   <Ready>
+///     <p>"Hello, "{move || user.read(cx)}</p>
+///   </Ready>
+///   <Failure view=|cx, _| view! { cx, <p>"Something went wrong"</p> } />
+/// </Async>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Async(
+  cx: Scope,
+  /// Keeps showing the [`Ready`] arm while resources are reloading, instead
+  /// of going back to the [`Pending`] arm.
+  #[prop(optional)]
+  transition: bool,
+  /// The arms of each state.
+  ///
+  /// Children must be any
+  /// - [`Pending`]
+  /// - [`Ready`]
+  /// - [`Failure`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let ready = blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<ReadyBlock>)
+    .map(|block| block.children.clone());
+  let failure = blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<FailureBlock>)
+    .map(|block| block.view.clone());
+
+  let fallback = move || render_pending(cx, &blocks);
+
+  let content: Box<dyn Fn(Scope) -> Fragment> = Box::new(move |cx| {
+    let Some(ready) = ready.clone() else {
+      return Fragment::new(vec![]);
+    };
+
+    let Some(failure) = failure.clone() else {
+      return ready(cx);
+    };
+
+    let boundary = ErrorBoundary(
+      cx,
+      ErrorBoundaryProps {
+        fallback: move |cx, errors| failure(cx, errors),
+        children: Box::new(move |cx| ready(cx)),
+      },
+    );
+
+    Fragment::new(vec![boundary.into_view(cx)])
+  });
+
+  if transition {
+    Transition(
+      cx,
+      TransitionProps {
+        fallback,
+        set_pending: None,
+        children: content,
+      },
+    )
+    .into_view(cx)
+  } else {
+    Suspense(
+      cx,
+      SuspenseProps {
+        fallback,
+        children: content,
+      },
+    )
+    .into_view(cx)
+  }
+}
+
+/// Shown by the parent [`Async`] once all resources read within it have
+/// loaded.
+#[component(transparent)]
+pub fn Ready(
+  cx: Scope,
+  /// What you want to show once loaded.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  ReadyBlock {
+    children: children.into(),
+  }
+}
+
+/// Shown by the parent [`Async`] while it's [`Ready`] arm renders errors.
+#[component(transparent)]
+pub fn Failure<F, IV>(
+  cx: Scope,
+  /// Renders the errors.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, RwSignal<Errors>) -> IV + 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  FailureBlock {
+    view: Rc::new(move |cx, errors| view(cx, errors).into_view(cx)),
+  }
+}
+
+transparent_block! {
+  /// The loaded arm returned by [`Ready`].
+  pub struct ReadyBlock {
+    children: Rc<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The error arm returned by [`Failure`].
+  pub struct FailureBlock {
+    view: Rc<dyn Fn(Scope, RwSignal<Errors>) -> View>,
   }
 }
@@ -0,0 +1,204 @@
+//! Authentication guards for [`leptos`].
+//!
+//! Please refer to [`AuthProvider`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <AuthProvider user=current_user resolving=loading_user>
+      <Resolving><Spinner /></Resolving>
+      <Authenticated view=|cx, user: User| view! { cx, <Dashboard user /> } />
+      <Anonymous><LoginForm /></Anonymous>
+    </AuthProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<AuthProvider />",
+  context: "AuthState",
+};
+
+/// Holds the signed in user, which [`Authenticated`], [`Anonymous`] and
+/// [`Resolving`] below it render based on.
+///
+/// `user` is supplied by your app, such as from a resource fetching the
+/// session. Set `resolving` while it's still unknown whether a user is
+/// signed in, so neither [`Authenticated`] nor [`Anonymous`] flash.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct User {
+///   name: String,
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (user, _) = create_signal(cx, None::<User>);
+/// let (loading, _) = create_signal(cx, true);
+///
+/// view! { cx,
+/// <AuthProvider user resolving=loading>
+///   <Resolving>"Checking your session..."</Resolving>
+///   <Authenticated view=|cx, user: User| view! { cx,
+///     <p>"Welcome back, "{user.name}</p>
+///   } />
+///   <Anonymous><button>"Sign in"</button></Anonymous>
+/// </AuthProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn AuthProvider<U>(
+  cx: Scope,
+  /// The signed in user, if any.
+  #[prop(into)]
+  user: Signal<Option<U>>,
+  /// Whether it's still unknown if a user is signed in. Defaults to `false`.
+  #[prop(into, default = false.into())]
+  resolving: MaybeSignal<bool>,
+  /// The part of your app which depends on the user.
+  children: Children,
+) -> impl IntoView
+where
+  U: Clone + 'static,
+{
+  let signed_in = create_memo(cx, move |_| user.with(Option::is_some));
+
+  provide_context(cx, user);
+  provide_context(
+    cx,
+    AuthState {
+      signed_in: signed_in.into(),
+      resolving: Signal::derive(cx, move || resolving.get()),
+    },
+  );
+
+  children(cx)
+}
+
+/// Renders `view` with the signed in user of the nearest [`AuthProvider`],
+/// while one is signed in.
+///
+/// # Panics
+/// Panics if there is no [`AuthProvider`] whose user is of type `U` above.
+#[component]
+pub fn Authenticated<F, U, IV>(
+  cx: Scope,
+  /// Renders the content, given the signed in user. The type of the user
+  /// must match the user of the [`AuthProvider`].
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, U) -> IV + 'static,
+  U: Clone + 'static,
+  IV: IntoView,
+{
+  let state = use_auth_state(cx);
+  let user = use_user::<U>(cx);
+
+  move || {
+    if state.is_resolving() {
+      return ().into_view(cx);
+    }
+
+    match user.get() {
+      Some(user) => view(cx, user).into_view(cx),
+      None => ().into_view(cx),
+    }
+  }
+}
+
+/// Renders it's children while no user is signed in to the nearest
+/// [`AuthProvider`].
+///
+/// # Panics
+/// Panics if there is no [`AuthProvider`] above.
+#[component]
+pub fn Anonymous(
+  cx: Scope,
+  /// What you want to render while no user is signed in.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let state = use_auth_state(cx);
+  let anonymous =
+    create_memo(cx, move |_| !state.is_resolving() && !state.is_signed_in());
+
+  move || {
+    if anonymous.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Renders it's children while the nearest [`AuthProvider`] is resolving
+/// whether a user is signed in.
+///
+/// # Panics
+/// Panics if there is no [`AuthProvider`] above.
+#[component]
+pub fn Resolving(
+  cx: Scope,
+  /// What you want to render while resolving.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let state = use_auth_state(cx);
+  let resolving = create_memo(cx, move |_| state.is_resolving());
+
+  move || {
+    if resolving.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// The state of the nearest [`AuthProvider`], regardless of the type of it's
+/// user.
+#[derive(Clone, Copy)]
+pub struct AuthState {
+  signed_in: Signal<bool>,
+  resolving: Signal<bool>,
+}
+
+impl AuthState {
+  /// Whether a user is signed in.
+  ///
+  /// This is reactive.
+  pub fn is_signed_in(&self) -> bool {
+    self.signed_in.get()
+  }
+
+  /// Whether it's still unknown if a user is signed in.
+  ///
+  /// This is reactive.
+  pub fn is_resolving(&self) -> bool {
+    self.resolving.get()
+  }
+}
+
+/// Gets the state of the nearest [`AuthProvider`].
+///
+/// # Panics
+/// Panics if there is no [`AuthProvider`] above.
+pub fn use_auth_state(cx: Scope) -> AuthState {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
+
+/// Gets the signed in user of the nearest [`AuthProvider`].
+///
+/// # Panics
+/// Panics if there is no [`AuthProvider`] whose user is of type `U` above.
+pub fn use_user<U: Clone + 'static>(cx: Scope) -> Signal<Option<U>> {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
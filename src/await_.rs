@@ -0,0 +1,199 @@
+//! Declarative async states for [`leptos`].
+//!
+//! Please refer to [`Await`] for usage examples.
+
+use crate::skeleton::use_group_pending;
+use leptos::*;
+use leptos_dom::Transparent;
+
+api_planning! {
+  view! { cx,
+    <Await resource=user>
+      <Pending>"Loading user..."</Pending>
+      <Resolved view=|cx, user: User| view! { cx, <p>{user.name}</p> } />
+      <Rejected view=|cx, error: ServerFnError| view! { cx,
+        <p>{error.to_string()}</p>
+      } />
+    </Await>
+  }
+}
+
+/// Renders the state of a [`Resource`] which resolves to a [`Result`].
+///
+/// While the resource is loading, the [`Pending`] arm is shown. Once it
+/// resolves, either the [`Resolved`] arm is shown with the data, or the
+/// [`Rejected`] arm with the error.
+///
+/// When placed within a [`SkeletonGroup`](crate::skeleton::SkeletonGroup),
+/// the [`Pending`] arm is shown until the whole group has settled.
+///
+/// For more docs on allowed child components, check out
+/// [`AwaitProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let user = create_local_resource(
+///   cx,
+///   || (),
+///   |_| async { Ok::<_, String>("Jose".to_string()) },
+/// );
+///
+/// view! { cx,
+/// <Await resource=user>
+///   <Pending>"Loading user..."</Pending>
+///   <Resolved view=|cx, name: String| view! { cx, <p>"Hello, "{name}</p> } />
+///   <Rejected view=|cx, error: String| view! { cx, <p>{error}</p> } />
+/// </Await>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Await<S, T, E>(
+  cx: Scope,
+  /// The resource whose state should be rendered.
+  resource: Resource<S, Result<T, E>>,
+  /// The arms of each state of the resource.
+  ///
+  /// Children must be any
+  /// - [`Pending`]
+  /// - [`Resolved`]
+  /// - [`Rejected`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  S: Clone + 'static,
+  T: Clone + 'static,
+  E: Clone + 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let pending = use_group_pending(cx, move || resource.loading().get());
+
+  move || {
+    #[cfg(feature = "performance")]
+    let _measure = crate::performance::measure_switch(cx);
+
+    if pending.get() {
+      return render_pending(cx, &blocks);
+    }
+
+    match resource.read(cx) {
+      Some(Ok(data)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<ResolvedBlock<T>>)
+        .map(|block| (block.view)(cx, data))
+        .unwrap_or_else(|| ().into_view(cx)),
+      Some(Err(error)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<RejectedBlock<E>>)
+        .map(|block| (block.view)(cx, error))
+        .unwrap_or_else(|| ().into_view(cx)),
+      None => render_pending(cx, &blocks),
+    }
+  }
+}
+
+/// Renders the [`Pending`] arm, if any.
+pub(crate) fn render_pending(cx: Scope, blocks: &[Transparent]) -> View {
+  blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<PendingBlock>)
+    .map(|block| (block.children)(cx).into_view(cx))
+    .unwrap_or_else(|| ().into_view(cx))
+}
+
+/// Shown while the resource of the parent [`Await`],
+/// [`Retry`](crate::retry::Retry) or [`Poll`](crate::poll::Poll) is loading,
+/// or while the resources of the parent [`Async`](crate::async_::Async) or
+/// [`First`](crate::first::First) are.
+#[component(transparent)]
+pub fn Pending(
+  cx: Scope,
+  /// What you want to show while loading.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  PendingBlock { children }
+}
+
+/// Shown when the resource of the parent [`Await`],
+/// [`Retry`](crate::retry::Retry) or [`Poll`](crate::poll::Poll) resolves to
+/// [`Ok`].
+///
+/// The type of the data must match the data of the resource, otherwise this
+/// arm will never be rendered.
+#[component(transparent)]
+pub fn Resolved<F, T, IV>(
+  cx: Scope,
+  /// Renders the data.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, T) -> IV + 'static,
+  T: 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  ResolvedBlock {
+    view: Box::new(move |cx, data| view(cx, data).into_view(cx)),
+  }
+}
+
+/// Shown when the resource of the parent [`Await`] or
+/// [`Poll`](crate::poll::Poll) resolves to [`Err`].
+///
+/// The type of the error must match the error of the resource, otherwise
+/// this arm will never be rendered.
+#[component(transparent)]
+pub fn Rejected<F, E, IV>(
+  cx: Scope,
+  /// Renders the error.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, E) -> IV + 'static,
+  E: 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  RejectedBlock {
+    view: Box::new(move |cx, error| view(cx, error).into_view(cx)),
+  }
+}
+
+transparent_block! {
+  /// The pending arm returned by [`Pending`].
+  pub struct PendingBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The success arm returned by [`Resolved`].
+  pub struct ResolvedBlock<T> {
+    pub(crate) view: Box<dyn Fn(Scope, T) -> View>,
+  }
+}
+
+transparent_block! {
+  /// The error arm returned by [`Rejected`].
+  pub struct RejectedBlock<E> {
+    pub(crate) view: Box<dyn Fn(Scope, E) -> View>,
+  }
+}
@@ -0,0 +1,233 @@
+//! Responsive rendering with named breakpoints for [`leptos`].
+//!
+//! Please refer to [`BreakpointProvider`] for usage examples.
+
+use crate::{
+  error::{
+    expect_context,
+    report,
+    Error,
+  },
+  util::try_window,
+};
+use leptos::*;
+use std::rc::Rc;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <BreakpointProvider breakpoints=vec![(Sm, 640.0), (Md, 768.0), (Lg, 1024.0)]>
+      <Breakpoint at=Md><DesktopNav /></Breakpoint>
+      <Breakpoint below=Md><MobileNav /></Breakpoint>
+    </BreakpointProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<BreakpointProvider />",
+  context: "BreakpointCtx",
+};
+
+/// Configures the named breakpoints used by [`Breakpoint`] below it.
+///
+/// Breakpoints are named by any type you like, usually an enum, and map to
+/// the minimum viewport width, in CSS pixels, at which they start. All
+/// [`Breakpoint`]s share a single `resize` listener.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Size {
+///   Sm,
+///   Md,
+///   Lg,
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <BreakpointProvider
+///   breakpoints=vec![(Size::Sm, 640.0), (Size::Md, 768.0), (Size::Lg, 1024.0)]
+/// >
+///   <Breakpoint at=Size::Md>"Desktop"</Breakpoint>
+///   <Breakpoint below=Size::Md>"Mobile"</Breakpoint>
+/// </BreakpointProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn BreakpointProvider<B>(
+  cx: Scope,
+  /// Each breakpoint, along with the viewport width at which it starts.
+  breakpoints: Vec<(B, f64)>,
+  /// The viewport width assumed when it can't be measured, such as on the
+  /// server. Defaults to `0.0`, meaning only `below` breakpoints match.
+  #[prop(optional)]
+  ssr_width: f64,
+  /// The part of your app which uses the breakpoints.
+  children: Children,
+) -> impl IntoView
+where
+  B: PartialEq + 'static,
+{
+  let width = create_rw_signal(cx, ssr_width);
+
+  create_effect(cx, move |_| {
+    let Some(window) = try_window() else {
+      return;
+    };
+
+    let measure = {
+      let window = window.clone();
+
+      move || {
+        if let Some(inner_width) =
+          window.inner_width().ok().and_then(|width| width.as_f64())
+        {
+          width.set(inner_width);
+        }
+      }
+    };
+
+    measure();
+
+    let on_resize = Closure::<dyn Fn()>::new(measure);
+
+    let _ = window.add_event_listener_with_callback(
+      "resize",
+      on_resize.as_ref().unchecked_ref(),
+    );
+
+    on_cleanup(cx, move || {
+      let _ = window.remove_event_listener_with_callback(
+        "resize",
+        on_resize.as_ref().unchecked_ref(),
+      );
+    });
+  });
+
+  provide_context(
+    cx,
+    BreakpointCtx {
+      breakpoints: Rc::new(breakpoints),
+      width: width.into(),
+    },
+  );
+
+  children(cx)
+}
+
+/// Renders it's children only while the viewport is within the given
+/// breakpoint of the nearest [`BreakpointProvider`].
+///
+/// Set `at` to render from the start of a breakpoint and up, and `below` to
+/// render until the start of a breakpoint. When both are set, the viewport
+/// must satisfy both.
+#[component]
+pub fn Breakpoint<B>(
+  cx: Scope,
+  /// Renders the children while the viewport is at least as wide as the
+  /// start of this breakpoint.
+  #[prop(optional)]
+  at: Option<B>,
+  /// Renders the children while the viewport is narrower than the start of
+  /// this breakpoint.
+  #[prop(optional)]
+  below: Option<B>,
+  /// What you want to render while the viewport is within the breakpoint.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  B: PartialEq + 'static,
+{
+  let ctx = use_breakpoints::<B>(cx);
+
+  let at = at.map(|at| ctx.min_width(&at));
+  let below = below.map(|below| ctx.min_width(&below));
+
+  let matches = create_memo(cx, move |_| {
+    let width = ctx.width.get();
+
+    at.map_or(true, |at| width >= at)
+      && below.map_or(true, |below| width < below)
+  });
+
+  move || {
+    if matches.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// The breakpoints of a [`BreakpointProvider`], returned by
+/// [`use_breakpoints`].
+pub struct BreakpointCtx<B> {
+  breakpoints: Rc<Vec<(B, f64)>>,
+  width: Signal<f64>,
+}
+
+impl<B> Clone for BreakpointCtx<B> {
+  fn clone(&self) -> Self {
+    Self {
+      breakpoints: self.breakpoints.clone(),
+      width: self.width,
+    }
+  }
+}
+
+impl<B: PartialEq> BreakpointCtx<B> {
+  /// The width of the viewport, in CSS pixels.
+  ///
+  /// This is reactive.
+  pub fn width(&self) -> f64 {
+    self.width.get()
+  }
+
+  /// Whether the viewport is at least as wide as the start of `breakpoint`.
+  ///
+  /// This is reactive.
+  pub fn is_at(&self, breakpoint: &B) -> bool {
+    self.width() >= self.min_width(breakpoint)
+  }
+
+  /// Whether the viewport is narrower than the start of `breakpoint`.
+  ///
+  /// This is reactive.
+  pub fn is_below(&self, breakpoint: &B) -> bool {
+    !self.is_at(breakpoint)
+  }
+
+  /// The width at which `breakpoint` starts.
+  ///
+  /// Reports [`Error::UnknownBreakpoint`] and treats `breakpoint` as never
+  /// reached if it wasn't configured in the [`BreakpointProvider`].
+  fn min_width(&self, breakpoint: &B) -> f64 {
+    self
+      .breakpoints
+      .iter()
+      .find(|(name, _)| name == breakpoint)
+      .map(|(_, width)| *width)
+      .unwrap_or_else(|| {
+        report(Error::UnknownBreakpoint);
+
+        f64::INFINITY
+      })
+  }
+}
+
+/// Gets the breakpoints of the nearest [`BreakpointProvider`].
+///
+/// # Panics
+/// Panics if there is no [`BreakpointProvider`] with breakpoints of type `B`
+/// above.
+pub fn use_breakpoints<B: 'static>(cx: Scope) -> BreakpointCtx<B> {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
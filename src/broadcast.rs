@@ -0,0 +1,170 @@
+//! Exchanging messages between tabs for [`leptos`].
+//!
+//! Please refer to [`Broadcast`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use serde::{
+  de::DeserializeOwned,
+  Serialize,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+  JsValue,
+};
+
+api_planning! {
+  view! { cx,
+    <Broadcast channel="auth" view=|cx, auth: BroadcastHandle<AuthMessage>| view! { cx,
+      <If signal=Signal::derive(cx, move || auth.latest().get() == Some(AuthMessage::LoggedOut))>
+        <Then><LoggedOutBanner /></Then>
+      </If>
+    } />
+  }
+}
+
+/// Passes `view` a [`BroadcastHandle`], which sends messages to the other
+/// tabs of your app, and receives theirs, over the `channel` broadcast
+/// channel.
+///
+/// This is handy for reacting to things which happen in other tabs, such as
+/// showing a banner when the user logged out in another tab.
+///
+/// Messages are sent as JSON. Messages which fail to deserialize are
+/// ignored. Nothing is sent or received when not running in a browser.
+///
+/// `view` is only called once, so it should read the latest message
+/// reactively. Use [`use_broadcast`] outside of views.
+///
+/// This component is only available with the `broadcast` feature.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, PartialEq, Serialize, Deserialize)]
+/// enum AuthMessage {
+///   LoggedOut,
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Broadcast channel="auth" view=|cx, auth: BroadcastHandle<AuthMessage>| view! { cx,
+///   <If signal=Signal::derive(cx, move || {
+///     auth.latest().get() == Some(AuthMessage::LoggedOut)
+///   })>
+///     <Then>"You logged out in another tab"</Then>
+///   </If>
+///   <button on:click=move |_| auth.send(&AuthMessage::LoggedOut)>"Log out"</button>
+/// } />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Broadcast<T, F, IV>(
+  cx: Scope,
+  /// The name of the broadcast channel.
+  #[prop(into)]
+  channel: String,
+  /// Renders the content, given a handle to the channel.
+  view: F,
+) -> impl IntoView
+where
+  T: Serialize + DeserializeOwned + Clone + 'static,
+  F: FnOnce(Scope, BroadcastHandle<T>) -> IV,
+  IV: IntoView,
+{
+  let handle = use_broadcast(cx, channel);
+
+  view(cx, handle)
+}
+
+/// Sends and receives the messages of a broadcast channel.
+pub struct BroadcastHandle<T: 'static> {
+  latest: RwSignal<Option<T>>,
+  channel: StoredValue<Option<web_sys::BroadcastChannel>>,
+}
+
+impl<T: 'static> Clone for BroadcastHandle<T> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<T: 'static> Copy for BroadcastHandle<T> {}
+
+impl<T: Serialize + Clone + 'static> BroadcastHandle<T> {
+  /// Returns the latest message received from another tab, if any.
+  ///
+  /// Messages sent by this tab aren't received by it.
+  pub fn latest(&self) -> Signal<Option<T>> {
+    self.latest.into()
+  }
+
+  /// Sends `message` to the other tabs.
+  pub fn send(&self, message: &T) {
+    let Ok(json) = serde_json::to_string(message) else {
+      return;
+    };
+
+    self.channel.with_value(|channel| {
+      if let Some(channel) = channel {
+        let _ = channel.post_message(&JsValue::from_str(&json));
+      }
+    });
+  }
+}
+
+/// Returns a [`BroadcastHandle`] to the `channel` broadcast channel, which
+/// is closed once `cx` is cleaned up.
+pub fn use_broadcast<T>(
+  cx: Scope,
+  channel: impl Into<String>,
+) -> BroadcastHandle<T>
+where
+  T: DeserializeOwned + 'static,
+{
+  let name = channel.into();
+  let latest = create_rw_signal(cx, None::<T>);
+  let channel = store_value(cx, None::<web_sys::BroadcastChannel>);
+
+  create_effect(cx, move |_| {
+    if try_window().is_none() {
+      return;
+    }
+
+    let Ok(broadcast) = web_sys::BroadcastChannel::new(&name) else {
+      return;
+    };
+
+    let on_message = Closure::<dyn Fn(web_sys::MessageEvent)>::new(
+      move |ev: web_sys::MessageEvent| {
+        if let Some(message) = ev
+          .data()
+          .as_string()
+          .and_then(|json| serde_json::from_str(&json).ok())
+        {
+          latest.set(Some(message));
+        }
+      },
+    );
+
+    broadcast.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+    channel.set_value(Some(broadcast.clone()));
+
+    on_cleanup(cx, move || {
+      broadcast.set_onmessage(None);
+      broadcast.close();
+      channel.set_value(None);
+
+      drop(on_message);
+    });
+  });
+
+  BroadcastHandle { latest, channel }
+}
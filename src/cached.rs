@@ -0,0 +1,82 @@
+//! Stale-while-revalidate rendering for [`leptos`].
+//!
+//! Please refer to [`Cached`] for usage examples.
+
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <Cached
+      resource=feed
+      placeholder=Box::new(|cx| view! { cx, <Spinner /> }.into_view(cx))
+      view=|cx, feed: Feed, is_stale: Signal<bool>| view! { cx,
+        <FeedView feed class:stale=is_stale />
+      }
+    />
+  }
+}
+
+/// Renders `view` with the data of `resource`, and keeps showing the last
+/// loaded data while it's refetching, instead of falling back to the
+/// `placeholder`.
+///
+/// `view` is given whether the shown data is stale, which is `true` while a
+/// refetch is in flight, so it can be marked as such. Resources keep their
+/// data while refetching, so the `placeholder` is only shown while the cache
+/// is cold, which is until the resource loads for the first time.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (page, set_page) = create_signal(cx, 1);
+/// let feed = create_local_resource(cx, page, |page| async move {
+///   format!("Page {page}")
+/// });
+///
+/// view! { cx,
+/// <button on:click=move |_| set_page.update(|page| *page += 1)>"Next"</button>
+/// <Cached
+///   resource=feed
+///   placeholder=Box::new(|cx| view! { cx, <p>"Loading..."</p> }.into_view(cx))
+///   view=|cx, feed: String, is_stale: Signal<bool>| view! { cx,
+///     <p class:stale=move || is_stale.get()>{feed}</p>
+///   }
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Cached<S, T, F, IV>(
+  cx: Scope,
+  /// The resource whose data should be rendered.
+  resource: Resource<S, T>,
+  /// Renders the data, given whether it's stale.
+  view: F,
+  /// Rendered until the resource loads for the first time. Defaults to
+  /// rendering nothing.
+  #[prop(optional)]
+  placeholder: Option<Box<dyn Fn(Scope) -> View>>,
+) -> impl IntoView
+where
+  S: Clone + 'static,
+  T: Clone + 'static,
+  F: Fn(Scope, T, Signal<bool>) -> IV + 'static,
+  IV: IntoView,
+{
+  let is_stale = Signal::derive(cx, move || resource.loading().get());
+
+  move || {
+    if let Some(data) = resource.read(cx) {
+      return view(cx, data, is_stale).into_view(cx);
+    }
+
+    if let Some(placeholder) = &placeholder {
+      placeholder(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
@@ -0,0 +1,125 @@
+//! Detecting clicks outside of an element for [`leptos`].
+//!
+//! Please refer to [`ClickOutside`] for usage examples.
+
+use crate::util::try_window;
+use leptos::{
+  html::Div,
+  *,
+};
+use std::rc::Rc;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <ClickOutside open=menu_open>
+      <button on:click=move |_| menu_open.set(true)>"Menu"</button>
+      <If signal=menu_open>
+        <Then><Menu /></Then>
+      </If>
+    </ClickOutside>
+  }
+}
+
+/// Calls `on_click_outside`, and sets `open` to `false`, whenever a pointer
+/// is pressed outside of it's children.
+///
+/// This is the usual way of dismissing dropdowns and popovers. The children
+/// are wrapped in a `div`, and anything outside of it is considered outside,
+/// including content the children project through a
+/// [`PortalInput`](crate::portal::PortalInput).
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let open = create_rw_signal(cx, false);
+///
+/// view! { cx,
+/// <ClickOutside open>
+///   <button on:click=move |_| open.set(true)>"Menu"</button>
+///   <If signal=open>
+///     <Then><ul><li>"Settings"</li></ul></Then>
+///   </If>
+/// </ClickOutside>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ClickOutside(
+  cx: Scope,
+  /// Called whenever a pointer is pressed outside of the children.
+  #[prop(optional)]
+  on_click_outside: Option<Box<dyn Fn()>>,
+  /// Set to `false` whenever a pointer is pressed outside of the children.
+  #[prop(optional)]
+  open: Option<RwSignal<bool>>,
+  /// The `class` of the wrapping `div`.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// The content a pointer must be pressed outside of.
+  children: Children,
+) -> impl IntoView {
+  let container = create_node_ref::<Div>(cx);
+  let on_click_outside = on_click_outside.map(Rc::<dyn Fn()>::from);
+
+  create_effect(cx, move |_| {
+    let Some(document) = try_window().and_then(|window| window.document())
+    else {
+      return;
+    };
+
+    let on_pointerdown = Closure::<dyn Fn(web_sys::Event)>::new({
+      let on_click_outside = on_click_outside.clone();
+
+      move |ev: web_sys::Event| {
+        let Some(container) = container.get_untracked() else {
+          return;
+        };
+
+        let target = ev
+          .target()
+          .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+        if container.contains(target.as_ref()) {
+          return;
+        }
+
+        if let Some(open) = open {
+          open.set(false);
+        }
+
+        if let Some(on_click_outside) = &on_click_outside {
+          on_click_outside();
+        }
+      }
+    });
+
+    // Listening while capturing, so children stopping propagation can't
+    // hide clicks
+    let _ = document.add_event_listener_with_callback_and_bool(
+      "pointerdown",
+      on_pointerdown.as_ref().unchecked_ref(),
+      true,
+    );
+
+    on_cleanup(cx, move || {
+      let _ = document.remove_event_listener_with_callback_and_bool(
+        "pointerdown",
+        on_pointerdown.as_ref().unchecked_ref(),
+        true,
+      );
+    });
+  });
+
+  view! { cx,
+    <div class=class node_ref=container>
+      {children(cx)}
+    </div>
+  }
+}
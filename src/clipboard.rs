@@ -0,0 +1,183 @@
+//! Copying text to the clipboard for [`leptos`].
+//!
+//! Please refer to [`CopyToClipboard`] for usage examples.
+
+use crate::util::{
+  set_timeout,
+  try_window,
+  Timeout,
+};
+use leptos::*;
+use std::time::Duration;
+use wasm_bindgen::{
+  JsCast,
+  JsValue,
+};
+
+api_planning! {
+  view! { cx,
+    <CopyToClipboard text=invite_link view=|cx, clipboard: CopyHandle| view! { cx,
+      <button on:click=move |_| clipboard.copy()>
+        {move || if clipboard.copied() { "Copied!" } else { "Copy link" }}
+      </button>
+    } />
+  }
+}
+
+/// Passes `view` a [`CopyHandle`], which copies `text` to the clipboard and
+/// tells whether it was just copied, for showing transient "Copied!"
+/// feedback.
+///
+/// `view` is only called once, so it should read whether the text was copied
+/// reactively. Use [`use_clipboard`] to copy arbitrary text outside of
+/// views.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <CopyToClipboard
+///   text="https://example.com/invite"
+///   view=|cx, clipboard: CopyHandle| view! { cx,
+///     <button on:click=move |_| clipboard.copy()>
+///       {move || if clipboard.copied() { "Copied!" } else { "Copy link" }}
+///     </button>
+///   }
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn CopyToClipboard<F, IV>(
+  cx: Scope,
+  /// The text to copy.
+  #[prop(into)]
+  text: MaybeSignal<String>,
+  /// How long the text is reported as copied. Defaults to 2 seconds.
+  #[prop(optional)]
+  reset_after: Option<Duration>,
+  /// Renders the content, given a handle to copy `text`.
+  view: F,
+) -> impl IntoView
+where
+  F: FnOnce(Scope, CopyHandle) -> IV,
+  IV: IntoView,
+{
+  let handle = CopyHandle {
+    clipboard: use_clipboard(cx, reset_after),
+    text: store_value(cx, text),
+  };
+
+  view(cx, handle)
+}
+
+/// Copies the `text` of a [`CopyToClipboard`].
+#[derive(Clone, Copy)]
+pub struct CopyHandle {
+  clipboard: Clipboard,
+  text: StoredValue<MaybeSignal<String>>,
+}
+
+impl CopyHandle {
+  /// Copies the text to the clipboard.
+  pub fn copy(&self) {
+    self
+      .clipboard
+      .copy(self.text.with_value(|text| text.get_untracked()));
+  }
+
+  /// Returns whether the text was just copied.
+  pub fn copied(&self) -> bool {
+    self.clipboard.copied().get()
+  }
+}
+
+/// Copies text to the clipboard, and tracks whether text was just copied.
+#[derive(Clone, Copy)]
+pub struct Clipboard {
+  cx: Scope,
+  copied: RwSignal<bool>,
+  reset_after: Duration,
+  timeout: StoredValue<Option<Timeout>>,
+}
+
+impl Clipboard {
+  /// Copies `text` to the clipboard, using the Clipboard API.
+  ///
+  /// Nothing happens when not running in a browser, or when the browser
+  /// denies access to the clipboard.
+  pub fn copy(&self, text: impl Into<String>) {
+    let Some(promise) = write_text(&text.into()) else {
+      return;
+    };
+
+    let this = *self;
+
+    spawn_local(async move {
+      if wasm_bindgen_futures::JsFuture::from(promise).await.is_err() {
+        return;
+      }
+
+      this.clear_timeout();
+      this.copied.set(true);
+
+      this.timeout.set_value(set_timeout(
+        this.cx,
+        move || this.copied.set(false),
+        this.reset_after,
+      ));
+    });
+  }
+
+  /// Returns whether text was just copied.
+  pub fn copied(&self) -> Signal<bool> {
+    self.copied.into()
+  }
+
+  fn clear_timeout(&self) {
+    self.timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    });
+  }
+}
+
+/// Returns a [`Clipboard`], which reports text as copied for `reset_after`,
+/// or 2 seconds by default.
+pub fn use_clipboard(cx: Scope, reset_after: Option<Duration>) -> Clipboard {
+  let clipboard = Clipboard {
+    cx,
+    copied: create_rw_signal(cx, false),
+    reset_after: reset_after.unwrap_or(Duration::from_secs(2)),
+    timeout: store_value(cx, None),
+  };
+
+  on_cleanup(cx, move || clipboard.clear_timeout());
+
+  clipboard
+}
+
+/// Calls `navigator.clipboard.writeText(text)`.
+///
+/// The Clipboard API is called dynamically, as it's still unstable in
+/// `web-sys`.
+fn write_text(text: &str) -> Option<js_sys::Promise> {
+  let navigator = try_window()?.navigator();
+
+  let clipboard =
+    js_sys::Reflect::get(&navigator, &JsValue::from_str("clipboard")).ok()?;
+
+  js_sys::Reflect::get(&clipboard, &JsValue::from_str("writeText"))
+    .ok()?
+    .dyn_into::<js_sys::Function>()
+    .ok()?
+    .call1(&clipboard, &JsValue::from_str(text))
+    .ok()?
+    .dyn_into()
+    .ok()
+}
@@ -0,0 +1,157 @@
+//! Rendering based on the preferred color scheme for [`leptos`].
+//!
+//! Please refer to [`ColorScheme`] for usage examples.
+
+use crate::media_query::use_media_query;
+use leptos::*;
+use leptos_dom::Transparent;
+
+api_planning! {
+  view! { cx,
+    <ColorScheme>
+      <Dark><img src="logo-dark.svg" /></Dark>
+      <Light><img src="logo-light.svg" /></Light>
+    </ColorScheme>
+  }
+}
+
+/// The color scheme preferred by the user's OS or browser, as reported by
+/// the `prefers-color-scheme` media feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorSchemePreference {
+  /// The user prefers a light theme, or has no preference.
+  #[default]
+  Light,
+  /// The user prefers a dark theme.
+  Dark,
+}
+
+/// Renders the [`Dark`] arm while the user prefers a dark color scheme, and
+/// the [`Light`] arm otherwise, reacting live to OS theme changes.
+///
+/// On the server, `ssr_default` is used as the preference.
+///
+/// For more docs on allowed child components, check out
+/// [`ColorSchemeProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ColorScheme>
+///   <Dark>"🌙"</Dark>
+///   <Light>"☀️"</Light>
+/// </ColorScheme>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ColorScheme(
+  cx: Scope,
+  /// The preference assumed when it can't be read, such as on the server.
+  #[prop(optional)]
+  ssr_default: ColorSchemePreference,
+  /// The arms of each color scheme.
+  ///
+  /// Children must be any
+  /// - [`Dark`]
+  /// - [`Light`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let preference = use_color_scheme_with_default(cx, ssr_default);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    match preference.get() {
+      ColorSchemePreference::Dark => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<DarkBlock>)
+        .map(|block| (block.children)(cx).into_view(cx)),
+      ColorSchemePreference::Light => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<LightBlock>)
+        .map(|block| (block.children)(cx).into_view(cx)),
+    }
+    .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown while the user prefers a dark color scheme.
+#[component(transparent)]
+pub fn Dark(
+  cx: Scope,
+  /// What you want to show for a dark color scheme.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  DarkBlock { children }
+}
+
+/// Shown while the user prefers a light color scheme, or has no preference.
+#[component(transparent)]
+pub fn Light(
+  cx: Scope,
+  /// What you want to show for a light color scheme.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  LightBlock { children }
+}
+
+transparent_block! {
+  /// The dark arm returned by [`Dark`].
+  pub struct DarkBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The light arm returned by [`Light`].
+  pub struct LightBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// Returns the color scheme preferred by the user, which updates whenever
+/// the OS or browser theme changes.
+///
+/// [`ColorSchemePreference::Light`] is returned on the server.
+pub fn use_color_scheme(cx: Scope) -> Signal<ColorSchemePreference> {
+  use_color_scheme_with_default(cx, ColorSchemePreference::default())
+}
+
+/// Like [`use_color_scheme`], but returns `ssr_default` on the server.
+pub fn use_color_scheme_with_default(
+  cx: Scope,
+  ssr_default: ColorSchemePreference,
+) -> Signal<ColorSchemePreference> {
+  let is_dark = use_media_query(
+    cx,
+    "(prefers-color-scheme: dark)",
+    ssr_default == ColorSchemePreference::Dark,
+  );
+
+  Signal::derive(cx, move || {
+    if is_dark.get() {
+      ColorSchemePreference::Dark
+    } else {
+      ColorSchemePreference::Light
+    }
+  })
+}
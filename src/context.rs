@@ -0,0 +1,82 @@
+//! Providing and consuming context within views for [`leptos`].
+//!
+//! Please refer to [`ProvideContext`] for usage examples.
+
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <ProvideContext value=theme>
+      <ConsumeContext view=|cx, theme: Theme| view! { cx, <p>{theme.name}</p> } />
+    </ProvideContext>
+  }
+}
+
+/// Provides `value` as context to it's children, like calling
+/// [`provide_context`], but within the view.
+///
+/// The context is provided within a child scope, so it's only visible to the
+/// children, and not to the siblings of this component.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct Theme(&'static str);
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ProvideContext value=Theme("dark")>
+///   <ConsumeContext view=|cx, theme: Theme| view! { cx, <p>{theme.0}</p> } />
+/// </ProvideContext>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ProvideContext<T>(
+  cx: Scope,
+  /// The context to provide.
+  value: T,
+  /// The part of your app the context is provided to.
+  children: Children,
+) -> impl IntoView
+where
+  T: Clone + 'static,
+{
+  cx.run_child_scope(|cx| {
+    provide_context(cx, value);
+
+    children(cx)
+  })
+  .0
+}
+
+/// Reads the context of type `T`, like calling [`use_context`], and passes it
+/// to `view`.
+///
+/// When there is no such context, `fallback` is rendered instead.
+#[component]
+pub fn ConsumeContext<T, F, IV>(
+  cx: Scope,
+  /// Renders the content, given the context.
+  view: F,
+  /// Rendered when there is no context of type `T`. Defaults to rendering
+  /// nothing.
+  #[prop(optional)]
+  fallback: Option<Box<dyn Fn(Scope) -> View>>,
+) -> impl IntoView
+where
+  T: Clone + 'static,
+  F: FnOnce(Scope, T) -> IV,
+  IV: IntoView,
+{
+  match use_context::<T>(cx) {
+    Some(value) => view(cx, value).into_view(cx),
+    None => fallback
+      .map(|fallback| fallback(cx))
+      .unwrap_or_else(|| ().into_view(cx)),
+  }
+}
@@ -0,0 +1,85 @@
+//! Delayed rendering for [`leptos`].
+//!
+//! Please refer to [`Defer`] for usage examples.
+
+use crate::util::{
+  set_timeout,
+  Timeout,
+};
+use leptos::*;
+use std::time::Duration;
+
+api_planning! {
+  view! { cx,
+    <Defer delay=Duration::from_millis(200)>
+      <Spinner />
+    </Defer>
+  }
+}
+
+/// Renders it's children only once `delay` has elapsed since it was mounted,
+/// showing the `placeholder` until then.
+///
+/// This is handy for delaying spinners, so fast loads never flash them, and
+/// for staggering heavy content below the fold.
+///
+/// The delay only starts in the browser, so the placeholder is what gets
+/// rendered on the server.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Defer delay=Duration::from_millis(200)>
+///   <p>"Still loading..."</p>
+/// </Defer>
+/// <Defer
+///   delay=Duration::from_secs(1)
+///   placeholder=Box::new(|cx| view! { cx, <p>"Comments"</p> }.into_view(cx))
+/// >
+///   <p>"Lots of comments"</p>
+/// </Defer>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Defer(
+  cx: Scope,
+  /// How long to wait after mounting before rendering the children.
+  delay: Duration,
+  /// Rendered until `delay` has elapsed. Defaults to rendering nothing.
+  #[prop(optional)]
+  placeholder: Option<Box<dyn Fn(Scope) -> View>>,
+  /// What you want to render once `delay` has elapsed.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let elapsed = create_rw_signal(cx, false);
+  let timeout = store_value(cx, None::<Timeout>);
+
+  create_effect(cx, move |_| {
+    timeout.set_value(set_timeout(cx, move || elapsed.set(true), delay));
+  });
+
+  on_cleanup(cx, move || {
+    timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    })
+  });
+
+  move || {
+    if elapsed.get() {
+      children(cx).into_view(cx)
+    } else if let Some(placeholder) = &placeholder {
+      placeholder(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
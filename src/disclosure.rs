@@ -0,0 +1,238 @@
+//! Accessible disclosure widgets for [`leptos`].
+//!
+//! Please refer to [`Disclosure`] for usage examples.
+
+use crate::error::{
+  report,
+  Error,
+};
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <Disclosure>
+      <AccessibleToggleButton>"Show details"</AccessibleToggleButton>
+      <DisclosureRegion>
+        "The details"
+      </DisclosureRegion>
+    </Disclosure>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<Disclosure />",
+  context: "DisclosureState",
+};
+
+/// Links an [`AccessibleToggleButton`] with the region it controls, such as a
+/// [`DisclosureRegion`] or your own [`If`](crate::if_::If), wiring up all
+/// the ARIA attributes between the two.
+///
+/// The state of the disclosure can be read with [`use_disclosure`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Disclosure>
+///   <AccessibleToggleButton>"Show details"</AccessibleToggleButton>
+///   <DisclosureRegion>"The details"</DisclosureRegion>
+/// </Disclosure>
+/// };
+/// # });
+/// ```
+///
+/// ### With `If`
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let open = create_rw_signal(cx, false);
+///
+/// view! { cx,
+/// <Disclosure open id="details">
+///   <AccessibleToggleButton>"Show details"</AccessibleToggleButton>
+///   <div id="details">
+///     <If signal=open>
+///       <Then>"The details"</Then>
+///     </If>
+///   </div>
+/// </Disclosure>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Disclosure(
+  cx: Scope,
+  /// Binds the open state of the disclosure to this signal. Defaults to a
+  /// closed disclosure.
+  #[prop(optional)]
+  open: Option<RwSignal<bool>>,
+  /// The `id` of the controlled region. A unique one is generated by default,
+  /// which is the same on the server and on the hydrating client.
+  #[prop(optional, into)]
+  id: Option<String>,
+  /// Must contain an [`AccessibleToggleButton`] and the region it controls.
+  children: Children,
+) -> impl IntoView {
+  let open = open.unwrap_or_else(|| create_rw_signal(cx, false));
+
+  let id = id.unwrap_or_else(|| {
+    format!("leptos-declarative-disclosure-{}", next_id(cx))
+  });
+
+  provide_context(cx, DisclosureState { open, id });
+
+  children(cx)
+}
+
+/// The number of generated ids, kept in the root scope of the app.
+#[derive(Clone, Copy)]
+struct NextId(StoredValue<usize>);
+
+/// Generates the next id of the app which `cx` belongs to.
+///
+/// Ids start over for every app, and are generated in the same order on the
+/// server and on the hydrating client, since both render the same
+/// disclosures in the same order.
+fn next_id(cx: Scope) -> usize {
+  let mut root = cx;
+
+  while let Some(parent) = root.parent() {
+    root = parent;
+  }
+
+  let next_id = use_context::<NextId>(root).unwrap_or_else(|| {
+    let next_id = NextId(store_value(root, 0));
+
+    provide_context(root, next_id);
+
+    next_id
+  });
+
+  let id = next_id.0.get_value();
+
+  next_id.0.set_value(id + 1);
+
+  id
+}
+
+/// The state of the nearest [`Disclosure`].
+#[derive(Clone)]
+pub struct DisclosureState {
+  /// Whether the region is currently shown.
+  pub open: RwSignal<bool>,
+  /// The `id` of the controlled region.
+  pub id: String,
+}
+
+impl DisclosureState {
+  /// Shows the region if it's hidden, and vice versa.
+  pub fn toggle(&self) {
+    self.open.update(|open| *open = !*open);
+  }
+
+  fn toggle_id(&self) -> String {
+    format!("{}-toggle", self.id)
+  }
+}
+
+/// Gets the state of the nearest [`Disclosure`], if any.
+pub fn use_disclosure(cx: Scope) -> Option<DisclosureState> {
+  use_context(cx)
+}
+
+/// The control which toggles the nearest [`Disclosure`].
+///
+/// It renders an unstyled element with the `button` role around it's
+/// children, with `aria-expanded` and `aria-controls` kept in sync with the
+/// disclosure, and which can be activated with the mouse, <kbd>Enter</kbd>
+/// or <kbd>Space</kbd>.
+#[component]
+pub fn AccessibleToggleButton(
+  cx: Scope,
+  /// Classes added to the toggle element.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// The contents of the toggle.
+  children: Children,
+) -> impl IntoView {
+  let Some(disclosure) = use_disclosure(cx) else {
+    report(CONTEXT_NOT_FOUND_ERROR);
+
+    return ().into_view(cx);
+  };
+
+  let open = disclosure.open;
+  let toggle_id = disclosure.toggle_id();
+  let region_id = disclosure.id.clone();
+
+  let on_click = {
+    let disclosure = disclosure.clone();
+
+    move |_| disclosure.toggle()
+  };
+
+  let on_keydown = move |ev: web_sys::KeyboardEvent| {
+    if ev.key() == "Enter" || ev.key() == " " {
+      ev.prevent_default();
+
+      disclosure.toggle();
+    }
+  };
+
+  view! { cx,
+    <span
+      id=toggle_id
+      class=class
+      role="button"
+      tabindex="0"
+      aria-expanded=move || open.get().to_string()
+      aria-controls=region_id
+      on:click=on_click
+      on:keydown=on_keydown
+    >
+      {children(cx)}
+    </span>
+  }
+  .into_view(cx)
+}
+
+/// The region controlled by the nearest [`Disclosure`]. It's children are
+/// only rendered while the disclosure is open.
+#[component]
+pub fn DisclosureRegion(
+  cx: Scope,
+  /// Classes added to the region element.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// What you want to show while the disclosure is open.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let Some(disclosure) = use_disclosure(cx) else {
+    report(CONTEXT_NOT_FOUND_ERROR);
+
+    return ().into_view(cx);
+  };
+
+  let open = disclosure.open;
+  let toggle_id = disclosure.toggle_id();
+
+  view! { cx,
+    <div
+      id=disclosure.id
+      class=class
+      role="region"
+      aria-labelledby=toggle_id
+      hidden=move || !open.get()
+    >
+      {move || open.get().then(|| children(cx))}
+    </div>
+  }
+  .into_view(cx)
+}
@@ -0,0 +1,159 @@
+//! Rendering based on the visibility of the page for [`leptos`].
+//!
+//! Please refer to [`DocumentVisible`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use leptos_dom::Transparent;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <DocumentVisible>
+      <Visible><LiveFeed /></Visible>
+      <Hidden>"Paused"</Hidden>
+    </DocumentVisible>
+  }
+}
+
+/// Renders the [`Visible`] arm while the page is visible, and the [`Hidden`]
+/// arm while it's not, such as when the tab is in the background or the
+/// window is minimized, using the Page Visibility API.
+///
+/// This is handy for pausing animations and live feeds which nobody can see.
+/// The page is considered visible on the server.
+///
+/// For more docs on allowed child components, check out
+/// [`DocumentVisibleProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <DocumentVisible>
+///   <Visible><p>"Live"</p></Visible>
+///   <Hidden><p>"Paused"</p></Hidden>
+/// </DocumentVisible>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn DocumentVisible(
+  cx: Scope,
+  /// The arms of each visibility state.
+  ///
+  /// Children must be any
+  /// - [`Visible`]
+  /// - [`Hidden`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let visible = use_document_visible(cx);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if visible.get() {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<VisibleBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<HiddenBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    }
+    .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown while the page is visible.
+#[component(transparent)]
+pub fn Visible(
+  cx: Scope,
+  /// What you want to show while the page is visible.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  VisibleBlock { children }
+}
+
+/// Shown while the page is hidden.
+#[component(transparent)]
+pub fn Hidden(
+  cx: Scope,
+  /// What you want to show while the page is hidden.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  HiddenBlock { children }
+}
+
+transparent_block! {
+  /// The visible arm returned by [`Visible`].
+  pub struct VisibleBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The hidden arm returned by [`Hidden`].
+  pub struct HiddenBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// Returns whether the page is visible, which updates whenever the page is
+/// shown or hidden.
+///
+/// `true` is returned on the server.
+pub fn use_document_visible(cx: Scope) -> Signal<bool> {
+  let visible = create_rw_signal(cx, true);
+
+  create_effect(cx, move |_| {
+    let Some(document) = try_window().and_then(|window| window.document())
+    else {
+      return;
+    };
+
+    visible.set(!document.hidden());
+
+    let on_change = Closure::<dyn Fn()>::new({
+      let document = document.clone();
+
+      move || visible.set(!document.hidden())
+    });
+
+    let _ = document.add_event_listener_with_callback(
+      "visibilitychange",
+      on_change.as_ref().unchecked_ref(),
+    );
+
+    on_cleanup(cx, move || {
+      let _ = document.remove_event_listener_with_callback(
+        "visibilitychange",
+        on_change.as_ref().unchecked_ref(),
+      );
+    });
+  });
+
+  visible.into()
+}
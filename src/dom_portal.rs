@@ -0,0 +1,100 @@
+//! Portals into arbitrary DOM nodes for [`leptos`].
+//!
+//! Please refer to [`DomPortal`] for usage examples.
+
+use crate::{
+  error::{
+    report,
+    Error,
+  },
+  util::try_window,
+};
+use leptos::*;
+use leptos_dom::Mountable;
+
+api_planning! {
+  view! { cx,
+    <div style="overflow: hidden">
+      <DomPortal mount="#modals">
+        <div class="modal">"I escaped!"</div>
+      </DomPortal>
+    </div>
+  }
+}
+
+/// Renders it's children into `document.body`, or into the element matching
+/// the `mount` selector, instead of where it is declared.
+///
+/// This is useful for content which must escape it's ancestors, such as
+/// modals inside of an `overflow: hidden` container. Unlike
+/// [`PortalInput`](crate::portal::PortalInput), this does not require a
+/// matching [`PortalOutput`](crate::portal::PortalOutput) to exist.
+///
+/// The children are rendered inside of a `div` which is appended to the
+/// target element on mount, and removed on unmount. Nothing is rendered when
+/// not running in a browser.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <div style="overflow: hidden">
+///   <DomPortal>
+///     <div class="modal">"I escaped!"</div>
+///   </DomPortal>
+///   <DomPortal mount="#toasts" class="toast-container">
+///     <p>"Saved"</p>
+///   </DomPortal>
+/// </div>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn DomPortal(
+  cx: Scope,
+  /// A CSS selector for the element the children should be rendered in.
+  /// Defaults to `document.body`.
+  #[prop(optional, into)]
+  mount: Option<String>,
+  /// The `class` of the `div` the children are rendered in.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// What you want to render into the target element.
+  children: Children,
+) -> impl IntoView {
+  let Some(document) = try_window().and_then(|window| window.document()) else {
+    return;
+  };
+
+  let target = if let Some(selector) = &mount {
+    document.query_selector(selector).ok().flatten()
+  } else {
+    document.body().map(Into::into)
+  };
+
+  let Some(target) = target else {
+    debug_warn!("`<DomPortal />` could not find an element to mount to");
+
+    return;
+  };
+
+  let Ok(container) = document.create_element("div") else {
+    report(Error::DomPortalContainer);
+
+    return;
+  };
+
+  if let Some(class) = class {
+    container.set_class_name(&class);
+  }
+
+  let _ =
+    container.append_child(&children(cx).into_view(cx).get_mountable_node());
+  let _ = target.append_child(&container);
+
+  on_cleanup(cx, move || container.remove());
+}
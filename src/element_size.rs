@@ -0,0 +1,141 @@
+//! Rendering based on the size of an element for [`leptos`].
+//!
+//! Please refer to [`ElementSize`] for usage examples.
+
+use crate::util::try_window;
+use leptos::{
+  html::{
+    Div,
+    ElementDescriptor,
+  },
+  *,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <ElementSize view=|cx, size: Signal<Dimensions>| view! { cx,
+      <If signal=Signal::derive(cx, move || size.get().width > 600.0)>
+        <Then><TwoColumns /></Then>
+        <Else><OneColumn /></Else>
+      </If>
+    } />
+  }
+}
+
+/// The size of an element's content box, in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dimensions {
+  /// The width of the element.
+  pub width: f64,
+  /// The height of the element.
+  pub height: f64,
+}
+
+/// Measures it's own size with a `ResizeObserver`, and passes it to `view`,
+/// which allows rendering based on the size of a container rather than the
+/// viewport, like container queries.
+///
+/// `view` is rendered within a `div` whose size is measured, and is only
+/// called once, so it should read the size reactively, such as with an
+/// [`If`](crate::if_::If). The size is `0.0` by `0.0` on the server, and
+/// until the element is first measured in the browser.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ElementSize view=|cx, size: Signal<Dimensions>| view! { cx,
+///   <If signal=Signal::derive(cx, move || size.get().width > 600.0)>
+///     <Then>"Wide"</Then>
+///     <Else>"Narrow"</Else>
+///   </If>
+/// } />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ElementSize<F, IV>(
+  cx: Scope,
+  /// Renders the content, given the size of the wrapping `div`.
+  view: F,
+  /// The `class` of the wrapping `div`.
+  #[prop(optional, into)]
+  class: Option<String>,
+) -> impl IntoView
+where
+  F: FnOnce(Scope, Signal<Dimensions>) -> IV,
+  IV: IntoView,
+{
+  let container = create_node_ref::<Div>(cx);
+  let size = use_element_size(cx, container);
+
+  view! { cx,
+    <div class=class node_ref=container>
+      {view(cx, size)}
+    </div>
+  }
+}
+
+/// Returns the size of the element of `target`, which updates whenever the
+/// element is resized.
+///
+/// The size is `0.0` by `0.0` on the server, and until the element is
+/// mounted and measured in the browser.
+pub fn use_element_size<T>(cx: Scope, target: NodeRef<T>) -> Signal<Dimensions>
+where
+  T: ElementDescriptor + Clone + 'static,
+{
+  let size = create_rw_signal(cx, Dimensions::default());
+
+  create_effect(cx, move |_| {
+    let Some(element) = target.get() else {
+      return;
+    };
+
+    if try_window().is_none() {
+      return;
+    }
+
+    let on_resize =
+      Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+        let Some(entry) = entries.iter().last() else {
+          return;
+        };
+
+        let rect = entry
+          .unchecked_into::<web_sys::ResizeObserverEntry>()
+          .content_rect();
+
+        size.set(Dimensions {
+          width: rect.width(),
+          height: rect.height(),
+        });
+      });
+
+    let Ok(observer) =
+      web_sys::ResizeObserver::new(on_resize.as_ref().unchecked_ref())
+    else {
+      return;
+    };
+
+    let element = element.into_any();
+
+    observer.observe(&element);
+
+    on_cleanup(cx, move || {
+      observer.disconnect();
+
+      drop(on_resize);
+    });
+  });
+
+  size.into()
+}
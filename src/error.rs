@@ -0,0 +1,162 @@
+//! Errors caused by misusing the components of this crate.
+//!
+//! Please refer to [`set_error_handler`] for usage examples.
+
+use leptos::*;
+use std::{
+  cell::RefCell,
+  fmt,
+  rc::Rc,
+};
+
+thread_local! {
+  static HANDLER: RefCell<Option<Rc<dyn Fn(&Error)>>> = RefCell::new(None);
+}
+
+/// A misuse of one of the components of this crate, such as an [`If`] whose
+/// first child isn't [`Then`], or a hook used without it's provider above.
+///
+/// Errors are passed to the handler set with [`set_error_handler`]. Where
+/// possible, the misused component renders nothing afterwards.
+///
+/// [`If`]: crate::if_::If
+/// [`Then`]: crate::if_::Then
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+  /// A component or hook which relies on context was used without it's
+  /// provider above, such as a [`PortalInput`] without a [`PortalProvider`].
+  ///
+  /// [`PortalInput`]: crate::portal::PortalInput
+  /// [`PortalProvider`]: crate::portal::PortalProvider
+  MissingProvider {
+    /// The name of the missing provider, such as `<PortalProvider />`.
+    provider: &'static str,
+    /// The name of the context which wasn't found.
+    context: &'static str,
+  },
+  /// The first child of an `<If />` isn't a `<Then />`.
+  MissingThen,
+  /// An `<If />` has more than one `<Then />`.
+  DuplicateThen,
+  /// The `<Else />` of an `<If />` isn't it's last child.
+  MisplacedElse,
+  /// An `<If />` has more than one `<Else />`.
+  DuplicateElse,
+  /// A list, such as `<ForEach />`, has no `<Item />` whose `view` takes it's
+  /// items.
+  MissingItem,
+  /// The keys of a `<Hotkey />` are not a valid shortcut.
+  InvalidHotkey(String),
+  /// A `<Breakpoint />` uses a breakpoint which wasn't configured in the
+  /// `<BreakpointProvider />`.
+  UnknownBreakpoint,
+  /// The container element of a `<DomPortal />` could not be created.
+  DomPortalContainer,
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::MissingProvider { provider, context } => write!(
+        f,
+        "failed to find `{context}`, make sure you are using `{provider}` \
+         above where it's used"
+      ),
+      Self::MissingThen => {
+        f.write_str("`<Then />` must be the first child of `<If />`")
+      }
+      Self::DuplicateThen => f.write_str(
+        "there must not be more than 1 `<Then />` children within `<If />`",
+      ),
+      Self::MisplacedElse => {
+        f.write_str("`<Else />` must be the last child of `<If />`")
+      }
+      Self::DuplicateElse => f.write_str(
+        "there must not be more than 1 `<Else />` children within `<If />`",
+      ),
+      Self::MissingItem => f.write_str(
+        "lists such as `<ForEach />` must have an `<Item />` child whose \
+         `view` takes the items of `each`",
+      ),
+      Self::InvalidHotkey(keys) => {
+        write!(f, "`{keys}` is not a valid hotkey")
+      }
+      Self::UnknownBreakpoint => {
+        f.write_str("breakpoint is not configured in `<BreakpointProvider />`")
+      }
+      Self::DomPortalContainer => {
+        f.write_str("failed to create the `<DomPortal />` container")
+      }
+    }
+  }
+}
+
+impl std::error::Error for Error {}
+
+/// Sets the handler which errors on the current thread are passed to.
+///
+/// By default, errors panic in debug builds, and are logged to the console
+/// in release builds, with the misused component rendering nothing.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::error::set_error_handler;
+///
+/// set_error_handler(|error| error!("leptos_declarative: {error}"));
+/// ```
+pub fn set_error_handler(handler: impl Fn(&Error) + 'static) {
+  HANDLER.with(|current| *current.borrow_mut() = Some(Rc::new(handler)));
+}
+
+/// Passes `error` to the current error handler.
+// Which of these are used depends on the enabled features
+#[allow(dead_code)]
+pub(crate) fn report(error: Error) {
+  let handler = HANDLER.with(|handler| handler.borrow().clone());
+
+  match handler {
+    Some(handler) => handler(&error),
+    None => default_handler(&error),
+  }
+}
+
+/// Passes `error` to the current error handler, and panics if it returns.
+#[allow(dead_code)]
+pub(crate) fn fail(error: Error) -> ! {
+  let message = error.to_string();
+
+  report(error);
+
+  panic!("{message}")
+}
+
+/// Gets `T` from context, or [`fail`]s with `error`.
+#[allow(dead_code)]
+pub(crate) fn expect_context<T: Clone + 'static>(cx: Scope, error: Error) -> T {
+  use_context(cx).unwrap_or_else(|| fail(error))
+}
+
+/// Gets `T` from context, or reports `error` and returns `None`.
+#[allow(dead_code)]
+pub(crate) fn context_or_report<T: Clone + 'static>(
+  cx: Scope,
+  error: Error,
+) -> Option<T> {
+  let value = use_context(cx);
+
+  if value.is_none() {
+    report(error);
+  }
+
+  value
+}
+
+fn default_handler(error: &Error) {
+  if cfg!(debug_assertions) {
+    panic!("{error}");
+  } else {
+    error!("{error}");
+  }
+}
@@ -0,0 +1,181 @@
+//! Typed error boundaries for [`leptos`].
+//!
+//! Please refer to [`ErrorCatch`] for usage examples.
+
+use leptos::*;
+use leptos_dom::Transparent;
+
+api_planning! {
+  view! { cx,
+    <ErrorCatch>
+      <UserProfile />
+
+      <Catch view=|cx, error: NotFound| view! { cx, <p>"No such user"</p> } />
+      <Catch view=|cx, error: Unauthorized| view! { cx, <LoginForm /> } />
+      <CatchAll view=|cx, errors| view! { cx, <p>"Something went wrong"</p> } />
+    </ErrorCatch>
+  }
+}
+
+/// An error boundary, like leptos' [`ErrorBoundary`], which renders a
+/// different arm for each type of error.
+///
+/// Errors are caught from any child which renders a [`Result`]. While there
+/// are errors, the first [`Catch`] arm whose error type matches one of them
+/// is shown, or the [`CatchAll`] arm if none do. The children are kept
+/// around, hidden, so they can clear their errors once they recover.
+///
+/// For more docs on allowed child components, check out
+/// [`ErrorCatchProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct NotFound;
+///
+/// impl std::fmt::Display for NotFound {
+///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///     f.write_str("not found")
+///   }
+/// }
+///
+/// impl std::error::Error for NotFound {}
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let user = move || Err::<String, _>(NotFound);
+///
+/// view! { cx,
+/// <ErrorCatch>
+///   {user}
+///
+///   <Catch view=|cx, _: NotFound| view! { cx, <p>"No such user"</p> } />
+///   <CatchAll view=|cx, _| view! { cx, <p>"Something went wrong"</p> } />
+/// </ErrorCatch>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ErrorCatch(
+  cx: Scope,
+  /// The content whose errors should be caught, along with the arms.
+  ///
+  /// Arms can be any
+  /// - [`Catch`]
+  /// - [`CatchAll`]
+  ///
+  /// Any other child is rendered as the content.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let errors = create_rw_signal(cx, Errors::default());
+
+  provide_context(cx, errors);
+
+  let children = children(cx);
+
+  let (blocks, content): (Vec<_>, Vec<_>) =
+    children.as_children().iter().cloned().partition(|child| {
+      child.as_transparent().map_or(false, |block| {
+        block.downcast_ref::<CatchBlock>().is_some()
+          || block.downcast_ref::<CatchAllBlock>().is_some()
+      })
+    });
+
+  let blocks = blocks
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let content = Fragment::new(content).into_view(cx);
+
+  move || {
+    let caught = errors.get();
+
+    if caught.is_empty() {
+      return content.clone();
+    }
+
+    let arm = blocks
+      .iter()
+      .filter_map(Transparent::downcast_ref::<CatchBlock>)
+      .find_map(|block| (block.view)(cx, &caught))
+      .or_else(|| {
+        blocks
+          .iter()
+          .find_map(Transparent::downcast_ref::<CatchAllBlock>)
+          .map(|block| (block.view)(cx, errors))
+      })
+      .unwrap_or_else(|| ().into_view(cx));
+
+    let hidden = html::div(cx)
+      .attr("style", "display: none")
+      .child(content.clone())
+      .into_view(cx);
+
+    Fragment::new(vec![arm, hidden]).into_view(cx)
+  }
+}
+
+/// Shown when the parent [`ErrorCatch`] caught an error of type `E`.
+///
+/// The first matching [`Catch`] arm is shown, so more specific arms should
+/// come first.
+#[component(transparent)]
+pub fn Catch<F, E, IV>(
+  cx: Scope,
+  /// Renders the caught error.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, E) -> IV + 'static,
+  E: std::error::Error + Clone + 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  CatchBlock {
+    view: Box::new(move |cx, errors| {
+      let error = errors
+        .iter()
+        .find_map(|(_, error)| error.downcast_ref::<E>().cloned())?;
+
+      Some(view(cx, error).into_view(cx))
+    }),
+  }
+}
+
+/// Shown when the parent [`ErrorCatch`] caught errors which no [`Catch`]
+/// arm matches.
+#[component(transparent)]
+pub fn CatchAll<F, IV>(
+  cx: Scope,
+  /// Renders the caught errors.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, RwSignal<Errors>) -> IV + 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  CatchAllBlock {
+    view: Box::new(move |cx, errors| view(cx, errors).into_view(cx)),
+  }
+}
+
+transparent_block! {
+  /// A typed error arm returned by [`Catch`].
+  pub struct CatchBlock {
+    view: Box<dyn Fn(Scope, &Errors) -> Option<View>>,
+  }
+}
+
+transparent_block! {
+  /// The fallback error arm returned by [`CatchAll`].
+  pub struct CatchAllBlock {
+    view: Box<dyn Fn(Scope, RwSignal<Errors>) -> View>,
+  }
+}
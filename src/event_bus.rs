@@ -0,0 +1,214 @@
+//! A typed publish/subscribe event bus for [`leptos`].
+//!
+//! Please refer to [`EventBusProvider`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+use std::{
+  any::{
+    Any,
+    TypeId,
+  },
+  cell::{
+    Cell,
+    RefCell,
+  },
+  collections::HashMap,
+  rc::Rc,
+};
+
+api_planning! {
+  view! { cx,
+    <EventBusProvider>
+      <CartButton />
+      <OnEvent view=|cx, added: ItemAdded| view! { cx,
+        <Toast>{added.name}" was added to your cart"</Toast>
+      } />
+    </EventBusProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<EventBusProvider />",
+  context: "EventBus",
+};
+
+/// Provides an [`EventBus`], which any component below it can emit events
+/// through, and which [`OnEvent`] and [`EventBus::subscribe`] receive events
+/// from.
+///
+/// Events are plain types, and are delivered to every receiver of the same
+/// type. This complements portals for communicating data, rather than views,
+/// between unrelated parts of the tree.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct ItemAdded(String);
+///
+/// #[component]
+/// fn AddButton(cx: Scope) -> impl IntoView {
+///   let bus = use_event_bus(cx);
+///
+///   view! { cx,
+///     <button on:click=move |_| bus.emit(ItemAdded("Socks".into()))>"Add"</button>
+///   }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <EventBusProvider>
+///   <AddButton />
+///   <OnEvent view=|cx, added: ItemAdded| view! { cx,
+///     <p>{added.0}" was added to your cart"</p>
+///   } />
+/// </EventBusProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn EventBusProvider(
+  cx: Scope,
+  /// The part of your app which uses the event bus.
+  children: Children,
+) -> impl IntoView {
+  provide_context(
+    cx,
+    EventBus {
+      cx,
+      channels: store_value(cx, Default::default()),
+    },
+  );
+
+  children(cx)
+}
+
+/// Renders the latest event of type `T` emitted through the nearest
+/// [`EventBusProvider`], and re-renders with every new event.
+///
+/// Nothing is rendered until the first event is emitted.
+///
+/// # Panics
+/// Panics if there is no [`EventBusProvider`] above.
+#[component]
+pub fn OnEvent<T, F, IV>(
+  cx: Scope,
+  /// Renders the latest event.
+  view: F,
+) -> impl IntoView
+where
+  T: Clone + 'static,
+  F: Fn(Scope, T) -> IV + 'static,
+  IV: IntoView,
+{
+  let latest = use_event_bus(cx).latest::<T>();
+
+  move || latest.get().map(|event| view(cx, event))
+}
+
+/// The events of a single type.
+struct Channel<T: 'static> {
+  latest: RwSignal<Option<T>>,
+  subscribers: RefCell<Vec<(usize, Rc<dyn Fn(T)>)>>,
+  next_id: Cell<usize>,
+}
+
+/// Emits and receives typed events.
+#[derive(Clone, Copy)]
+pub struct EventBus {
+  cx: Scope,
+  channels: StoredValue<HashMap<TypeId, Rc<dyn Any>>>,
+}
+
+impl EventBus {
+  /// Delivers `event` to every [`OnEvent`] and subscriber of type `T`.
+  pub fn emit<T: Clone + 'static>(&self, event: T) {
+    let channel = self.channel::<T>();
+
+    // Cloned, so subscribers can subscribe and unsubscribe while handling
+    // the event
+    let subscribers = channel
+      .subscribers
+      .borrow()
+      .iter()
+      .map(|(_, subscriber)| subscriber.clone())
+      .collect::<Vec<_>>();
+
+    for subscriber in subscribers {
+      subscriber(event.clone());
+    }
+
+    channel.latest.set(Some(event));
+  }
+
+  /// Returns the latest event of type `T`, if any was emitted.
+  pub fn latest<T: Clone + 'static>(&self) -> Signal<Option<T>> {
+    self.channel::<T>().latest.into()
+  }
+
+  /// Calls `on_event` with every event of type `T`, until `cx` is cleaned
+  /// up.
+  pub fn subscribe<T: Clone + 'static>(
+    &self,
+    cx: Scope,
+    on_event: impl Fn(T) + 'static,
+  ) {
+    let channel = self.channel::<T>();
+
+    let id = channel.next_id.get();
+    channel.next_id.set(id + 1);
+
+    channel
+      .subscribers
+      .borrow_mut()
+      .push((id, Rc::new(on_event)));
+
+    on_cleanup(cx, move || {
+      channel
+        .subscribers
+        .borrow_mut()
+        .retain(|(subscriber, _)| *subscriber != id)
+    });
+  }
+
+  /// Returns the channel of type `T`, creating it if needed.
+  fn channel<T: Clone + 'static>(&self) -> Rc<Channel<T>> {
+    let id = TypeId::of::<T>();
+
+    let channel = self
+      .channels
+      .with_value(|channels| channels.get(&id).cloned())
+      .unwrap_or_else(|| {
+        let channel: Rc<dyn Any> = Rc::new(Channel::<T> {
+          latest: create_rw_signal(self.cx, None),
+          subscribers: Default::default(),
+          next_id: Default::default(),
+        });
+
+        self.channels.update_value(|channels| {
+          channels.insert(id, channel.clone());
+        });
+
+        channel
+      });
+
+    channel
+      .downcast()
+      .unwrap_or_else(|_| unreachable!("channels are keyed by their type"))
+  }
+}
+
+/// Returns the [`EventBus`] of the nearest [`EventBusProvider`].
+///
+/// # Panics
+/// Panics if there is no [`EventBusProvider`] above.
+pub fn use_event_bus(cx: Scope) -> EventBus {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
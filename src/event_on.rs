@@ -0,0 +1,207 @@
+//! Declarative event listeners for [`leptos`].
+//!
+//! Please refer to [`EventOn`] for usage examples.
+
+use crate::util::try_window;
+use leptos::{
+  html::ElementDescriptor,
+  *,
+};
+use std::rc::Rc;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <If signal=editing>
+      <Then>
+        <EventOn target=ListenerTarget::Window event="beforeunload" on_event=Box::new(warn) />
+        <Editor />
+      </Then>
+    </If>
+  }
+}
+
+/// Listens to `event` on `target` for as long as this component is mounted,
+/// calling `on_event` and setting `signal` with every event, and renders
+/// it's children, if any.
+///
+/// This replaces attaching listeners by hand and removing them on cleanup,
+/// which is especially handy within conditional content, as the listener is
+/// removed along with it.
+///
+/// The type of the event can be any event type from `web-sys`, such as
+/// [`web_sys::KeyboardEvent`], and must match the events fired for `event`.
+///
+/// Nothing is listened to when not running in a browser.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (key, set_key) = create_signal(cx, None::<web_sys::KeyboardEvent>);
+///
+/// view! { cx,
+/// <EventOn target=ListenerTarget::Document event="keydown" signal=set_key />
+/// <p>{move || key.get().map(|ev| ev.key())}</p>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn EventOn<E>(
+  cx: Scope,
+  /// What to listen to. Defaults to [`ListenerTarget::Window`].
+  #[prop(optional, into)]
+  target: Option<ListenerTarget>,
+  /// The name of the event, such as `"resize"`.
+  #[prop(into)]
+  event: String,
+  /// Called with every event.
+  #[prop(optional)]
+  on_event: Option<Box<dyn Fn(E)>>,
+  /// Set to the latest event.
+  #[prop(optional)]
+  signal: Option<WriteSignal<Option<E>>>,
+  /// Whether to listen during the capture phase.
+  #[prop(optional)]
+  capture: bool,
+  /// The content.
+  #[prop(optional)]
+  children: Option<Children>,
+) -> impl IntoView
+where
+  E: JsCast + Clone + 'static,
+{
+  listen(
+    cx,
+    target.unwrap_or(ListenerTarget::Window),
+    event,
+    capture,
+    move |ev: E| {
+      if let Some(signal) = signal {
+        signal.set(Some(ev.clone()));
+      }
+
+      if let Some(on_event) = &on_event {
+        on_event(ev);
+      }
+    },
+  );
+
+  children.map(|children| children(cx))
+}
+
+/// What an [`EventOn`] listens to.
+#[derive(Clone)]
+pub enum ListenerTarget {
+  /// The `window`.
+  Window,
+  /// The `document`.
+  Document,
+  /// An element, which is usually created from a [`NodeRef`]. Returns
+  /// [`None`] while the element isn't available.
+  Element(Rc<dyn Fn() -> Option<web_sys::EventTarget>>),
+}
+
+impl<T: ElementDescriptor + Clone + 'static> From<NodeRef<T>>
+  for ListenerTarget
+{
+  fn from(node_ref: NodeRef<T>) -> Self {
+    Self::Element(Rc::new(move || {
+      node_ref
+        .get()
+        .map(|element| (*element.into_any()).clone().into())
+    }))
+  }
+}
+
+/// Listens to `event` on `target` until the current scope is cleaned up,
+/// calling `on_event` with every event.
+///
+/// Element targets are listened to once the element is available, and
+/// listened to again whenever it changes.
+pub fn use_event_listener<E>(
+  cx: Scope,
+  target: impl Into<ListenerTarget>,
+  event: impl Into<String>,
+  on_event: impl Fn(E) + 'static,
+) where
+  E: JsCast + 'static,
+{
+  listen(cx, target.into(), event.into(), false, on_event);
+}
+
+fn listen<E>(
+  cx: Scope,
+  target: ListenerTarget,
+  event: String,
+  capture: bool,
+  on_event: impl Fn(E) + 'static,
+) where
+  E: JsCast + 'static,
+{
+  let on_event = Rc::new(on_event);
+  // Removes the listener from the previous target
+  let remove = store_value(cx, None::<Box<dyn FnOnce()>>);
+  let stop = move || {
+    remove.update_value(|remove| {
+      if let Some(remove) = remove.take() {
+        remove();
+      }
+    })
+  };
+
+  create_effect(cx, move |_| {
+    stop();
+
+    let Some(window) = try_window() else {
+      return;
+    };
+
+    let target: web_sys::EventTarget = match &target {
+      ListenerTarget::Window => window.into(),
+      ListenerTarget::Document => {
+        let Some(document) = window.document() else {
+          return;
+        };
+
+        document.into()
+      }
+      ListenerTarget::Element(element) => {
+        let Some(element) = element() else {
+          return;
+        };
+
+        element
+      }
+    };
+
+    let listener = Closure::<dyn Fn(web_sys::Event)>::new({
+      let on_event = on_event.clone();
+
+      move |ev: web_sys::Event| on_event(ev.unchecked_into())
+    });
+
+    let _ = target.add_event_listener_with_callback_and_bool(
+      &event,
+      listener.as_ref().unchecked_ref(),
+      capture,
+    );
+
+    let event = event.clone();
+
+    remove.set_value(Some(Box::new(move || {
+      let _ = target.remove_event_listener_with_callback_and_bool(
+        &event,
+        listener.as_ref().unchecked_ref(),
+        capture,
+      );
+    })));
+  });
+
+  on_cleanup(cx, stop);
+}
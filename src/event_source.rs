@@ -0,0 +1,296 @@
+//! Declarative Server-Sent Events for [`leptos`].
+//!
+//! Please refer to [`EventSource`] for usage examples.
+
+use crate::{
+  error::{
+    expect_context,
+    Error,
+  },
+  util::try_window,
+};
+use leptos::*;
+use std::collections::HashMap;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+  JsValue,
+};
+
+api_planning! {
+  view! { cx,
+    <EventSource url="/api/feed">
+      <Open><LiveFeed /></Open>
+      <Errored>"Feed unavailable, retrying..."</Errored>
+    </EventSource>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<EventSource />",
+  context: "EventSourceHandle",
+};
+
+/// The state of the connection of an [`EventSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventSourceState {
+  /// The connection is being opened. This is also the state on the server,
+  /// where no connection is made.
+  Connecting,
+  /// The connection is open.
+  Open,
+  /// The connection failed, and the browser is reopening it.
+  Reconnecting,
+  /// The connection failed, and won't be reopened.
+  Closed,
+}
+
+/// Receives Server-Sent Events from `url` for as long as it's mounted, and
+/// provides an [`EventSourceHandle`] to it's children, which exposes the
+/// latest event of each name as a signal.
+///
+/// The browser reopens the connection whenever it's lost. The [`Open`] and
+/// [`Errored`] components below it render their children depending on the
+/// state of the connection.
+///
+/// No connection is made on the server.
+///
+/// This component is only available with the `event-source` feature.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn Scores(cx: Scope) -> impl IntoView {
+///   let score = use_event_source(cx).event("score");
+///
+///   view! { cx, <p>"Score: "{move || score.get()}</p> }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <EventSource url="/api/feed">
+///   <Open><Scores /></Open>
+///   <Errored>"Feed unavailable, retrying..."</Errored>
+/// </EventSource>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn EventSource(
+  cx: Scope,
+  /// The URL to receive events from.
+  #[prop(into)]
+  url: String,
+  /// Whether to send cookies to other origins.
+  #[prop(optional)]
+  with_credentials: bool,
+  /// The part of your app which uses the events.
+  children: Children,
+) -> impl IntoView {
+  let handle = EventSourceHandle {
+    state: create_rw_signal(cx, EventSourceState::Connecting),
+    source: store_value(cx, None),
+    events: store_value(cx, HashMap::new()),
+    cx,
+  };
+
+  create_effect(cx, move |_| {
+    if try_window().is_none() {
+      return;
+    }
+
+    let mut init = web_sys::EventSourceInit::new();
+    init.with_credentials(with_credentials);
+
+    let Ok(source) =
+      web_sys::EventSource::new_with_event_source_init_dict(&url, &init)
+    else {
+      handle.state.set(EventSourceState::Closed);
+
+      return;
+    };
+
+    let state = handle.state;
+
+    let on_open = Closure::<dyn Fn(JsValue)>::new(move |_| {
+      state.set(EventSourceState::Open)
+    });
+
+    let on_error = Closure::<dyn Fn(JsValue)>::new({
+      let source = source.clone();
+
+      move |_| {
+        state.set(if source.ready_state() == web_sys::EventSource::CLOSED {
+          EventSourceState::Closed
+        } else {
+          EventSourceState::Reconnecting
+        })
+      }
+    });
+
+    source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+    let mut source = Source {
+      source,
+      handlers: vec![on_open, on_error],
+    };
+
+    // Events which were asked for before the connection was made
+    handle.events.with_value(|events| {
+      for (name, event) in events {
+        source.listen(name, *event);
+      }
+    });
+
+    handle.source.set_value(Some(source));
+  });
+
+  on_cleanup(cx, move || handle.source.set_value(None));
+
+  provide_context(cx, handle);
+
+  children(cx)
+}
+
+/// Renders it's children while the connection of the nearest
+/// [`EventSource`] is open.
+///
+/// # Panics
+/// Panics if there is no [`EventSource`] above.
+#[component]
+pub fn Open(
+  cx: Scope,
+  /// What you want to render while connected.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let handle = use_event_source(cx);
+  let open =
+    create_memo(cx, move |_| handle.state.get() == EventSourceState::Open);
+
+  move || {
+    if open.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Renders it's children while the connection of the nearest
+/// [`EventSource`] failed, whether it's being reopened or not.
+///
+/// # Panics
+/// Panics if there is no [`EventSource`] above.
+#[component]
+pub fn Errored(
+  cx: Scope,
+  /// What you want to render while the connection failed.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let handle = use_event_source(cx);
+  let errored = create_memo(cx, move |_| {
+    matches!(
+      handle.state.get(),
+      EventSourceState::Reconnecting | EventSourceState::Closed
+    )
+  });
+
+  move || {
+    if errored.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Receives the events of an [`EventSource`].
+#[derive(Clone, Copy)]
+pub struct EventSourceHandle {
+  state: RwSignal<EventSourceState>,
+  source: StoredValue<Option<Source>>,
+  events: StoredValue<HashMap<String, RwSignal<Option<String>>>>,
+  cx: Scope,
+}
+
+impl EventSourceHandle {
+  /// Returns the state of the connection.
+  pub fn state(&self) -> Signal<EventSourceState> {
+    self.state.into()
+  }
+
+  /// Returns the data of the latest unnamed event, which are the events
+  /// without an `event` field.
+  pub fn message(&self) -> Signal<Option<String>> {
+    self.event("message")
+  }
+
+  /// Returns the data of the latest event named `name`, if any was received.
+  pub fn event(&self, name: &str) -> Signal<Option<String>> {
+    if let Some(event) =
+      self.events.with_value(|events| events.get(name).copied())
+    {
+      return event.into();
+    }
+
+    let event = create_rw_signal(self.cx, None);
+
+    self.events.update_value(|events| {
+      events.insert(name.to_string(), event);
+    });
+
+    self.source.update_value(|source| {
+      if let Some(source) = source {
+        source.listen(name, event);
+      }
+    });
+
+    event.into()
+  }
+}
+
+/// Gets the [`EventSourceHandle`] of the nearest [`EventSource`].
+///
+/// # Panics
+/// Panics if there is no [`EventSource`] above.
+pub fn use_event_source(cx: Scope) -> EventSourceHandle {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
+
+/// An open `EventSource`, along with it's event handlers.
+struct Source {
+  source: web_sys::EventSource,
+  handlers: Vec<Closure<dyn Fn(JsValue)>>,
+}
+
+impl Source {
+  /// Sets `event` to the data of every event named `name`.
+  fn listen(&mut self, name: &str, event: RwSignal<Option<String>>) {
+    let handler = Closure::<dyn Fn(JsValue)>::new(move |ev: JsValue| {
+      event.set(
+        ev.unchecked_into::<web_sys::MessageEvent>()
+          .data()
+          .as_string(),
+      )
+    });
+
+    let _ = self
+      .source
+      .add_event_listener_with_callback(name, handler.as_ref().unchecked_ref());
+
+    self.handlers.push(handler);
+  }
+}
+
+impl Drop for Source {
+  fn drop(&mut self) {
+    self.source.set_onopen(None);
+    self.source.set_onerror(None);
+    self.source.close();
+  }
+}
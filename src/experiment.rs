@@ -0,0 +1,317 @@
+//! A/B testing for [`leptos`].
+//!
+//! Please refer to [`ExperimentProvider`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::rc::Rc;
+
+api_planning! {
+  view! { cx,
+    <ExperimentProvider unit=user_id on_exposure=track>
+      <Experiment name="checkout">
+        <Variant name="control"><Checkout /></Variant>
+        <Variant name="one_page" weight=2><OnePageCheckout /></Variant>
+      </Experiment>
+    </ExperimentProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<ExperimentProvider />",
+  context: "ExperimentCtx",
+};
+
+/// Assigns the [`Experiment`]s below it to variants, deterministically for
+/// each `unit`, such as the ID of the user.
+///
+/// A unit is assigned to a variant by hashing the unit, the name of the
+/// experiment and `seed`, so the same user always sees the same variant of
+/// an experiment, while different experiments are assigned independently.
+/// Change `seed` to reshuffle all assignments.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ExperimentProvider
+///   unit="user-42"
+///   on_exposure=Box::new(|exposure: Exposure| {
+///     log!("{} saw {}", exposure.experiment, exposure.variant)
+///   })
+/// >
+///   <Experiment name="checkout">
+///     <Variant name="control">"Checkout"</Variant>
+///     <Variant name="one_page" weight=2>"One page checkout"</Variant>
+///   </Experiment>
+/// </ExperimentProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ExperimentProvider(
+  cx: Scope,
+  /// What is assigned to variants, such as the ID of the user.
+  #[prop(into)]
+  unit: MaybeSignal<String>,
+  /// Mixed into every assignment. Defaults to `0`.
+  #[prop(optional)]
+  seed: u64,
+  /// Called whenever an [`Experiment`] shows a variant. This is only called
+  /// in the browser, so exposures aren't reported twice when server
+  /// rendering.
+  #[prop(optional)]
+  on_exposure: Option<Box<dyn Fn(Exposure)>>,
+  /// The part of your app which runs experiments.
+  children: Children,
+) -> impl IntoView {
+  provide_context(
+    cx,
+    ExperimentCtx {
+      unit: Signal::derive(cx, move || unit.get()),
+      seed,
+      on_exposure: on_exposure.map(Rc::from),
+    },
+  );
+
+  children(cx)
+}
+
+/// Renders the [`Variant`] the unit of the nearest [`ExperimentProvider`] is
+/// assigned to.
+///
+/// Each variant is assigned a share of units proportional to it's `weight`.
+///
+/// For more docs on allowed child components, check out
+/// [`ExperimentProps::children`].
+///
+/// # Panics
+/// Panics if there is no [`ExperimentProvider`] above.
+#[component]
+pub fn Experiment(
+  cx: Scope,
+  /// The name of the experiment.
+  #[prop(into)]
+  name: String,
+  /// The variants of the experiment.
+  ///
+  /// Children must be [`Variant`]s. Any other child will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let ctx = expect_context::<ExperimentCtx>(cx, CONTEXT_NOT_FOUND_ERROR);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let weights = blocks
+    .iter()
+    .filter_map(Transparent::downcast_ref::<VariantBlock>)
+    .map(|block| block.weight)
+    .collect::<Vec<_>>();
+
+  let assigned = create_memo(cx, {
+    let name = name.clone();
+
+    move |_| {
+      ctx
+        .unit
+        .with(|unit| assign(&weights, unit, &name, ctx.seed))
+    }
+  });
+
+  if let Some(on_exposure) = ctx.on_exposure.clone() {
+    let blocks = blocks.clone();
+
+    create_effect(cx, move |_| {
+      let Some(variant) = assigned.get().and_then(|i| {
+        blocks
+          .iter()
+          .filter_map(Transparent::downcast_ref::<VariantBlock>)
+          .nth(i)
+      }) else {
+        return;
+      };
+
+      on_exposure(Exposure {
+        experiment: name.clone(),
+        variant: variant.name.clone(),
+      });
+    });
+  }
+
+  move || {
+    assigned
+      .get()
+      .and_then(|i| {
+        blocks
+          .iter()
+          .filter_map(Transparent::downcast_ref::<VariantBlock>)
+          .nth(i)
+      })
+      .map(|block| (block.children)(cx).into_view(cx))
+      .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// A variant of the parent [`Experiment`].
+#[component(transparent)]
+pub fn Variant(
+  cx: Scope,
+  /// The name of the variant, which is reported in [`Exposure`]s.
+  #[prop(into)]
+  name: String,
+  /// The share of units assigned to this variant, relative to the other
+  /// variants. Defaults to `1`.
+  #[prop(default = 1)]
+  weight: u32,
+  /// What you want to show for this variant.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  VariantBlock {
+    name,
+    weight,
+    children,
+  }
+}
+
+transparent_block! {
+  /// The variant arm returned by [`Variant`].
+  pub struct VariantBlock {
+    name: String,
+    weight: u32,
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// A variant of an [`Experiment`] being shown, which is passed to the
+/// `on_exposure` callback of the [`ExperimentProvider`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Exposure {
+  /// The name of the experiment.
+  pub experiment: String,
+  /// The name of the variant which was shown.
+  pub variant: String,
+}
+
+/// The state of the nearest [`ExperimentProvider`].
+#[derive(Clone)]
+struct ExperimentCtx {
+  unit: Signal<String>,
+  seed: u64,
+  on_exposure: Option<Rc<dyn Fn(Exposure)>>,
+}
+
+/// Returns the index of the variant `unit` is assigned to in `experiment`,
+/// given the weight of each variant.
+fn assign(
+  weights: &[u32],
+  unit: &str,
+  experiment: &str,
+  seed: u64,
+) -> Option<usize> {
+  let total = weights.iter().map(|weight| u64::from(*weight)).sum::<u64>();
+
+  if total == 0 {
+    return None;
+  }
+
+  // FNV-1a, as the hashers of `std` aren't stable across releases, which
+  // would reassign units whenever the compiler is updated
+  let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+
+  for byte in seed
+    .to_le_bytes()
+    .iter()
+    .chain(unit.as_bytes())
+    .chain(&[0])
+    .chain(experiment.as_bytes())
+  {
+    hash ^= u64::from(*byte);
+    hash = hash.wrapping_mul(0x0100_0000_01b3);
+  }
+
+  let mut bucket = hash % total;
+
+  weights.iter().position(|weight| {
+    let weight = u64::from(*weight);
+
+    if bucket < weight {
+      true
+    } else {
+      bucket -= weight;
+
+      false
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn assignments_are_stable() {
+    // Changing these reassigns the units of every running experiment
+    assert_eq!(assign(&[1, 1], "alice", "checkout", 0), Some(1));
+    assert_eq!(assign(&[1, 1], "bob", "checkout", 0), Some(0));
+    assert_eq!(assign(&[1, 2, 1], "alice", "checkout", 0), Some(1));
+    assert_eq!(assign(&[1, 2, 1], "bob", "checkout", 0), Some(0));
+    assert_eq!(assign(&[3, 1], "carol", "checkout", 0), Some(0));
+  }
+
+  #[test]
+  fn seed_reshuffles_assignments() {
+    assert_eq!(assign(&[1, 1], "alice", "checkout", 1), Some(0));
+    assert_eq!(assign(&[1, 1], "bob", "checkout", 1), Some(1));
+  }
+
+  #[test]
+  fn assignments_follow_weights() {
+    let weights = [1, 2, 1];
+    let units = 10_000;
+    let mut counts = [0; 3];
+
+    for unit in 0..units {
+      let variant = assign(&weights, &format!("user-{unit}"), "checkout", 0);
+
+      counts[variant.unwrap()] += 1;
+    }
+
+    for (count, weight) in counts.iter().zip(weights) {
+      let expected = units * weight / 4;
+
+      assert!(
+        count.abs_diff(expected) < units / 50,
+        "{counts:?} doesn't follow {weights:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn zero_weights_are_never_assigned() {
+    for unit in 0..1_000 {
+      let unit = format!("user-{unit}");
+
+      assert_eq!(assign(&[0, 1], &unit, "checkout", 0), Some(1));
+    }
+
+    assert_eq!(assign(&[0, 0], "alice", "checkout", 0), None);
+    assert_eq!(assign(&[], "alice", "checkout", 0), None);
+  }
+}
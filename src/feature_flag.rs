@@ -0,0 +1,237 @@
+//! Feature flags for [`leptos`].
+//!
+//! Please refer to [`FeatureFlagProvider`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  collections::HashMap,
+  future::Future,
+};
+
+api_planning! {
+  view! { cx,
+    <FeatureFlagProvider flags=initial_flags>
+      <Feature flag="new_nav">
+        <Enabled><NewNav /></Enabled>
+        <Disabled><OldNav /></Disabled>
+      </Feature>
+    </FeatureFlagProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<FeatureFlagProvider />",
+  context: "FeatureFlags",
+};
+
+/// Holds the feature flags used by [`Feature`] below it.
+///
+/// The flags can be changed at any time with the [`FeatureFlags`] returned by
+/// [`use_feature_flags`], such as to load them from your server. Flags which
+/// aren't set are disabled.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::collections::HashMap;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <FeatureFlagProvider flags=HashMap::from([("new_nav".to_string(), true)])>
+///   <Feature flag="new_nav">
+///     <Enabled>"New navigation"</Enabled>
+///     <Disabled>"Old navigation"</Disabled>
+///   </Feature>
+/// </FeatureFlagProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Loading flags
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::collections::HashMap;
+///
+/// #[component]
+/// fn LoadFlags(cx: Scope) -> impl IntoView {
+///   use_feature_flags(cx).load(async {
+///     // fetch the flags from your server
+///     HashMap::from([("new_nav".to_string(), true)])
+///   });
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <FeatureFlagProvider>
+///   <LoadFlags />
+///   <Feature flag="new_nav">
+///     <Enabled>"New navigation"</Enabled>
+///   </Feature>
+/// </FeatureFlagProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn FeatureFlagProvider(
+  cx: Scope,
+  /// The initial flags.
+  #[prop(optional)]
+  flags: HashMap<String, bool>,
+  /// The part of your app which uses the flags.
+  children: Children,
+) -> impl IntoView {
+  provide_context(
+    cx,
+    FeatureFlags {
+      flags: create_rw_signal(cx, flags),
+    },
+  );
+
+  children(cx)
+}
+
+/// Renders the [`Enabled`] arm while `flag` is enabled in the nearest
+/// [`FeatureFlagProvider`], and the [`Disabled`] arm otherwise.
+///
+/// For more docs on allowed child components, check out
+/// [`FeatureProps::children`].
+///
+/// # Panics
+/// Panics if there is no [`FeatureFlagProvider`] above.
+#[component]
+pub fn Feature(
+  cx: Scope,
+  /// The name of the flag.
+  #[prop(into)]
+  flag: String,
+  /// The arms of the flag.
+  ///
+  /// Children must be any
+  /// - [`Enabled`]
+  /// - [`Disabled`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let flags = use_feature_flags(cx);
+  let enabled = create_memo(cx, move |_| flags.is_enabled(&flag));
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if enabled.get() {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<EnabledBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<DisabledBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    }
+    .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown while the flag of the parent [`Feature`] is enabled.
+#[component(transparent)]
+pub fn Enabled(
+  cx: Scope,
+  /// What you want to show while the flag is enabled.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  EnabledBlock { children }
+}
+
+/// Shown while the flag of the parent [`Feature`] is disabled, or not set.
+#[component(transparent)]
+pub fn Disabled(
+  cx: Scope,
+  /// What you want to show while the flag is disabled.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  DisabledBlock { children }
+}
+
+transparent_block! {
+  /// The enabled arm returned by [`Enabled`].
+  pub struct EnabledBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The disabled arm returned by [`Disabled`].
+  pub struct DisabledBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// The flags of a [`FeatureFlagProvider`], returned by
+/// [`use_feature_flags`].
+#[derive(Clone, Copy)]
+pub struct FeatureFlags {
+  flags: RwSignal<HashMap<String, bool>>,
+}
+
+impl FeatureFlags {
+  /// Whether `flag` is enabled.
+  ///
+  /// This is reactive.
+  pub fn is_enabled(&self, flag: &str) -> bool {
+    self
+      .flags
+      .with(|flags| flags.get(flag).copied().unwrap_or_default())
+  }
+
+  /// Enables or disables `flag`.
+  pub fn set(&self, flag: impl Into<String>, enabled: bool) {
+    self.flags.update(|flags| {
+      flags.insert(flag.into(), enabled);
+    });
+  }
+
+  /// Replaces all flags.
+  pub fn set_all(&self, flags: HashMap<String, bool>) {
+    self.flags.set(flags);
+  }
+
+  /// Replaces all flags with the ones `flags` resolves to.
+  pub fn load(
+    &self,
+    flags: impl Future<Output = HashMap<String, bool>> + 'static,
+  ) {
+    let this = *self;
+
+    spawn_local(async move { this.set_all(flags.await) });
+  }
+}
+
+/// Gets the flags of the nearest [`FeatureFlagProvider`].
+///
+/// # Panics
+/// Panics if there is no [`FeatureFlagProvider`] above.
+pub fn use_feature_flags(cx: Scope) -> FeatureFlags {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
@@ -0,0 +1,150 @@
+//! Racing resources for [`leptos`].
+//!
+//! Please refer to [`First`] for usage examples.
+
+use crate::{
+  await_::render_pending,
+  skeleton::use_group_pending,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::rc::Rc;
+
+api_planning! {
+  view! { cx,
+    <First resources=vec![from_cache, from_network]>
+      <Pending>"Loading..."</Pending>
+      <Winner view=|cx, user: User| view! { cx, <p>{user.name}</p> } />
+    </First>
+  }
+}
+
+/// Renders the [`Winner`] arm with the data of whichever of the `resources`
+/// loads first, and the [`Pending`](crate::await_::Pending) arm until one
+/// does.
+///
+/// This is handy for cache-vs-network patterns, where a cached copy should be
+/// shown if it's available before the network responds. Once a resource has
+/// won, it keeps being shown, including while it refetches, since resources
+/// keep their data until new data arrives. Futures can take part by wrapping
+/// them in a resource.
+///
+/// When placed within a [`SkeletonGroup`](crate::skeleton::SkeletonGroup),
+/// the [`Pending`](crate::await_::Pending) arm is shown until the whole group
+/// has settled.
+///
+/// For more docs on allowed child components, check out
+/// [`FirstProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let from_cache = create_local_resource(cx, || (), |_| async { "cached".to_string() });
+/// let from_network = create_local_resource(cx, || (), |_| async { "fresh".to_string() });
+///
+/// view! { cx,
+/// <First resources=vec![from_cache, from_network]>
+///   <Pending>"Loading..."</Pending>
+///   <Winner view=|cx, value: String| view! { cx, <p>{value}</p> } />
+/// </First>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn First<S, T>(
+  cx: Scope,
+  /// The resources which race each other.
+  resources: Vec<Resource<S, T>>,
+  /// The arms of the race.
+  ///
+  /// Children must be any
+  /// - [`Pending`](crate::await_::Pending)
+  /// - [`Winner`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  S: Clone + 'static,
+  T: Clone + 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let resources = Rc::new(resources);
+
+  let winner = create_memo(cx, {
+    let resources = resources.clone();
+
+    move |prev: Option<&Option<usize>>| {
+      let is_loaded = |i: usize| resources[i].with(cx, |_| ()).is_some();
+
+      // Only the winner is tracked once it has data, so the others can't
+      // take over
+      if let Some(prev) = prev.copied().flatten() {
+        if is_loaded(prev) {
+          return Some(prev);
+        }
+      }
+
+      (0..resources.len()).find(|i| is_loaded(*i))
+    }
+  });
+
+  let pending = use_group_pending(cx, move || winner.get().is_none());
+
+  move || {
+    if pending.get() {
+      return render_pending(cx, &blocks);
+    }
+
+    let Some(data) = winner.get().and_then(|i| resources[i].read(cx)) else {
+      return render_pending(cx, &blocks);
+    };
+
+    blocks
+      .iter()
+      .find_map(Transparent::downcast_ref::<WinnerBlock<T>>)
+      .map(|block| (block.view)(cx, data))
+      .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown by the parent [`First`] with the data of the resource which loaded
+/// first.
+///
+/// The type of the data must match the data of the resources, otherwise this
+/// arm will never be rendered.
+#[component(transparent)]
+pub fn Winner<F, T, IV>(
+  cx: Scope,
+  /// Renders the data.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, T) -> IV + 'static,
+  T: 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  WinnerBlock {
+    view: Box::new(move |cx, data| view(cx, data).into_view(cx)),
+  }
+}
+
+transparent_block! {
+  /// The winning arm returned by [`Winner`].
+  pub struct WinnerBlock<T> {
+    view: Box<dyn Fn(Scope, T) -> View>,
+  }
+}
@@ -0,0 +1,121 @@
+//! Keeping keyboard focus within an element for [`leptos`].
+//!
+//! Please refer to [`FocusTrap`] for usage examples.
+
+use crate::util::{
+  active_element,
+  focus_first,
+  wrap_focus,
+};
+use leptos::{
+  html::Div,
+  *,
+};
+
+api_planning! {
+  view! { cx,
+    <FocusTrap active=menu_open>
+      <Menu />
+    </FocusTrap>
+  }
+}
+
+/// Keeps keyboard focus within it's children while `active`.
+///
+/// When activated, focus is moved to the first focusable child, and
+/// <kbd>Tab</kbd> and <kbd>Shift</kbd>+<kbd>Tab</kbd> wrap around the
+/// children instead of leaving them. When deactivated or unmounted, focus is
+/// returned to wherever it was before.
+///
+/// The children are wrapped in a `div`, which is focused itself if none of
+/// the children can be. [`Modal`](crate::modal::Modal) uses this for it's
+/// dialog.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (editing, set_editing) = create_signal(cx, false);
+///
+/// view! { cx,
+/// <FocusTrap active=editing>
+///   <input />
+///   <button on:click=move |_| set_editing(false)>"Done"</button>
+/// </FocusTrap>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn FocusTrap(
+  cx: Scope,
+  /// Whether focus is trapped. Defaults to `true`.
+  #[prop(into, default = true.into())]
+  active: MaybeSignal<bool>,
+  /// The `class` of the wrapping `div`.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// Extra attributes of the wrapping `div`.
+  #[prop(optional)]
+  attrs: Vec<(&'static str, String)>,
+  /// The content focus is trapped within.
+  children: Children,
+) -> impl IntoView {
+  let container = create_node_ref::<Div>(cx);
+  let restore_focus = store_value(cx, None::<web_sys::HtmlElement>);
+
+  let restore = move || {
+    if let Some(element) = restore_focus.get_value() {
+      restore_focus.set_value(None);
+
+      let _ = element.focus();
+    }
+  };
+
+  create_effect(cx, {
+    let active = active.clone();
+
+    move |was_active: Option<bool>| {
+      let is_active = active.get();
+
+      let Some(container) = container.get() else {
+        return false;
+      };
+
+      if is_active && was_active != Some(true) {
+        restore_focus.set_value(active_element());
+
+        focus_first(&container);
+      } else if !is_active && was_active == Some(true) {
+        restore();
+      }
+
+      is_active
+    }
+  });
+
+  on_cleanup(cx, restore);
+
+  let on_keydown = move |ev: web_sys::KeyboardEvent| {
+    if !active.get_untracked() {
+      return;
+    }
+
+    if let Some(container) = container.get_untracked() {
+      wrap_focus(&ev, &container);
+    }
+  };
+
+  let mut wrapper = html::div(cx)
+    .attr("tabindex", "-1")
+    .attr("class", class)
+    .on(ev::keydown, on_keydown)
+    .node_ref(container);
+
+  for (name, value) in attrs {
+    wrapper = wrapper.attr(name, value);
+  }
+
+  wrapper.child(children(cx))
+}
@@ -0,0 +1,176 @@
+//! Keyed lists with an empty state for [`leptos`].
+//!
+//! Please refer to [`ForEach`] for usage examples.
+
+use crate::error::{
+  report,
+  Error,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  hash::Hash,
+  rc::Rc,
+};
+
+api_planning! {
+  view! { cx,
+    <ForEach each=todos key=|todo| todo.id>
+      <Item view=|cx, todo: Todo| view! { cx, <li>{todo.title}</li> } />
+      <Empty>"Nothing to do!"</Empty>
+    </ForEach>
+  }
+}
+
+/// Renders a keyed list, like leptos' [`For`], and the [`Empty`] arm while
+/// the list has no items.
+///
+/// Each item is rendered with the `view` of the [`Item`] child, whose item
+/// type must match the items of `each`.
+///
+/// For more docs on allowed child components, check out
+/// [`ForEachProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (todos, _) = create_signal(cx, vec![(0, "Write docs")]);
+///
+/// view! { cx,
+/// <ul>
+///   <ForEach each=move || todos.get() key=|todo| todo.0>
+///     <Item view=|cx, todo: (usize, &str)| view! { cx, <li>{todo.1}</li> } />
+///     <Empty>"Nothing to do!"</Empty>
+///   </ForEach>
+/// </ul>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ForEach<IF, I, T, KF, K>(
+  cx: Scope,
+  /// Returns the items to render. This should be reactive.
+  each: IF,
+  /// Returns a key which uniquely identifies an item, so items are only
+  /// re-rendered when they are added.
+  key: KF,
+  /// The arms of the list.
+  ///
+  /// Children must be any
+  /// - [`Item`]
+  /// - [`Empty`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  ///
+  /// [`Item`] must be present.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  IF: Fn() -> I + 'static,
+  I: IntoIterator<Item = T>,
+  KF: Fn(&T) -> K + 'static,
+  K: Eq + Hash + 'static,
+  T: 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let item = blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<ItemBlock<T>>)
+    .map(|block| block.view.clone());
+
+  if item.is_none() {
+    report(Error::MissingItem);
+  }
+
+  let each = Rc::new(each);
+  let key = Rc::new(key);
+
+  let is_empty = create_memo(cx, {
+    let each = each.clone();
+
+    move |_| each().into_iter().next().is_none()
+  });
+
+  move || {
+    if is_empty.get() {
+      return blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<EmptyBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+        .unwrap_or_else(|| ().into_view(cx));
+    }
+
+    let Some(item) = item.clone() else {
+      return ().into_view(cx);
+    };
+
+    let each = each.clone();
+    let key = key.clone();
+
+    view! { cx,
+      <For
+        each=move || each()
+        key=move |data: &T| key(data)
+        view=move |cx, data| item(cx, data)
+      />
+    }
+    .into_view(cx)
+  }
+}
+
+/// Renders each item of the parent [`ForEach`], `PaginatedFor` or `VirtualFor`.
+#[component(transparent)]
+pub fn Item<F, T, IV>(
+  cx: Scope,
+  /// Renders a single item. The item type must match the items of the
+  /// parent [`ForEach`], otherwise this arm is ignored.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, T) -> IV + 'static,
+  T: 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  ItemBlock {
+    view: Rc::new(move |cx, item| view(cx, item).into_view(cx)),
+  }
+}
+
+/// Shown while the list of the parent [`ForEach`], `PaginatedFor` or `VirtualFor`, is empty.
+#[component(transparent)]
+pub fn Empty(
+  cx: Scope,
+  /// What you want to show when there are no items.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  EmptyBlock { children }
+}
+
+transparent_block! {
+  /// The item arm returned by [`Item`].
+  pub struct ItemBlock<T> {
+    pub(crate) view: Rc<dyn Fn(Scope, T) -> View>,
+  }
+}
+
+transparent_block! {
+  /// The empty arm returned by [`Empty`].
+  pub struct EmptyBlock {
+    pub(crate) children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
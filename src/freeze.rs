@@ -0,0 +1,110 @@
+//! Pausing the updates of signals for [`leptos`].
+//!
+//! Please refer to [`FreezeSignals`] for usage examples.
+
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <FreezeSignals when=dashboard_hidden>
+      <LiveDashboard />
+    </FreezeSignals>
+  }
+
+  let ticks = create_frozen_signal(cx, ticks);
+}
+
+/// Pauses the updates of signals created with [`create_frozen_signal`] below
+/// it while `when` is `true`.
+///
+/// While frozen, frozen signals keep their last value, so the views reading
+/// them show a snapshot and don't re-run. Once `when` becomes `false`, they
+/// catch up with the latest values. This is handy for pausing expensive live
+/// dashboards while they're hidden, such as behind an inactive
+/// [`Tab`](crate::tabs::Tab).
+///
+/// Only frozen signals are paused. Other signals, effects and resources below
+/// keep updating as usual, so read anything which should pause through
+/// [`create_frozen_signal`].
+///
+/// Nested freezes are frozen whenever any of their ancestors are.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn LiveCounter(cx: Scope, count: ReadSignal<u64>) -> impl IntoView {
+///   let count = create_frozen_signal(cx, count);
+///
+///   view! { cx, <p>{move || count.get()}</p> }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (count, _) = create_signal(cx, 0);
+/// let (hidden, _) = create_signal(cx, false);
+///
+/// view! { cx,
+/// <FreezeSignals when=hidden>
+///   <LiveCounter count />
+/// </FreezeSignals>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn FreezeSignals(
+  cx: Scope,
+  /// While this is `true`, frozen signals below are paused.
+  #[prop(into)]
+  when: MaybeSignal<bool>,
+  /// The part of your app whose frozen signals should be paused.
+  children: Children,
+) -> impl IntoView {
+  let parent = use_context::<FreezeCtx>(cx);
+
+  let frozen = create_memo(cx, move |_| {
+    parent.map_or(false, |parent| parent.0.get()) || when.get()
+  });
+
+  provide_context(cx, FreezeCtx(frozen));
+
+  children(cx)
+}
+
+#[derive(Clone, Copy)]
+struct FreezeCtx(Memo<bool>);
+
+/// Whether the nearest [`FreezeSignals`] is frozen, or `false` if there is
+/// none.
+///
+/// This is reactive.
+pub fn use_frozen(cx: Scope) -> Signal<bool> {
+  match use_context::<FreezeCtx>(cx) {
+    Some(ctx) => ctx.0.into(),
+    None => Signal::derive(cx, || false),
+  }
+}
+
+/// Returns a copy of `source`, which keeps it's last value while the nearest
+/// [`FreezeSignals`] is frozen, and catches up once it's not.
+///
+/// `source` isn't tracked while frozen, so nothing reading the returned
+/// signal re-runs. Without a [`FreezeSignals`] above, this is the same as
+/// `source`.
+pub fn create_frozen_signal<T>(
+  cx: Scope,
+  source: impl Into<Signal<T>>,
+) -> Signal<T>
+where
+  T: Clone + PartialEq + 'static,
+{
+  let source = source.into();
+  let frozen = use_frozen(cx);
+
+  create_memo(cx, move |prev: Option<&T>| match prev {
+    Some(prev) if frozen.get() => prev.clone(),
+    _ => source.get(),
+  })
+  .into()
+}
@@ -0,0 +1,82 @@
+//! Deferring the initialization of part of the UI for [`leptos`].
+//!
+//! Please refer to [`Gate`] for usage examples.
+
+use crate::once::{
+  Once,
+  OnceProps,
+};
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <Gate open=panel_open buffer=true>
+      <HeavyPanel />
+    </Gate>
+  }
+}
+
+/// Renders it's children while `open` is `true`.
+///
+/// With `buffer` set, this behaves like [`Once`]: nothing is rendered until
+/// `open` first becomes `true`, and the children are then kept mounted
+/// regardless of later changes. This is handy for not initializing a
+/// subsystem until the user opens it's panel once, while keeping it's state
+/// when the panel is closed again.
+///
+/// Without `buffer`, the children are rendered again each time the gate
+/// opens.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (panel_open, set_panel_open) = create_signal(cx, false);
+///
+/// view! { cx,
+/// <button on:click=move |_| set_panel_open.update(|open| *open = !*open)>
+///   "Toggle panel"
+/// </button>
+/// <Gate open=panel_open buffer=true>
+///   <textarea />
+/// </Gate>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Gate(
+  cx: Scope,
+  /// Whether the gate is open.
+  #[prop(into)]
+  open: MaybeSignal<bool>,
+  /// Keeps the children mounted once the gate was first opened.
+  #[prop(optional)]
+  buffer: bool,
+  /// What you want to render once the gate is open.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  if buffer {
+    return Once(
+      cx,
+      OnceProps {
+        when: open,
+        children: Box::new(move |cx| children(cx)),
+      },
+    )
+    .into_view(cx);
+  }
+
+  let open = create_memo(cx, move |_| open.get());
+
+  (move || {
+    let is_open = open.get();
+
+    #[cfg(feature = "performance")]
+    let _measure = crate::performance::measure_switch(cx);
+
+    is_open.then(|| cx.untrack(|| children(cx)))
+  })
+  .into_view(cx)
+}
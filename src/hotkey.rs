@@ -0,0 +1,347 @@
+//! Declarative keyboard shortcuts for [`leptos`].
+//!
+//! Please refer to [`Hotkey`] for usage examples.
+
+use crate::{
+  error::{
+    report,
+    Error,
+  },
+  util::try_window,
+};
+use leptos::*;
+use std::rc::Rc;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <Hotkey keys="ctrl+k" on_press=open_search prevent_default=true />
+    <Hotkey keys="shift+?" toggle=show_help />
+    <Hotkey keys="alt+enter" on_press=submit scoped=true>
+      <textarea />
+    </Hotkey>
+  }
+}
+
+/// Calls `on_press`, and toggles `toggle`, whenever the keyboard shortcut
+/// `keys` is pressed while this component is mounted.
+///
+/// `keys` is a list of modifiers followed by a key, separated by `+`, such
+/// as `ctrl+shift+k`. The modifiers are `ctrl`, `shift`, `alt` and `meta`,
+/// which `cmd` is an alias of. Letters and digits are compared with the
+/// [`code`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/code)
+/// of the pressed key, so `alt+k` also works on macOS, where `alt` changes
+/// the character typed. Other keys are compared case insensitively with the
+/// [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key),
+/// with `esc`, `space` and `plus` as aliases of `Escape`, the space bar and
+/// `+`, which can also be bound as `ctrl++`. Modifiers must match exactly, so
+/// `k` doesn't fire for `ctrl+k`.
+///
+/// The shortcut is global by default. Set `scoped` to only listen while focus
+/// is within the children, which are then wrapped in a `div`.
+///
+/// If `keys` has no key, or an unknown modifier,
+/// [`Error::InvalidHotkey`](crate::error::Error::InvalidHotkey) is reported
+/// and the children are rendered without listening.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let show_help = create_rw_signal(cx, false);
+///
+/// view! { cx,
+/// <Hotkey keys="shift+?" toggle=show_help />
+/// <Hotkey
+///   keys="ctrl+s"
+///   on_press=Box::new(|_| log!("saving..."))
+///   prevent_default=true
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Hotkey(
+  cx: Scope,
+  /// The keyboard shortcut, such as `ctrl+shift+k`.
+  #[prop(into)]
+  keys: String,
+  /// Called whenever the shortcut is pressed.
+  #[prop(optional)]
+  on_press: Option<Box<dyn Fn(web_sys::KeyboardEvent)>>,
+  /// Toggled whenever the shortcut is pressed.
+  #[prop(optional)]
+  toggle: Option<RwSignal<bool>>,
+  /// Prevents the browser's default action for the shortcut, such as saving
+  /// the page for `ctrl+s`.
+  #[prop(optional)]
+  prevent_default: bool,
+  /// Only listens while focus is within the children.
+  #[prop(optional)]
+  scoped: bool,
+  /// The `class` of the `div` wrapping the children when `scoped`.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// Rendered as is, or within a `div` when `scoped`.
+  #[prop(optional)]
+  children: Option<Children>,
+) -> impl IntoView {
+  let Some(shortcut) = Shortcut::parse(&keys) else {
+    report(Error::InvalidHotkey(keys));
+
+    return children.map(|children| children(cx)).into_view(cx);
+  };
+
+  let on_keydown = Rc::new(move |ev: web_sys::KeyboardEvent| {
+    if !shortcut.matches(&ev) {
+      return;
+    }
+
+    if prevent_default {
+      ev.prevent_default();
+    }
+
+    if let Some(toggle) = toggle {
+      toggle.update(|toggle| *toggle = !*toggle);
+    }
+
+    if let Some(on_press) = &on_press {
+      on_press(ev);
+    }
+  });
+
+  let children = children.map(|children| children(cx));
+
+  if scoped {
+    return view! { cx,
+      <div class=class on:keydown=move |ev| on_keydown(ev)>
+        {children}
+      </div>
+    }
+    .into_view(cx);
+  }
+
+  create_effect(cx, move |_| {
+    let Some(window) = try_window() else {
+      return;
+    };
+
+    let on_keydown = Closure::<dyn Fn(web_sys::KeyboardEvent)>::new({
+      let on_keydown = on_keydown.clone();
+
+      move |ev| on_keydown(ev)
+    });
+
+    let _ = window.add_event_listener_with_callback(
+      "keydown",
+      on_keydown.as_ref().unchecked_ref(),
+    );
+
+    on_cleanup(cx, move || {
+      let _ = window.remove_event_listener_with_callback(
+        "keydown",
+        on_keydown.as_ref().unchecked_ref(),
+      );
+    });
+  });
+
+  children.into_view(cx)
+}
+
+/// The modifiers of a [`Shortcut`], or of a pressed key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Modifiers {
+  ctrl: bool,
+  shift: bool,
+  alt: bool,
+  meta: bool,
+}
+
+impl Modifiers {
+  fn of(ev: &web_sys::KeyboardEvent) -> Self {
+    Self {
+      ctrl: ev.ctrl_key(),
+      shift: ev.shift_key(),
+      alt: ev.alt_key(),
+      meta: ev.meta_key(),
+    }
+  }
+}
+
+/// A parsed keyboard shortcut.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Shortcut {
+  modifiers: Modifiers,
+  key: String,
+}
+
+impl Shortcut {
+  fn parse(keys: &str) -> Option<Self> {
+    let keys = keys.trim();
+
+    // A trailing `+` binds the `+` key itself, as in `+` or `ctrl++`
+    let (modifiers, key) = if keys == "+" {
+      ("", "+")
+    } else if let Some(modifiers) = keys.strip_suffix("++") {
+      (modifiers, "+")
+    } else {
+      keys.rsplit_once('+').unwrap_or(("", keys))
+    };
+
+    let mut shortcut = Self {
+      key: match key.trim().to_lowercase().as_str() {
+        "" => return None,
+        "esc" => "escape".into(),
+        "space" => " ".into(),
+        "plus" => "+".into(),
+        key => key.into(),
+      },
+      ..Default::default()
+    };
+
+    if modifiers.is_empty() {
+      return Some(shortcut);
+    }
+
+    for modifier in modifiers.split('+') {
+      match modifier.trim().to_lowercase().as_str() {
+        "ctrl" | "control" => shortcut.modifiers.ctrl = true,
+        "shift" => shortcut.modifiers.shift = true,
+        "alt" | "option" => shortcut.modifiers.alt = true,
+        "meta" | "cmd" | "command" | "super" => shortcut.modifiers.meta = true,
+        _ => return None,
+      }
+    }
+
+    Some(shortcut)
+  }
+
+  fn matches(&self, ev: &web_sys::KeyboardEvent) -> bool {
+    self.matches_key(Modifiers::of(ev), &ev.key(), &ev.code())
+  }
+
+  /// Whether pressing `key`, with the physical `code`, while holding
+  /// `modifiers` triggers this shortcut.
+  fn matches_key(&self, modifiers: Modifiers, key: &str, code: &str) -> bool {
+    if modifiers != self.modifiers {
+      return false;
+    }
+
+    // Letters and digits are matched by their physical key, since modifiers
+    // change the `key` they produce, such as `alt+k` producing `˚` on macOS
+    let mut chars = self.key.chars();
+
+    match (chars.next(), chars.next()) {
+      (Some(c), None) if c.is_ascii_alphabetic() => {
+        code == format!("Key{}", c.to_ascii_uppercase())
+      }
+      (Some(c), None) if c.is_ascii_digit() => {
+        code == format!("Digit{c}") || code == format!("Numpad{c}")
+      }
+      _ => key.to_lowercase() == self.key,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn shortcut(keys: &str) -> Shortcut {
+    Shortcut::parse(keys).unwrap()
+  }
+
+  fn modifiers(ctrl: bool, shift: bool, alt: bool, meta: bool) -> Modifiers {
+    Modifiers {
+      ctrl,
+      shift,
+      alt,
+      meta,
+    }
+  }
+
+  #[test]
+  fn parses_modifiers_and_key() {
+    assert_eq!(
+      shortcut("Ctrl + Shift+K"),
+      Shortcut {
+        modifiers: modifiers(true, true, false, false),
+        key: "k".into(),
+      }
+    );
+    assert_eq!(
+      shortcut("cmd+option+k").modifiers,
+      modifiers(false, false, true, true)
+    );
+    assert_eq!(shortcut("k").modifiers, Modifiers::default());
+  }
+
+  #[test]
+  fn parses_aliases() {
+    assert_eq!(shortcut("esc").key, "escape");
+    assert_eq!(shortcut("ctrl+space").key, " ");
+    assert_eq!(shortcut("ctrl+plus").key, "+");
+  }
+
+  #[test]
+  fn parses_the_plus_key() {
+    assert_eq!(shortcut("+").key, "+");
+    assert_eq!(shortcut("+").modifiers, Modifiers::default());
+    assert_eq!(
+      shortcut("ctrl++"),
+      Shortcut {
+        modifiers: modifiers(true, false, false, false),
+        key: "+".into(),
+      }
+    );
+    assert_eq!(
+      shortcut("ctrl+shift++").modifiers,
+      modifiers(true, true, false, false)
+    );
+  }
+
+  #[test]
+  fn rejects_invalid_shortcuts() {
+    assert_eq!(Shortcut::parse(""), None);
+    assert_eq!(Shortcut::parse("ctrl+"), None);
+    assert_eq!(Shortcut::parse("ctrl+foo+k"), None);
+    assert_eq!(Shortcut::parse("ctrl++k"), None);
+  }
+
+  #[test]
+  fn matches_letters_and_digits_by_code() {
+    let alt = modifiers(false, false, true, false);
+
+    assert!(shortcut("alt+k").matches_key(alt, "˚", "KeyK"));
+    assert!(!shortcut("alt+k").matches_key(alt, "k", "KeyJ"));
+    assert!(shortcut("alt+1").matches_key(alt, "¡", "Digit1"));
+    assert!(shortcut("alt+1").matches_key(alt, "1", "Numpad1"));
+  }
+
+  #[test]
+  fn matches_other_keys_by_key() {
+    let shift = modifiers(false, true, false, false);
+
+    assert!(shortcut("shift+?").matches_key(shift, "?", "Slash"));
+    assert!(shortcut("esc").matches_key(
+      Modifiers::default(),
+      "Escape",
+      "Escape"
+    ));
+    assert!(shortcut("+").matches_key(Modifiers::default(), "+", "NumpadAdd"));
+  }
+
+  #[test]
+  fn modifiers_must_match_exactly() {
+    let ctrl = modifiers(true, false, false, false);
+
+    assert!(shortcut("ctrl+k").matches_key(ctrl, "k", "KeyK"));
+    assert!(!shortcut("k").matches_key(ctrl, "k", "KeyK"));
+    assert!(!shortcut("ctrl+k").matches_key(Modifiers::default(), "k", "KeyK"));
+  }
+}
@@ -0,0 +1,150 @@
+//! Hover state for [`leptos`].
+//!
+//! Please refer to [`Hover`] for usage examples.
+
+use leptos::*;
+use leptos_dom::Transparent;
+use std::rc::Rc;
+
+api_planning! {
+  view! { cx,
+    <Hover>
+      <img src=thumbnail />
+      <WhenHovered><Actions /></WhenHovered>
+      <WhenNotHovered><Title /></WhenNotHovered>
+    </Hover>
+  }
+}
+
+/// Tracks whether the pointer is over it's children, rendering the
+/// [`WhenHovered`] arms while it is, and the [`WhenNotHovered`] arms while
+/// it's not.
+///
+/// The children are wrapped in a `div`, which is considered hovered while
+/// the mouse is over it, or while it's being touched on touch screens. Any
+/// child which isn't an arm is always rendered, and arms are rendered where
+/// they appear among them.
+///
+/// Bind `hovered` to read the hover state from elsewhere.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Hover class="card">
+///   <h2>"Card"</h2>
+///   <WhenHovered><button>"Edit"</button></WhenHovered>
+///   <WhenNotHovered><p>"Hover to edit"</p></WhenNotHovered>
+/// </Hover>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Hover(
+  cx: Scope,
+  /// Binds the hover state to this signal.
+  #[prop(optional)]
+  hovered: Option<RwSignal<bool>>,
+  /// The `class` of the wrapping `div`.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// The content whose hover state is tracked, along with the arms.
+  ///
+  /// Arms can be any
+  /// - [`WhenHovered`]
+  /// - [`WhenNotHovered`]
+  ///
+  /// Any other child is always rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let hovered = hovered.unwrap_or_else(|| create_rw_signal(cx, false));
+
+  let children = children(cx)
+    .as_children()
+    .iter()
+    .cloned()
+    .map(|child| {
+      let Some(block) = child.as_transparent() else {
+        return child;
+      };
+
+      let (when, children) = if let Some(block) =
+        block.downcast_ref::<WhenHoveredBlock>()
+      {
+        (true, block.children.clone())
+      } else if let Some(block) = block.downcast_ref::<WhenNotHoveredBlock>() {
+        (false, block.children.clone())
+      } else {
+        return child;
+      };
+
+      (move || {
+        if hovered.get() == when {
+          children(cx).into_view(cx)
+        } else {
+          ().into_view(cx)
+        }
+      })
+      .into_view(cx)
+    })
+    .collect::<Vec<_>>();
+
+  view! { cx,
+    <div
+      class=class
+      on:mouseenter=move |_| hovered.set(true)
+      on:mouseleave=move |_| hovered.set(false)
+      on:touchstart=move |_| hovered.set(true)
+      on:touchend=move |_| hovered.set(false)
+      on:touchcancel=move |_| hovered.set(false)
+    >
+      {children}
+    </div>
+  }
+}
+
+/// Shown while the parent [`Hover`] is hovered.
+#[component(transparent)]
+pub fn WhenHovered(
+  cx: Scope,
+  /// What you want to show while hovered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  WhenHoveredBlock {
+    children: children.into(),
+  }
+}
+
+/// Shown while the parent [`Hover`] is not hovered.
+#[component(transparent)]
+pub fn WhenNotHovered(
+  cx: Scope,
+  /// What you want to show while not hovered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  WhenNotHoveredBlock {
+    children: children.into(),
+  }
+}
+
+transparent_block! {
+  /// The hovered arm returned by [`WhenHovered`].
+  pub struct WhenHoveredBlock {
+    children: Rc<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The not hovered arm returned by [`WhenNotHovered`].
+  pub struct WhenNotHoveredBlock {
+    children: Rc<dyn Fn(Scope) -> Fragment>,
+  }
+}
@@ -0,0 +1,237 @@
+//! Rendering based on hydration, or only on the client or the server, for
+//! [`leptos`].
+//!
+//! Please refer to [`Hydrated`], [`ClientOnly`] and [`ServerOnly`] for usage
+//! examples.
+
+use crate::util::try_window;
+use leptos::*;
+use leptos_dom::{
+  HydrationCtx,
+  Transparent,
+};
+
+api_planning! {
+  view! { cx,
+    <ClientOnly placeholder=|cx| view! { cx, <Skeleton /> }>
+      <Map />
+    </ClientOnly>
+    <ServerOnly>
+      <noscript>"Please enable JavaScript"</noscript>
+    </ServerOnly>
+    <Hydrated>
+      <Before><button disabled=true>"Save"</button></Before>
+      <After><button on:click=save>"Save"</button></After>
+    </Hydrated>
+  }
+}
+
+/// Renders the [`Before`] arm until the app is interactive, and the
+/// [`After`] arm afterwards.
+///
+/// This is handy for interactions which need live signals, which can be shown
+/// disabled or as a skeleton until hydration is done. On the server, and
+/// while hydrating, the [`Before`] arm is rendered, so hydration never
+/// mismatches.
+///
+/// For more docs on allowed child components, check out
+/// [`HydratedProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Hydrated>
+///   <Before><button disabled=true>"Save"</button></Before>
+///   <After><button on:click=|_| log!("saving...")>"Save"</button></After>
+/// </Hydrated>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Hydrated(
+  cx: Scope,
+  /// The arms before and after hydration.
+  ///
+  /// Children must be any
+  /// - [`Before`]
+  /// - [`After`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let hydrated = use_hydrated(cx);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if hydrated.get() {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<AfterBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<BeforeBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    }
+    .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown by the parent [`Hydrated`] until the app is interactive.
+#[component(transparent)]
+pub fn Before(
+  cx: Scope,
+  /// What you want to show until the app is interactive.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  BeforeBlock { children }
+}
+
+/// Shown by the parent [`Hydrated`] once the app is interactive.
+#[component(transparent)]
+pub fn After(
+  cx: Scope,
+  /// What you want to show once the app is interactive.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  AfterBlock { children }
+}
+
+transparent_block! {
+  /// The arm returned by [`Before`].
+  pub struct BeforeBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The arm returned by [`After`].
+  pub struct AfterBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// Returns a signal which becomes `true` once the app is interactive, which
+/// is once hydration is done, or once mounted in apps which aren't hydrated.
+///
+/// It's always `false` on the server.
+pub fn use_hydrated(cx: Scope) -> Signal<bool> {
+  let hydrated = create_rw_signal(cx, false);
+  let hydrating = HydrationCtx::is_hydrating();
+
+  // Effects only run in the browser, after the view was mounted, but the rest
+  // of the app might still be hydrating
+  create_effect(cx, move |_| {
+    if hydrating {
+      request_animation_frame(move || hydrated.set(true));
+    } else {
+      hydrated.set(true);
+    }
+  });
+
+  hydrated.into()
+}
+
+/// Renders it's children only in the browser, once it's been mounted,
+/// showing the `placeholder` until then.
+///
+/// The placeholder is what gets rendered on the server, and what the client
+/// hydrates, so browser only widgets such as maps and editors never cause
+/// hydration mismatches.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ClientOnly placeholder=Box::new(|cx| view! { cx, <p>"Loading map..."</p> }.into_view(cx))>
+///   <canvas id="map" />
+/// </ClientOnly>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ClientOnly(
+  cx: Scope,
+  /// Rendered on the server, and until mounted in the browser. Defaults to
+  /// rendering nothing.
+  #[prop(optional)]
+  placeholder: Option<Box<dyn Fn(Scope) -> View>>,
+  /// What you want to render in the browser.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let mounted = create_rw_signal(cx, false);
+
+  // Effects only run in the browser, after the view was mounted
+  create_effect(cx, move |_| mounted.set(true));
+
+  move || {
+    if mounted.get() {
+      children(cx).into_view(cx)
+    } else if let Some(placeholder) = &placeholder {
+      placeholder(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Renders it's children only on the server, showing the `placeholder` in
+/// the browser instead.
+///
+/// This is handy for content which only makes sense before the app is
+/// interactive, such as `noscript` notices. The server rendered children are
+/// replaced by the placeholder when hydrating.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ServerOnly>
+///   <noscript>"Please enable JavaScript"</noscript>
+/// </ServerOnly>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ServerOnly(
+  cx: Scope,
+  /// Rendered in the browser. Defaults to rendering nothing.
+  #[prop(optional)]
+  placeholder: Option<Box<dyn Fn(Scope) -> View>>,
+  /// What you want to render on the server.
+  children: Children,
+) -> impl IntoView {
+  if try_window().is_none() {
+    children(cx).into_view(cx)
+  } else if let Some(placeholder) = placeholder {
+    placeholder(cx)
+  } else {
+    ().into_view(cx)
+  }
+}
@@ -0,0 +1,128 @@
+//! Rendering when the browser is idle for [`leptos`].
+//!
+//! Please refer to [`IdleRender`] for usage examples.
+
+use crate::{
+  quiesce::PendingGuard,
+  util::{
+    set_timeout,
+    try_window,
+    Timeout,
+  },
+};
+use leptos::*;
+use std::{
+  cell::Cell,
+  rc::Rc,
+  time::Duration,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <IdleRender timeout=Duration::from_secs(2)>
+      <SalesChart />
+    </IdleRender>
+  }
+}
+
+/// Renders it's children only once the browser is idle, using
+/// `requestIdleCallback`, showing the `placeholder` until then.
+///
+/// This keeps expensive, non-critical widgets such as charts and maps from
+/// blocking the first paint.
+///
+/// In browsers without `requestIdleCallback`, the children are rendered
+/// right after mounting. Nothing but the placeholder is rendered on the
+/// server.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <IdleRender timeout=Duration::from_secs(2)>
+///   <p>"A very expensive chart"</p>
+/// </IdleRender>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn IdleRender(
+  cx: Scope,
+  /// Renders the children after this long, even if the browser never
+  /// becomes idle. Defaults to 2 seconds.
+  #[prop(optional)]
+  timeout: Option<Duration>,
+  /// Rendered until the browser is idle. Defaults to rendering nothing.
+  #[prop(optional)]
+  placeholder: Option<Box<dyn Fn(Scope) -> View>>,
+  /// What you want to render once the browser is idle.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let timeout = timeout.unwrap_or(Duration::from_secs(2));
+  let idle = create_rw_signal(cx, false);
+
+  create_effect(cx, move |_| {
+    let Some(window) = try_window() else {
+      return;
+    };
+
+    let guard = Rc::new(Cell::new(Some(PendingGuard::new(cx.runtime))));
+
+    let callback = Closure::<dyn Fn()>::new({
+      let guard = guard.clone();
+
+      move || {
+        idle.set(true);
+
+        guard.set(None);
+      }
+    });
+
+    let mut options = web_sys::IdleRequestOptions::new();
+    options.timeout(timeout.as_millis().try_into().unwrap_or(u32::MAX));
+
+    match window.request_idle_callback_with_options(
+      callback.as_ref().unchecked_ref(),
+      &options,
+    ) {
+      Ok(handle) => on_cleanup(cx, move || {
+        window.cancel_idle_callback(handle);
+        guard.set(None);
+
+        drop(callback);
+      }),
+      // `requestIdleCallback` isn't supported
+      Err(_) => {
+        guard.set(None);
+
+        let fallback: Option<Timeout> =
+          set_timeout(cx, move || idle.set(true), Duration::ZERO);
+
+        on_cleanup(cx, move || {
+          if let Some(fallback) = fallback {
+            fallback.clear();
+          }
+        });
+      }
+    }
+  });
+
+  move || {
+    if idle.get() {
+      children(cx).into_view(cx)
+    } else if let Some(placeholder) = &placeholder {
+      placeholder(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
@@ -2,6 +2,10 @@
 //!
 //! Please refer to [`If`] for usage examples.
 
+use crate::error::{
+  report,
+  Error,
+};
 use leptos::*;
 use leptos_dom::Transparent;
 use std::cell::Cell;
@@ -122,6 +126,8 @@ pub fn If(
   /// [`Then`] must be present and the first child.
   ///
   /// [`Else`] must be the last child.
+  ///
+  /// Otherwise, the [`Error`] is reported and nothing is rendered.
   children: Box<dyn Fn(Scope) -> Fragment>,
 ) -> impl IntoView {
   // Memoize the signal
@@ -137,13 +143,19 @@ pub fn If(
     .cloned()
     .collect::<Vec<_>>();
 
-  #[cfg(debug_assertions)]
-  run_debug_checks(&if_blocks);
+  let is_valid = check_structure(&if_blocks).map_err(report).is_ok();
 
   let last_rendered_block = Cell::<Option<usize>>::new(None);
   let child = Cell::new(().into_view(cx));
 
+  #[cfg(feature = "testing")]
+  let probe = use_context::<crate::testing::IfProbe>(cx);
+
   move || {
+    if !is_valid {
+      return ().into_view(cx);
+    }
+
     let mut if_blocks = if_blocks
       .iter()
       .filter_map(Transparent::downcast_ref::<IfBlock>)
@@ -158,6 +170,16 @@ pub fn If(
 
     if signal.get() {
       if last_rendered_block.get() != Some(0) {
+        #[cfg(feature = "performance")]
+        let _measure = crate::performance::measure_switch(cx);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+          "if_switch",
+          from = ?last_rendered_block.get(),
+          to = 0
+        )
+        .entered();
+
         last_rendered_block.set(Some(0));
 
         let new_child = if_blocks.next().unwrap().1.render(cx).into_view(cx);
@@ -168,6 +190,16 @@ pub fn If(
       if_blocks.find(|(_, block)| block.is_true())
     {
       if last_rendered_block.get() != Some(i) {
+        #[cfg(feature = "performance")]
+        let _measure = crate::performance::measure_switch(cx);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+          "if_switch",
+          from = ?last_rendered_block.get(),
+          to = i
+        )
+        .entered();
+
         last_rendered_block.set(Some(i));
 
         let new_child = block.render(cx).into_view(cx);
@@ -175,11 +207,24 @@ pub fn If(
         child.set(new_child);
       }
     } else {
+      #[cfg(feature = "tracing")]
+      if last_rendered_block.get().is_some() {
+        tracing::debug!(
+          from = ?last_rendered_block.get(),
+          "`<If />` switched to rendering nothing"
+        );
+      }
+
       last_rendered_block.set(None);
 
       child.set(().into_view(cx));
     }
 
+    #[cfg(feature = "testing")]
+    if let Some(probe) = &probe {
+      probe.set(last_rendered_block.get());
+    }
+
     let view = child.take();
     child.set(view.clone());
 
@@ -230,30 +275,32 @@ pub fn Else(
   IfBlock::Else { children }
 }
 
-/// Represents an if block which is returned by [`Then`], [`ElseIf`]
-/// or [`Else`] components.
-pub enum IfBlock {
-  /// The initial `if` condition, returned by [`Then`].
-  If {
-    /// The children method.
-    children: Box<dyn Fn(Scope) -> Fragment>,
-  },
-  /// An `else if` condition, returned by [`ElseIf`].
-  ElseIf {
-    /// The signal which must evaluate to true to be rendered.
-    signal: Memo<bool>,
-    /// The children method.
-    children: Box<dyn Fn(Scope) -> Fragment>,
-  },
-  /// The `else` condition, returned by [`Else`].
-  Else {
-    /// The children method.
-    children: Box<dyn Fn(Scope) -> Fragment>,
-  },
+transparent_block! {
+  /// Represents an if block which is returned by [`Then`], [`ElseIf`]
+  /// or [`Else`] components.
+  pub enum IfBlock {
+    /// The initial `if` condition, returned by [`Then`].
+    If {
+      /// The children method.
+      children: Box<dyn Fn(Scope) -> Fragment>,
+    },
+    /// An `else if` condition, returned by [`ElseIf`].
+    ElseIf {
+      /// The signal which must evaluate to true to be rendered.
+      signal: Memo<bool>,
+      /// The children method.
+      children: Box<dyn Fn(Scope) -> Fragment>,
+    },
+    /// The `else` condition, returned by [`Else`].
+    Else {
+      /// The children method.
+      children: Box<dyn Fn(Scope) -> Fragment>,
+    },
+  }
 }
 
 impl IfBlock {
-  fn is_true(&self) -> bool {
+  pub(crate) fn is_true(&self) -> bool {
     if let Self::ElseIf { signal, .. } = self {
       signal.get()
     } else {
@@ -261,15 +308,15 @@ impl IfBlock {
     }
   }
 
-  fn is_if(&self) -> bool {
+  pub(crate) fn is_if(&self) -> bool {
     matches!(self, Self::If { .. })
   }
 
-  fn is_else(&self) -> bool {
+  pub(crate) fn is_else(&self) -> bool {
     matches!(self, Self::Else { .. })
   }
 
-  fn render(&self, cx: Scope) -> Fragment {
+  pub(crate) fn render(&self, cx: Scope) -> Fragment {
     match self {
       Self::If { children } => children(cx),
       Self::ElseIf { children, .. } => children(cx),
@@ -278,43 +325,34 @@ impl IfBlock {
   }
 }
 
-impl IntoView for IfBlock {
-  fn into_view(self, _: Scope) -> View {
-    View::Transparent(Transparent::new(self))
-  }
-}
-
-#[cfg(debug_assertions)]
-fn run_debug_checks(if_blocks: &[Transparent]) {
+/// Makes sure [`Then`] is the first and only one, and that [`Else`] is the
+/// last and only one, if any.
+pub(crate) fn check_structure(if_blocks: &[Transparent]) -> Result<(), Error> {
   let if_blocks = if_blocks
     .iter()
     .filter_map(Transparent::downcast_ref::<IfBlock>);
 
-  // Make sure <Show /> is first
-  assert!(
-    if_blocks.clone().next().unwrap().is_if(),
-    "`<Show />` must be the first child of `<If />`"
-  );
+  // Make sure <Then /> is first
+  if !matches!(if_blocks.clone().next(), Some(IfBlock::If { .. })) {
+    return Err(Error::MissingThen);
+  }
+
+  // Make sure there is no more than 1 <Then />
+  if if_blocks.clone().filter(|block| block.is_if()).count() > 1 {
+    return Err(Error::DuplicateThen);
+  }
 
-  // Make sure there is no more than 1 <Show />
-  assert_eq!(
-    if_blocks.clone().filter(|block| block.is_if()).count(),
-    1,
-    "there must not be more than 1 `<Show />` children within `<If />`"
-  );
+  // Make sure there is no more than 1 <Else />
+  if if_blocks.clone().filter(|block| block.is_else()).count() > 1 {
+    return Err(Error::DuplicateElse);
+  }
 
   // Make sure <Else /> is last
   if let Some(pos) = if_blocks.clone().position(|block| block.is_else()) {
-    assert_eq!(
-      pos,
-      if_blocks.clone().count() - 1,
-      "`<Else />` must be the last child of `<If />`"
-    );
+    if pos != if_blocks.count() - 1 {
+      return Err(Error::MisplacedElse);
+    }
   }
 
-  // Make sure there is no more than 1 <Else />
-  assert!(
-    if_blocks.filter(|block| block.is_else()).count() <= 1,
-    "there must not be more than 1 `<Else />` children within `<If />`"
-  );
+  Ok(())
 }
@@ -0,0 +1,142 @@
+//! Rendering when scrolled into view for [`leptos`].
+//!
+//! Please refer to [`InView`] for usage examples.
+
+use crate::util::try_window;
+use leptos::{
+  html::Div,
+  *,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <InView root_margin="200px" once=true>
+      <Comments />
+    </InView>
+  }
+}
+
+/// Renders it's children only while it's element is scrolled into the
+/// viewport, using an `IntersectionObserver`, showing the `placeholder`
+/// otherwise.
+///
+/// Set `once` to keep the children once they were rendered, which is what
+/// you want for lazily loading heavy sections.
+///
+/// The children are rendered within a `div`, which should be given a size
+/// while empty, such as with `class`, so it doesn't collapse. In browsers
+/// without `IntersectionObserver`, the children are rendered right after
+/// mounting. Nothing but the placeholder is rendered on the server.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <InView root_margin="200px" once=true class="comments">
+///   <p>"Lots of comments"</p>
+/// </InView>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn InView(
+  cx: Scope,
+  /// Grows or shrinks the viewport when checking whether the element is in
+  /// view, using the syntax of the CSS `margin` property. Defaults to `0px`.
+  #[prop(optional, into)]
+  root_margin: Option<String>,
+  /// How much of the element must be visible for it to be in view, from
+  /// `0.0` to `1.0`. Defaults to `0.0`, meaning any part of it.
+  #[prop(optional)]
+  threshold: f64,
+  /// Keeps the children rendered once they were rendered, instead of
+  /// removing them when scrolled out of view.
+  #[prop(optional)]
+  once: bool,
+  /// The `class` of the `div` the children are rendered in.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// Rendered while the element is not in view. Defaults to rendering
+  /// nothing.
+  #[prop(optional)]
+  placeholder: Option<Box<dyn Fn(Scope) -> View>>,
+  /// What you want to render while the element is in view.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let in_view = create_rw_signal(cx, false);
+  let container = create_node_ref::<Div>(cx);
+
+  create_effect(cx, move |_| {
+    let Some(container) = container.get() else {
+      return;
+    };
+
+    if try_window().is_none() {
+      return;
+    }
+
+    let callback = Closure::<
+      dyn Fn(js_sys::Array, web_sys::IntersectionObserver),
+    >::new(
+      move |entries: js_sys::Array, observer: web_sys::IntersectionObserver| {
+        let Some(entry) = entries.iter().last().map(|entry| {
+          entry.unchecked_into::<web_sys::IntersectionObserverEntry>()
+        }) else {
+          return;
+        };
+
+        let is_intersecting = entry.is_intersecting();
+
+        in_view.set(is_intersecting);
+
+        if once && is_intersecting {
+          observer.disconnect();
+        }
+      },
+    );
+
+    let mut options = web_sys::IntersectionObserverInit::new();
+    options.root_margin(root_margin.as_deref().unwrap_or("0px"));
+    options.threshold(&threshold.into());
+
+    let Ok(observer) = web_sys::IntersectionObserver::new_with_options(
+      callback.as_ref().unchecked_ref(),
+      &options,
+    ) else {
+      // `IntersectionObserver` isn't supported
+      in_view.set(true);
+
+      return;
+    };
+
+    observer.observe(&container);
+
+    on_cleanup(cx, move || {
+      observer.disconnect();
+
+      drop(callback);
+    });
+  });
+
+  view! { cx,
+    <div class=class node_ref=container>
+      {move || {
+        if in_view.get() {
+          children(cx).into_view(cx)
+        } else if let Some(placeholder) = &placeholder {
+          placeholder(cx)
+        } else {
+          ().into_view(cx)
+        }
+      }}
+    </div>
+  }
+}
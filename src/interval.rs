@@ -0,0 +1,101 @@
+//! Counting intervals for [`leptos`].
+//!
+//! Please refer to [`Interval`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use std::time::Duration;
+
+api_planning! {
+  view! { cx,
+    <Interval every=Duration::from_secs(1) view=|cx, tick: Signal<u64>| view! { cx,
+      <p>{move || tick.get()}" seconds since opening"</p>
+    } />
+  }
+}
+
+/// Passes `view` a counter, which is incremented `every` so often while
+/// mounted.
+///
+/// The counter starts at `0`, or is incremented right away when mounted if
+/// `immediate` is set. Set `max_ticks` to stop once the counter reaches it.
+/// The interval is cleared when unmounted, and only runs in the browser.
+///
+/// `view` is only called once, so it should read the counter reactively.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Interval
+///   every=Duration::from_millis(500)
+///   max_ticks=3
+///   view=|cx, tick: Signal<u64>| view! { cx,
+///     <p>"Loading"{move || ".".repeat(tick.get() as usize)}</p>
+///   }
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Interval<F, IV>(
+  cx: Scope,
+  /// How often the counter is incremented.
+  every: Duration,
+  /// Increments the counter right away when mounted.
+  #[prop(optional)]
+  immediate: bool,
+  /// Stops incrementing the counter once it reaches this value.
+  #[prop(optional)]
+  max_ticks: Option<u64>,
+  /// Renders the content, given the counter.
+  view: F,
+) -> impl IntoView
+where
+  F: FnOnce(Scope, Signal<u64>) -> IV,
+  IV: IntoView,
+{
+  let tick = create_rw_signal(cx, 0u64);
+  let interval = store_value(cx, None::<IntervalHandle>);
+
+  let stop = move || {
+    interval.update_value(|interval| {
+      if let Some(interval) = interval.take() {
+        interval.clear();
+      }
+    })
+  };
+
+  let increment = move || {
+    tick.update(|tick| *tick += 1);
+
+    if max_ticks.map_or(false, |max_ticks| tick.get_untracked() >= max_ticks) {
+      stop();
+    }
+  };
+
+  create_effect(cx, move |_| {
+    if try_window().is_none() {
+      return;
+    }
+
+    if max_ticks == Some(0) {
+      return;
+    }
+
+    interval.set_value(set_interval_with_handle(increment, every).ok());
+
+    if immediate {
+      increment();
+    }
+  });
+
+  on_cleanup(cx, stop);
+
+  view(cx, tick.into())
+}
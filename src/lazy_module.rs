@@ -0,0 +1,208 @@
+//! Code-split rendering for [`leptos`].
+//!
+//! Please refer to [`LazyModule`] for usage examples.
+
+use crate::{
+  retry::{
+    FailedBlock,
+    RetryHandle,
+  },
+  skeleton::use_group_pending,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  future::Future,
+  rc::Rc,
+};
+
+api_planning! {
+  view! { cx,
+    <LazyModule loader=load_settings_chunk>
+      <Loading>"Loading settings..."</Loading>
+      <Retrying>"Trying again..."</Retrying>
+      <Failed view=|cx, error: JsValue, retry: RetryHandle| view! { cx,
+        <button on:click=move |_| retry.retry()>"Retry"</button>
+      } />
+    </LazyModule>
+  }
+}
+
+type ModuleFn = Rc<dyn Fn(Scope) -> View>;
+
+/// Renders a component which lives in a secondary chunk (such as a
+/// dynamically imported wasm or JS module), showing the [`Loading`] arm
+/// while the chunk is being fetched.
+///
+/// The `loader` is called once on mount, and again every time the
+/// [`RetryHandle`] given to the [`Failed`](crate::retry::Failed) arm is used. It must resolve to the component which
+/// should be rendered once the chunk is available.
+///
+/// When placed within a [`SkeletonGroup`](crate::skeleton::SkeletonGroup),
+/// the pending arm is shown until the whole group has settled.
+///
+/// This component is only available with the `lazy-module` feature.
+///
+/// For more docs on allowed child components, check out
+/// [`LazyModuleProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let loader = || async {
+///   Ok::<_, String>(|cx| view! { cx, <p>"Hello from another chunk!"</p> })
+/// };
+///
+/// view! { cx,
+/// <LazyModule loader>
+///   <Loading>"Loading..."</Loading>
+///   <Failed view=|cx, error: String, retry: RetryHandle| view! { cx,
+///     <p>{error}</p>
+///     <button on:click=move |_| retry.retry()>"Retry"</button>
+///   } />
+/// </LazyModule>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn LazyModule<L, Fut, M, V, E>(
+  cx: Scope,
+  /// Asynchronously loads the chunk, resolving to the component which
+  /// should be rendered.
+  loader: L,
+  /// The arms to render while the module is not available.
+  ///
+  /// Children must be any
+  /// - [`Loading`]
+  /// - [`Retrying`]
+  /// - [`Failed`](crate::retry::Failed)
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  L: Fn() -> Fut + 'static,
+  Fut: Future<Output = Result<M, E>> + 'static,
+  M: Fn(Scope) -> V + 'static,
+  V: IntoView,
+  E: Clone + 'static,
+{
+  let attempt = create_rw_signal(cx, 0usize);
+
+  let module = create_local_resource(
+    cx,
+    move || attempt.get(),
+    move |_| {
+      let module = loader();
+
+      async move {
+        module
+          .await
+          .map(|module| Rc::new(move |cx| module(cx).into_view(cx)) as ModuleFn)
+      }
+    },
+  );
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let retry = RetryHandle::new(attempt, store_value(cx, 0));
+
+  let pending = use_group_pending(cx, move || module.loading().get());
+
+  move || {
+    if pending.get() {
+      return render_pending(cx, &blocks, attempt.get_untracked() > 0);
+    }
+
+    match module.read(cx) {
+      Some(Ok(module)) => module(cx),
+      Some(Err(error)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<FailedBlock<E>>)
+        .map(|block| (block.view)(cx, error, retry))
+        .unwrap_or_else(|| ().into_view(cx)),
+      None => render_pending(cx, &blocks, false),
+    }
+  }
+}
+
+/// Renders the pending arm, falling back to [`Loading`] when retrying without
+/// a [`Retrying`] arm.
+fn render_pending(cx: Scope, blocks: &[Transparent], retrying: bool) -> View {
+  let mut pending = blocks
+    .iter()
+    .filter_map(Transparent::downcast_ref::<LazyModuleBlock>);
+
+  pending
+    .clone()
+    .find(|block| retrying && block.is_retrying())
+    .or_else(|| pending.find(|block| !block.is_retrying()))
+    .map(|block| block.render(cx).into_view(cx))
+    .unwrap_or_else(|| ().into_view(cx))
+}
+
+/// Shown while the [`LazyModule`] chunk is loading for the first time.
+///
+/// This is also shown while retrying if no [`Retrying`] arm is present.
+#[component(transparent)]
+pub fn Loading(
+  cx: Scope,
+  /// What you want to show while loading.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  LazyModuleBlock::Loading { children }
+}
+
+/// Shown while the [`LazyModule`] chunk is being loaded again after
+/// [`RetryHandle::retry`] was called.
+#[component(transparent)]
+pub fn Retrying(
+  cx: Scope,
+  /// What you want to show while retrying.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  LazyModuleBlock::Retrying { children }
+}
+
+transparent_block! {
+  /// Represents a pending arm returned by [`Loading`] or [`Retrying`].
+  pub enum LazyModuleBlock {
+    /// Returned by [`Loading`].
+    Loading {
+      /// The children method.
+      children: Box<dyn Fn(Scope) -> Fragment>,
+    },
+    /// Returned by [`Retrying`].
+    Retrying {
+      /// The children method.
+      children: Box<dyn Fn(Scope) -> Fragment>,
+    },
+  }
+}
+
+impl LazyModuleBlock {
+  fn is_retrying(&self) -> bool {
+    matches!(self, Self::Retrying { .. })
+  }
+
+  fn render(&self, cx: Scope) -> Fragment {
+    match self {
+      Self::Loading { children } => children(cx),
+      Self::Retrying { children } => children(cx),
+    }
+  }
+}
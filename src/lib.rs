@@ -39,32 +39,295 @@
 //!
 //! # let _ = create_scope(create_runtime(), |cx| {
 //!
-//! struct PortalId;
+//! #[derive(PortalId)]
+//! struct MyPortal;
 //!
 //! view! { cx,
 //!   <PortalProvider>
 //!     <div>
 //!       <h1>"Portal goes here!"</h1>
-//!       <PortalOutput id=PortalId />
+//!       <PortalOutput id=MyPortal />
 //!     </div>
 //!
-//!     <PortalInput id=PortalId>
+//!     <PortalInput id=MyPortal>
 //!       <p>"I went through the portal!"</p>
 //!     </PortalInput>
 //!   </PortalProvider>
 //! };
 //! # });
 //! ```
+//!
+//! # Features
+//! Components are grouped into features, so apps only compile what they use.
+//! The following are enabled by default:
+//!
+//! - `if`: [`If`](if_::If)
+//! - `control`: control flow and state, such as [`Tabs`](tabs::Tabs),
+//!   [`Wizard`](wizard::Wizard) and
+//!   [`PaginatedFor`](paginated_for::PaginatedFor)
+//! - `time`: timers, intervals, and debounced and throttled signals
+//! - `async`: [`Async`](async_::Async), [`Await`](await_::Await),
+//!   [`Retry`](retry::Retry), [`Poll`](poll::Poll), skeletons and deferred
+//!   rendering
+//! - `observers`: the browser observers and listeners, such as
+//!   [`InView`](in_view::InView) and [`MediaQuery`](media_query::MediaQuery)
+//! - `animation`: [`AnimatedFor`](animated_for::AnimatedFor),
+//!   [`AnimatedIf`](animated_if::AnimatedIf) and
+//!   [`TransitionGroup`](transition_group::TransitionGroup)
+//! - `a11y`: announcers, disclosures and focus traps
+//! - `portal`: portals, modals and toasts
+//! - `app`: auth, permissions, feature flags, experiments, the event bus and
+//!   the clipboard
+//! - `virtual`: [`VirtualFor`](virtual_for::VirtualFor), a virtualized list
+//!
+//! The following are opt-in: `broadcast`, `devtools`, `event-source`,
+//! `global-portal`, `lazy-module`, `performance`, `persist`, `router`,
+//! `testing`, `tracing` and `websocket`. Enable `ssr` or `hydrate` to turn on
+//! the matching `leptos` feature.
+//!
+//! # Errors
+//! Misusing a component, such as an [`If`](if_::If) without a `<Then />`,
+//! reports an [`Error`] to the handler set with
+//! [`set_error_handler`](error::set_error_handler). By default, it panics in
+//! debug builds, and is logged in release builds while the component renders
+//! nothing.
 
 #[macro_use]
 mod util;
+#[cfg(feature = "animation")]
+pub mod animated_for;
+#[cfg(feature = "animation")]
+pub mod animated_if;
+#[cfg(feature = "a11y")]
+pub mod announcer;
+#[cfg(feature = "async")]
+pub mod async_;
+#[cfg(feature = "app")]
+pub mod auth;
+#[cfg(feature = "async")]
+pub mod await_;
+#[cfg(feature = "observers")]
+pub mod breakpoint;
+#[cfg(feature = "broadcast")]
+pub mod broadcast;
+#[cfg(feature = "async")]
+pub mod cached;
+#[cfg(feature = "observers")]
+pub mod click_outside;
+#[cfg(feature = "app")]
+pub mod clipboard;
+#[cfg(feature = "observers")]
+pub mod color_scheme;
+#[cfg(feature = "control")]
+pub mod context;
+#[cfg(feature = "async")]
+pub mod defer;
+#[cfg(feature = "a11y")]
+pub mod disclosure;
+#[cfg(feature = "observers")]
+pub mod document_visible;
+#[cfg(feature = "portal")]
+pub mod dom_portal;
+#[cfg(feature = "observers")]
+pub mod element_size;
+pub mod error;
+#[cfg(feature = "control")]
+pub mod error_catch;
+#[cfg(feature = "app")]
+pub mod event_bus;
+#[cfg(feature = "observers")]
+pub mod event_on;
+#[cfg(feature = "event-source")]
+pub mod event_source;
+#[cfg(feature = "app")]
+pub mod experiment;
+#[cfg(feature = "app")]
+pub mod feature_flag;
+#[cfg(feature = "async")]
+pub mod first;
+#[cfg(feature = "a11y")]
+pub mod focus_trap;
+#[cfg(feature = "control")]
+pub mod for_each;
+#[cfg(feature = "control")]
+pub mod freeze;
+#[cfg(feature = "control")]
+pub mod gate;
+#[cfg(feature = "observers")]
+pub mod hotkey;
+#[cfg(feature = "observers")]
+pub mod hover;
+#[cfg(feature = "control")]
+pub mod hydration;
+#[cfg(feature = "async")]
+pub mod idle_render;
+#[cfg(feature = "if")]
 pub mod if_;
+#[cfg(feature = "observers")]
+pub mod in_view;
+#[cfg(feature = "time")]
+pub mod interval;
+#[cfg(feature = "lazy-module")]
+pub mod lazy_module;
+#[cfg(feature = "control")]
+pub mod machine;
+#[cfg(feature = "observers")]
+pub mod media_query;
+#[cfg(feature = "portal")]
+pub mod modal;
+#[cfg(feature = "control")]
+pub mod once;
+#[cfg(feature = "observers")]
+pub mod online;
+#[cfg(feature = "control")]
+pub mod paginated_for;
+#[cfg(feature = "performance")]
+pub mod performance;
+#[cfg(feature = "app")]
+pub mod permission;
+#[cfg(feature = "persist")]
+pub mod persist;
+#[cfg(feature = "async")]
+pub mod poll;
+#[cfg(feature = "portal")]
 pub mod portal;
+#[cfg(feature = "router")]
+pub mod query_param;
+pub mod quiesce;
+#[cfg(feature = "time")]
+pub mod rate_limit;
+#[cfg(feature = "observers")]
+pub mod reduced_motion;
+#[cfg(feature = "control")]
+pub mod repeat;
+#[cfg(feature = "async")]
+pub mod retry;
+#[cfg(feature = "router")]
+pub mod route_is;
+#[cfg(feature = "observers")]
+pub mod scroll_position;
+#[cfg(feature = "async")]
+pub mod skeleton;
+#[cfg(feature = "control")]
+pub mod tabs;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "time")]
+pub mod timer;
+#[cfg(feature = "portal")]
+pub mod toast;
+#[cfg(feature = "animation")]
+pub mod transition_group;
+#[cfg(feature = "virtual")]
+pub mod virtual_for;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+#[cfg(feature = "control")]
+pub mod with_memo;
+#[cfg(feature = "control")]
+pub mod wizard;
+
+pub use error::Error;
 
 /// Convenient import of all components.
 pub mod prelude {
+  #[cfg(feature = "broadcast")]
+  pub use crate::broadcast::*;
+  #[cfg(feature = "event-source")]
+  pub use crate::event_source::*;
+  #[cfg(feature = "if")]
+  pub use crate::if_::*;
+  #[cfg(feature = "lazy-module")]
+  pub use crate::lazy_module::*;
+  #[cfg(feature = "performance")]
+  pub use crate::performance::*;
+  #[cfg(feature = "persist")]
+  pub use crate::persist::*;
+  #[cfg(feature = "virtual")]
+  pub use crate::virtual_for::*;
+  #[cfg(feature = "websocket")]
+  pub use crate::websocket::*;
+  #[cfg(feature = "animation")]
+  pub use crate::{
+    animated_for::*,
+    animated_if::*,
+    transition_group::*,
+  };
+  #[cfg(feature = "a11y")]
+  pub use crate::{
+    announcer::*,
+    disclosure::*,
+    focus_trap::*,
+  };
+  #[cfg(feature = "async")]
+  pub use crate::{
+    async_::*,
+    await_::*,
+    cached::*,
+    defer::*,
+    first::*,
+    idle_render::*,
+    poll::*,
+    retry::*,
+    skeleton::*,
+  };
+  #[cfg(feature = "app")]
   pub use crate::{
-    if_::*,
+    auth::*,
+    clipboard::*,
+    event_bus::*,
+    experiment::*,
+    feature_flag::*,
+    permission::*,
+  };
+  #[cfg(feature = "observers")]
+  pub use crate::{
+    breakpoint::*,
+    click_outside::*,
+    color_scheme::*,
+    document_visible::*,
+    element_size::*,
+    event_on::*,
+    hotkey::*,
+    hover::*,
+    in_view::*,
+    media_query::*,
+    online::*,
+    reduced_motion::*,
+    scroll_position::*,
+  };
+  #[cfg(feature = "control")]
+  pub use crate::{
+    context::*,
+    error_catch::*,
+    for_each::*,
+    freeze::*,
+    gate::*,
+    hydration::*,
+    machine::*,
+    once::*,
+    paginated_for::*,
+    repeat::*,
+    tabs::*,
+    with_memo::*,
+    wizard::*,
+  };
+  #[cfg(feature = "portal")]
+  pub use crate::{
+    dom_portal::*,
+    modal::*,
     portal::*,
+    toast::*,
+  };
+  #[cfg(feature = "time")]
+  pub use crate::{
+    interval::*,
+    rate_limit::*,
+    timer::*,
+  };
+  #[cfg(feature = "router")]
+  pub use crate::{
+    query_param::*,
+    route_is::*,
   };
 }
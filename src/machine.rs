@@ -0,0 +1,620 @@
+//! Finite state machines for [`leptos`].
+//!
+//! Please refer to [`create_machine`] for usage examples.
+
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  fmt,
+  rc::Rc,
+};
+
+api_planning! {
+  let machine = create_machine(cx, Phase::Editing, |phase, event| match (phase, event) {
+    (Phase::Editing, Event::Submit) => Some(Phase::Saving),
+    (Phase::Saving, Event::Saved) => Some(Phase::Done),
+    (Phase::Saving, Event::Failed) => Some(Phase::Editing),
+    _ => None,
+  });
+
+  view! { cx,
+    <MachineView machine>
+      <State is=Phase::Editing><Form /></State>
+      <State is=Phase::Saving>"Saving..."</State>
+      <State is=Phase::Done>"Saved!"</State>
+    </MachineView>
+  }
+}
+
+/// Creates a finite state machine, which starts in the `initial` state, and
+/// moves between states as events are sent to it with [`Machine::send`].
+///
+/// `transition` returns the state to move to when an event is sent in a
+/// given state, or [`None`] to ignore the event. Render the states with a
+/// [`MachineView`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Phase {
+///   Editing,
+///   Saving,
+///   Done,
+/// }
+///
+/// enum Event {
+///   Submit,
+///   Saved,
+///   Failed,
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let machine = create_machine(cx, Phase::Editing, |phase, event| {
+///   match (phase, event) {
+///     (Phase::Editing, Event::Submit) => Some(Phase::Saving),
+///     (Phase::Saving, Event::Saved) => Some(Phase::Done),
+///     (Phase::Saving, Event::Failed) => Some(Phase::Editing),
+///     _ => None,
+///   }
+/// });
+///
+/// view! { cx,
+/// <MachineView machine>
+///   <State is=Phase::Editing>
+///     <button on:click=move |_| machine.send(Event::Submit)>"Save"</button>
+///   </State>
+///   <State is=Phase::Saving>"Saving..."</State>
+///   <State is=Phase::Done>"Saved!"</State>
+/// </MachineView>
+/// };
+/// # });
+/// ```
+pub fn create_machine<S, E>(
+  cx: Scope,
+  initial: S,
+  transition: impl Fn(&S, &E) -> Option<S> + 'static,
+) -> Machine<S, E>
+where
+  S: 'static,
+  E: 'static,
+{
+  let transition: Rc<dyn Fn(&S, &E) -> Option<S>> = Rc::new(transition);
+
+  Machine {
+    state: create_rw_signal(cx, initial),
+    transition: store_value(cx, transition),
+  }
+}
+
+/// A finite state machine created with [`create_machine`].
+pub struct Machine<S: 'static, E: 'static> {
+  state: RwSignal<S>,
+  transition: StoredValue<Rc<dyn Fn(&S, &E) -> Option<S>>>,
+}
+
+impl<S: 'static, E: 'static> Clone for Machine<S, E> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<S: 'static, E: 'static> Copy for Machine<S, E> {}
+
+impl<S: Clone + 'static, E: 'static> Machine<S, E> {
+  /// The current state.
+  ///
+  /// This is reactive.
+  pub fn state(&self) -> S {
+    self.state.get()
+  }
+
+  /// The current state, as a signal.
+  pub fn signal(&self) -> Signal<S> {
+    self.state.into()
+  }
+}
+
+impl<S: PartialEq + 'static, E: 'static> Machine<S, E> {
+  /// Whether the machine is in `state`.
+  ///
+  /// This is reactive.
+  pub fn matches(&self, state: &S) -> bool {
+    self.state.with(|current| current == state)
+  }
+
+  /// Sends `event` to the machine, moving it to the state it's transition
+  /// returns, if any.
+  pub fn send(&self, event: E) {
+    let transition = self.transition.get_value();
+
+    let next = self.state.with_untracked(|state| transition(state, &event));
+
+    if let Some(next) = next {
+      self.state.set(next);
+    }
+  }
+}
+
+impl<S, E> Machine<S, E>
+where
+  S: PartialEq + fmt::Debug + 'static,
+  E: fmt::Debug + 'static,
+{
+  /// Describes the states and transitions of the machine, which can be
+  /// exported with [`StateChart::to_dot`] and [`StateChart::to_mermaid`].
+  ///
+  /// Since transitions are plain functions, the chart is found by sending
+  /// each of the `events` to each of the `states`, and to every state
+  /// reached this way. States and events are labeled with their [`Debug`]
+  /// representation.
+  ///
+  /// This is reactive, as the current state is highlighted.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use leptos::*;
+  /// use leptos_declarative::prelude::*;
+  ///
+  /// #[derive(Clone, Debug, PartialEq)]
+  /// enum Light {
+  ///   Red,
+  ///   Green,
+  /// }
+  ///
+  /// #[derive(Debug)]
+  /// struct Next;
+  ///
+  /// # let _ = create_scope(create_runtime(), |cx| {
+  /// let machine = create_machine(cx, Light::Red, |light, _: &Next| {
+  ///   Some(match light {
+  ///     Light::Red => Light::Green,
+  ///     Light::Green => Light::Red,
+  ///   })
+  /// });
+  ///
+  /// let chart = machine.chart([Light::Red], &[Next]);
+  ///
+  /// assert_eq!(chart.states(), ["Red", "Green"]);
+  /// println!("{}", chart.to_mermaid());
+  /// # });
+  /// ```
+  pub fn chart(
+    &self,
+    states: impl IntoIterator<Item = S>,
+    events: &[E],
+  ) -> StateChart {
+    let transition = self.transition.get_value();
+
+    let mut found = Vec::<S>::new();
+
+    for state in states {
+      if !found.contains(&state) {
+        found.push(state);
+      }
+    }
+
+    let mut transitions = Vec::new();
+    let mut from = 0;
+
+    // States reached along the way are appended, so they are explored too
+    while from < found.len() {
+      for event in events {
+        let Some(next) = transition(&found[from], event) else {
+          continue;
+        };
+
+        let to = match found.iter().position(|state| *state == next) {
+          Some(to) => to,
+          None => {
+            found.push(next);
+
+            found.len() - 1
+          }
+        };
+
+        transitions.push(StateTransition {
+          from,
+          to,
+          event: format!("{event:?}"),
+        });
+      }
+
+      from += 1;
+    }
+
+    let current = self
+      .state
+      .with(|current| found.iter().position(|state| state == current));
+
+    StateChart {
+      states: found.iter().map(|state| format!("{state:?}")).collect(),
+      transitions,
+      current,
+    }
+  }
+}
+
+/// The states and transitions of a [`Machine`], as returned by
+/// [`Machine::chart`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateChart {
+  states: Vec<String>,
+  transitions: Vec<StateTransition>,
+  current: Option<usize>,
+}
+
+/// A transition of a [`StateChart`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateTransition {
+  /// The index of the state the transition starts from.
+  pub from: usize,
+  /// The index of the state the transition moves to.
+  pub to: usize,
+  /// The label of the event which causes the transition.
+  pub event: String,
+}
+
+impl StateChart {
+  /// The labels of the states, in the order they were found.
+  pub fn states(&self) -> &[String] {
+    &self.states
+  }
+
+  /// The transitions between the states.
+  pub fn transitions(&self) -> &[StateTransition] {
+    &self.transitions
+  }
+
+  /// The index of the current state of the machine, if it's one of the
+  /// [`StateChart::states`].
+  pub fn current(&self) -> Option<usize> {
+    self.current
+  }
+
+  /// Exports the chart as a Graphviz DOT graph, with the current state
+  /// filled.
+  pub fn to_dot(&self) -> String {
+    let escape = |label: &str| label.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut dot = String::from("digraph {\n");
+
+    for (i, state) in self.states.iter().enumerate() {
+      let style = if self.current == Some(i) {
+        ", style=filled"
+      } else {
+        ""
+      };
+
+      dot += &format!("  s{i} [label=\"{}\"{style}];\n", escape(state));
+    }
+
+    for StateTransition { from, to, event } in &self.transitions {
+      dot += &format!("  s{from} -> s{to} [label=\"{}\"];\n", escape(event));
+    }
+
+    dot += "}\n";
+
+    dot
+  }
+
+  /// Exports the chart as a Mermaid state diagram, with the current state
+  /// using the `current` class.
+  pub fn to_mermaid(&self) -> String {
+    let escape = |label: &str| label.replace('"', "#quot;");
+
+    let mut mermaid = String::from("stateDiagram-v2\n");
+
+    for (i, state) in self.states.iter().enumerate() {
+      mermaid += &format!("  state \"{}\" as s{i}\n", escape(state));
+    }
+
+    for StateTransition { from, to, event } in &self.transitions {
+      mermaid += &format!("  s{from} --> s{to}: {}\n", escape(event));
+    }
+
+    if let Some(current) = self.current {
+      mermaid += "  classDef current font-weight:bold\n";
+      mermaid += &format!("  class s{current} current\n");
+    }
+
+    mermaid
+  }
+}
+
+/// Renders the states of `machine` and their transitions as a list, which
+/// updates as the machine moves between states, with the current state
+/// marked with `aria-current`.
+///
+/// Please refer to [`Machine::chart`] for how the states are found.
+///
+/// This is only available with the `devtools` feature.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Door {
+///   Open,
+///   Closed,
+/// }
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Open,
+///   Close,
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let machine = create_machine(cx, Door::Closed, |door, event| {
+///   match (door, event) {
+///     (Door::Closed, Event::Open) => Some(Door::Open),
+///     (Door::Open, Event::Close) => Some(Door::Closed),
+///     _ => None,
+///   }
+/// });
+///
+/// view! { cx,
+/// <StateChartInspector
+///   machine
+///   states=vec![Door::Closed]
+///   events=vec![Event::Open, Event::Close]
+/// />
+/// };
+/// # });
+/// ```
+#[cfg(feature = "devtools")]
+#[component]
+pub fn StateChartInspector<S, E>(
+  cx: Scope,
+  /// The machine to inspect.
+  machine: Machine<S, E>,
+  /// The states to start looking for transitions from.
+  states: Vec<S>,
+  /// The events which are sent to each state.
+  events: Vec<E>,
+) -> impl IntoView
+where
+  S: Clone + PartialEq + fmt::Debug + 'static,
+  E: fmt::Debug + 'static,
+{
+  let rows = move || {
+    let chart = machine.chart(states.clone(), &events);
+
+    chart
+      .states()
+      .iter()
+      .enumerate()
+      .map(|(i, state)| {
+        let transitions = chart
+          .transitions()
+          .iter()
+          .filter(|transition| transition.from == i)
+          .map(|transition| {
+            let to = chart.states()[transition.to].clone();
+
+            view! { cx, <li>{transition.event.clone()}" → "{to}</li> }
+          })
+          .collect::<Vec<_>>();
+
+        view! { cx,
+          <li aria-current=(chart.current() == Some(i)).then_some("true")>
+            {state.clone()}
+            <ul>{transitions}</ul>
+          </li>
+        }
+      })
+      .collect::<Vec<_>>()
+  };
+
+  view! { cx, <ul class="state-chart-inspector">{rows}</ul> }
+}
+
+/// Renders the [`State`] arm matching the current state of `machine`.
+///
+/// The view only changes when the machine moves to a different state, and
+/// nothing is rendered for states without an arm.
+///
+/// For more docs on allowed child components, check out
+/// [`MachineViewProps::children`].
+#[component]
+pub fn MachineView<S, E>(
+  cx: Scope,
+  /// The machine whose states are rendered.
+  machine: Machine<S, E>,
+  /// The arms of each state.
+  ///
+  /// Children must be [`State`]s whose states are of the same type as those
+  /// of `machine`. Any other child will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  S: PartialEq + 'static,
+  E: 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .filter(|block| block.downcast_ref::<StateBlock<S>>().is_some())
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let active = create_memo(cx, {
+    let blocks = blocks.clone();
+
+    move |_| {
+      blocks
+        .iter()
+        .filter_map(Transparent::downcast_ref::<StateBlock<S>>)
+        .position(|block| machine.matches(&block.is))
+    }
+  });
+
+  move || {
+    active
+      .get()
+      .and_then(|i| blocks[i].downcast_ref::<StateBlock<S>>())
+      .map(|block| (block.children)(cx).into_view(cx))
+      .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown while the machine of the parent [`MachineView`] is in the state
+/// `is`.
+#[component(transparent)]
+pub fn State<S>(
+  cx: Scope,
+  /// The state this arm is shown in. This must be of the same type as the
+  /// states of the machine, otherwise this arm is ignored.
+  is: S,
+  /// What you want to show in this state.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  S: 'static,
+{
+  let _ = cx;
+
+  StateBlock { is, children }
+}
+
+transparent_block! {
+  /// The state arm returned by [`State`].
+  pub struct StateBlock<S> {
+    is: S,
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Clone, Debug, PartialEq)]
+  enum Door {
+    Open,
+    Closed,
+    Locked,
+  }
+
+  #[derive(Debug)]
+  enum Event {
+    Open,
+    Close,
+    Lock,
+  }
+
+  const EVENTS: [Event; 3] = [Event::Open, Event::Close, Event::Lock];
+
+  fn door(cx: Scope) -> Machine<Door, Event> {
+    create_machine(cx, Door::Closed, |door, event| match (door, event) {
+      (Door::Closed, Event::Open) => Some(Door::Open),
+      (Door::Open, Event::Close) => Some(Door::Closed),
+      (Door::Closed, Event::Lock) => Some(Door::Locked),
+      _ => None,
+    })
+  }
+
+  fn transition(from: usize, to: usize, event: &str) -> StateTransition {
+    StateTransition {
+      from,
+      to,
+      event: event.into(),
+    }
+  }
+
+  #[test]
+  fn chart_finds_reachable_states() {
+    let chart = run_scope(create_runtime(), |cx| {
+      door(cx).chart([Door::Closed], &EVENTS)
+    });
+
+    assert_eq!(chart.states(), ["Closed", "Open", "Locked"]);
+    assert_eq!(
+      chart.transitions(),
+      [
+        transition(0, 1, "Open"),
+        transition(0, 2, "Lock"),
+        transition(1, 0, "Close"),
+      ]
+    );
+    assert_eq!(chart.current(), Some(0));
+  }
+
+  #[test]
+  fn chart_skips_duplicate_states() {
+    let chart = run_scope(create_runtime(), |cx| {
+      door(cx).chart([Door::Closed, Door::Open, Door::Closed], &EVENTS)
+    });
+
+    assert_eq!(chart.states(), ["Closed", "Open", "Locked"]);
+  }
+
+  #[test]
+  fn chart_follows_current_state() {
+    let current = run_scope(create_runtime(), |cx| {
+      let machine = door(cx);
+
+      machine.send(Event::Open);
+
+      machine.chart([Door::Closed], &EVENTS).current()
+    });
+
+    assert_eq!(current, Some(1));
+  }
+
+  #[test]
+  fn exports_dot() {
+    let chart = run_scope(create_runtime(), |cx| {
+      door(cx).chart([Door::Closed], &EVENTS)
+    });
+
+    assert_eq!(
+      chart.to_dot(),
+      "digraph {\n  s0 [label=\"Closed\", style=filled];\n  s1 \
+       [label=\"Open\"];\n  s2 [label=\"Locked\"];\n  s0 -> s1 \
+       [label=\"Open\"];\n  s0 -> s2 [label=\"Lock\"];\n  s1 -> s0 \
+       [label=\"Close\"];\n}\n"
+    );
+  }
+
+  #[test]
+  fn exports_mermaid() {
+    let chart = run_scope(create_runtime(), |cx| {
+      door(cx).chart([Door::Closed], &EVENTS)
+    });
+
+    assert_eq!(
+      chart.to_mermaid(),
+      "stateDiagram-v2\n  state \"Closed\" as s0\n  state \"Open\" as \
+       s1\n  state \"Locked\" as s2\n  s0 --> s1: Open\n  s0 --> s2: \
+       Lock\n  s1 --> s0: Close\n  classDef current \
+       font-weight:bold\n  class s0 current\n"
+    );
+  }
+
+  #[test]
+  fn escapes_labels() {
+    let chart = StateChart {
+      states: vec!["say \"hi\"".into()],
+      transitions: Vec::new(),
+      current: None,
+    };
+
+    assert_eq!(
+      chart.to_dot(),
+      "digraph {\n  s0 [label=\"say \\\"hi\\\"\"];\n}\n"
+    );
+    assert_eq!(
+      chart.to_mermaid(),
+      "stateDiagram-v2\n  state \"say #quot;hi#quot;\" as s0\n"
+    );
+  }
+}
@@ -0,0 +1,173 @@
+//! Declarative media queries for [`leptos`].
+//!
+//! Please refer to [`MediaQuery`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use leptos_dom::Transparent;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <MediaQuery query="(min-width: 768px)" ssr_default=true>
+      <Matches><DesktopNav /></Matches>
+      <NotMatches><MobileNav /></NotMatches>
+    </MediaQuery>
+  }
+}
+
+/// Renders the [`Matches`] arm while the media `query` matches, and the
+/// [`NotMatches`] arm otherwise, re-rendering whenever the result changes.
+///
+/// On the server, and until the query is first evaluated in the browser,
+/// `ssr_default` is used as the result, so hydration doesn't mismatch.
+///
+/// For more docs on allowed child components, check out
+/// [`MediaQueryProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <MediaQuery query="(min-width: 768px)" ssr_default=true>
+///   <Matches>"Desktop"</Matches>
+///   <NotMatches>"Mobile"</NotMatches>
+/// </MediaQuery>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn MediaQuery(
+  cx: Scope,
+  /// The media query, such as `(min-width: 768px)`.
+  #[prop(into)]
+  query: String,
+  /// Whether the query is assumed to match when it can't be evaluated, such
+  /// as on the server.
+  #[prop(optional)]
+  ssr_default: bool,
+  /// The arms of the query.
+  ///
+  /// Children must be any
+  /// - [`Matches`]
+  /// - [`NotMatches`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let matches = use_media_query(cx, query, ssr_default);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if matches.get() {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<MatchesBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<NotMatchesBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    }
+    .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown while the query of the parent [`MediaQuery`] matches.
+#[component(transparent)]
+pub fn Matches(
+  cx: Scope,
+  /// What you want to show while the query matches.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  MatchesBlock { children }
+}
+
+/// Shown while the query of the parent [`MediaQuery`] doesn't match.
+#[component(transparent)]
+pub fn NotMatches(
+  cx: Scope,
+  /// What you want to show while the query doesn't match.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  NotMatchesBlock { children }
+}
+
+transparent_block! {
+  /// The matching arm returned by [`Matches`].
+  pub struct MatchesBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The non-matching arm returned by [`NotMatches`].
+  pub struct NotMatchesBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// Returns whether the media `query` matches, which updates whenever the
+/// result changes.
+///
+/// `ssr_default` is returned on the server, and until the query is first
+/// evaluated in the browser.
+pub fn use_media_query(
+  cx: Scope,
+  query: impl Into<String>,
+  ssr_default: bool,
+) -> Signal<bool> {
+  let query = query.into();
+  let matches = create_rw_signal(cx, ssr_default);
+
+  create_effect(cx, move |_| {
+    let Some(list) = try_window()
+      .and_then(|window| window.match_media(&query).ok())
+      .flatten()
+    else {
+      return;
+    };
+
+    matches.set(list.matches());
+
+    let on_change = Closure::<dyn Fn()>::new({
+      let list = list.clone();
+
+      move || matches.set(list.matches())
+    });
+
+    let _ = list.add_event_listener_with_callback(
+      "change",
+      on_change.as_ref().unchecked_ref(),
+    );
+
+    on_cleanup(cx, move || {
+      let _ = list.remove_event_listener_with_callback(
+        "change",
+        on_change.as_ref().unchecked_ref(),
+      );
+    });
+  });
+
+  matches.into()
+}
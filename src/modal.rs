@@ -0,0 +1,241 @@
+//! Modal dialogs for [`leptos`], built on top of [portals](crate::portal).
+//!
+//! Please refer to [`Modal`] for usage examples.
+
+use crate::{
+  focus_trap::FocusTrap,
+  portal::{
+    PortalId,
+    PortalInput,
+    PortalOutput,
+  },
+  util::try_window,
+};
+use leptos::*;
+use std::{
+  cell::{
+    Cell,
+    RefCell,
+  },
+  rc::Rc,
+};
+
+api_planning! {
+  view! { cx,
+    <PortalProvider>
+      // rest of your app
+      <ModalOutlet />
+    </PortalProvider>
+  }
+
+  view! { cx,
+    <Modal when=show_settings on_close=move || set_show_settings(false)
+      close_on_escape=true close_on_backdrop=true
+    >
+      <SettingsForm />
+    </Modal>
+  }
+}
+
+/// The portal modals are projected through.
+#[derive(Hash, PartialEq, Eq)]
+struct ModalRegion;
+
+impl PortalId for ModalRegion {}
+
+thread_local! {
+  /// The number of open modals, which lock the scroll of the `body`.
+  static SCROLL_LOCKS: Cell<usize> = Cell::new(0);
+  /// The `overflow` of the `body` before the first modal was opened, which is
+  /// restored once the last one is closed.
+  static PREVIOUS_OVERFLOW: RefCell<String> = RefCell::new(String::new());
+}
+
+/// Where the open [`Modal`]s are shown. Place this at the end of your app,
+/// below a [`PortalProvider`](crate::portal::PortalProvider), so modals are
+/// rendered above everything else.
+///
+/// Modals opened while others are open are rendered after them, and
+/// therefore on top of them.
+#[component]
+pub fn ModalOutlet(cx: Scope) -> impl IntoView {
+  view! { cx, <PortalOutput id=ModalRegion /> }
+}
+
+/// Shows it's children in a dialog in the [`ModalOutlet`] while `when` is
+/// `true`.
+///
+/// While any modal is open, the `body` can't be scrolled, and it's previous
+/// `overflow` is restored once the last one closes. Focus is trapped within
+/// the dialog with a [`FocusTrap`], so it's moved into the dialog when it
+/// opens, kept within it while it's open, and returned to where it was once
+/// it closes, even while the dialog is still leaving. The dialog, and
+/// the reactive state of it's content, is disposed once it's closed.
+///
+/// The dialog is wrapped in a backdrop element covering the modals below it.
+/// Modals don't close themselves, instead `on_close` is called when the
+/// user asks to close the dialog, such as by pressing <kbd>Escape</kbd> when
+/// `close_on_escape` is set.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (show_settings, set_show_settings) = create_signal(cx, false);
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <button on:click=move |_| set_show_settings(true)>"Settings"</button>
+///
+///     <Modal
+///       when=show_settings
+///       on_close=Box::new(move || set_show_settings(false))
+///       close_on_escape=true
+///       close_on_backdrop=true
+///     >
+///       <h2>"Settings"</h2>
+///       <button on:click=move |_| set_show_settings(false)>"Done"</button>
+///     </Modal>
+///
+///     <ModalOutlet />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Modal(
+  cx: Scope,
+  /// Whether the modal is open.
+  #[prop(into)]
+  when: MaybeSignal<bool>,
+  /// Called when the user asks to close the modal.
+  #[prop(optional)]
+  on_close: Option<Box<dyn Fn()>>,
+  /// Calls `on_close` when <kbd>Escape</kbd> is pressed within the dialog.
+  #[prop(optional)]
+  close_on_escape: bool,
+  /// Calls `on_close` when the backdrop around the dialog is clicked.
+  #[prop(optional)]
+  close_on_backdrop: bool,
+  /// The `class` of the dialog element.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// The `class` of the backdrop element.
+  #[prop(optional, into)]
+  backdrop_class: Option<String>,
+  /// The content of the dialog.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let on_close: Rc<dyn Fn()> = match on_close {
+    Some(on_close) => on_close.into(),
+    None => Rc::new(|| ()),
+  };
+  let children = Rc::new(children);
+
+  let active = when.clone();
+  let trapped = when.clone();
+  let locked = store_value(cx, false);
+
+  create_effect(cx, move |_| {
+    let is_open = when.get();
+
+    if is_open == locked.get_value() {
+      return;
+    }
+
+    locked.set_value(is_open);
+
+    if is_open {
+      lock_scroll();
+    } else {
+      unlock_scroll();
+    }
+  });
+
+  on_cleanup(cx, move || {
+    if locked.get_value() {
+      unlock_scroll();
+    }
+  });
+
+  let on_keydown = {
+    let on_close = on_close.clone();
+
+    move |ev: web_sys::KeyboardEvent| {
+      if close_on_escape && ev.key() == "Escape" {
+        ev.prevent_default();
+
+        on_close();
+      }
+    }
+  };
+
+  let on_backdrop_click = move |ev: web_sys::MouseEvent| {
+    if close_on_backdrop && ev.target() == ev.current_target() {
+      on_close();
+    }
+  };
+
+  let dialog = move |cx: Scope| {
+    let children = children.clone();
+
+    view! { cx,
+      <FocusTrap
+        active=trapped.clone()
+        class=class.clone()
+        attrs=vec![("role", "dialog".into()), ("aria-modal", "true".into())]
+      >
+        {children(cx)}
+      </FocusTrap>
+    }
+  };
+
+  view! { cx,
+    <PortalInput id=ModalRegion active>
+      <div
+        class=backdrop_class.clone()
+        on:click=on_backdrop_click.clone()
+        on:keydown=on_keydown.clone()
+      >
+        {dialog(cx)}
+      </div>
+    </PortalInput>
+  }
+}
+
+fn body_style() -> Option<web_sys::CssStyleDeclaration> {
+  Some(try_window()?.document()?.body()?.style())
+}
+
+fn lock_scroll() {
+  if SCROLL_LOCKS.with(|locks| locks.replace(locks.get() + 1)) == 0 {
+    if let Some(style) = body_style() {
+      let previous = style.get_property_value("overflow").unwrap_or_default();
+
+      PREVIOUS_OVERFLOW.with(|overflow| *overflow.borrow_mut() = previous);
+
+      let _ = style.set_property("overflow", "hidden");
+    }
+  }
+}
+
+fn unlock_scroll() {
+  // Only the last open modal restores the scroll
+  if SCROLL_LOCKS.with(|locks| locks.replace(locks.get().saturating_sub(1)))
+    != 1
+  {
+    return;
+  }
+
+  if let Some(style) = body_style() {
+    let previous = PREVIOUS_OVERFLOW.with(|overflow| overflow.take());
+
+    let _ = if previous.is_empty() {
+      style.remove_property("overflow").map(drop)
+    } else {
+      style.set_property("overflow", &previous)
+    };
+  }
+}
@@ -0,0 +1,72 @@
+//! Rendering something once a condition was met for [`leptos`].
+//!
+//! Please refer to [`Once`] for usage examples.
+
+use leptos::*;
+use std::cell::{
+  Cell,
+  RefCell,
+};
+
+api_planning! {
+  view! { cx,
+    <Once when=show_hint>
+      <OnboardingHint />
+    </Once>
+  }
+}
+
+/// Renders it's children the first time `when` is `true`, and keeps them
+/// afterwards, even if `when` becomes `false` again.
+///
+/// The children are only ever rendered once, which is handy for one time
+/// hints, and for widgets which are expensive to initialize and should only
+/// be initialized when first needed.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (opened, set_opened) = create_signal(cx, false);
+///
+/// view! { cx,
+/// <button on:click=move |_| set_opened(true)>"Open editor"</button>
+/// <Once when=opened>
+///   <textarea />
+/// </Once>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Once(
+  cx: Scope,
+  /// Once this is `true`, the children are rendered.
+  #[prop(into)]
+  when: MaybeSignal<bool>,
+  /// What you want to render once `when` is `true`.
+  children: Children,
+) -> impl IntoView {
+  // Stops tracking `when` once it's been `true`, so this never changes again
+  let met = create_memo(cx, move |was_met: Option<&bool>| {
+    was_met.copied().unwrap_or_default() || when.get()
+  });
+
+  let children = Cell::new(Some(children));
+  let rendered = RefCell::new(None::<View>);
+
+  move || {
+    if !met.get() {
+      return None;
+    }
+
+    // Rendered untracked, so signals read by the children don't re-run this,
+    // and kept, since the children can't be rendered again
+    if let Some(children) = children.take() {
+      *rendered.borrow_mut() = Some(cx.untrack(|| children(cx)).into_view(cx));
+    }
+
+    rendered.borrow().clone()
+  }
+}
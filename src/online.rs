@@ -0,0 +1,192 @@
+//! Rendering based on network connectivity for [`leptos`].
+//!
+//! Please refer to [`Online`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use std::{
+  future::Future,
+  pin::Pin,
+  rc::Rc,
+  time::Duration,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <Offline><Banner>"You are offline"</Banner></Offline>
+    <Online ping=Ping::new(Duration::from_secs(30), ping_api)>
+      <LiveFeed />
+    </Online>
+  }
+}
+
+/// Renders it's children only while the browser is online.
+///
+/// The browser only knows whether it's connected to a network, which doesn't
+/// mean the internet, or your server, can be reached. Set `ping` to also
+/// check that periodically, such as by requesting a health endpoint.
+///
+/// Use [`Offline`] for the opposite. The children are rendered on the server.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let ping = Ping::new(Duration::from_secs(30), || async { true });
+///
+/// view! { cx,
+/// <Offline><p>"You are offline, changes will be synced later"</p></Offline>
+/// <Online ping><p>"All changes saved"</p></Online>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Online(
+  cx: Scope,
+  /// Periodically checks whether the browser is really online.
+  #[prop(optional)]
+  ping: Option<Ping>,
+  /// What you want to render while online.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let online = use_online(cx, ping);
+
+  move || {
+    if online.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Renders it's children only while the browser is offline.
+///
+/// This is the opposite of [`Online`]. Nothing is rendered on the server.
+#[component]
+pub fn Offline(
+  cx: Scope,
+  /// Periodically checks whether the browser is really online.
+  #[prop(optional)]
+  ping: Option<Ping>,
+  /// What you want to render while offline.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let online = use_online(cx, ping);
+
+  move || {
+    if online.get() {
+      ().into_view(cx)
+    } else {
+      children(cx).into_view(cx)
+    }
+  }
+}
+
+/// Periodically checks whether the browser is really online, for [`Online`],
+/// [`Offline`] and [`use_online`].
+#[derive(Clone)]
+pub struct Ping {
+  interval: Duration,
+  check: Rc<dyn Fn() -> Pin<Box<dyn Future<Output = bool>>>>,
+}
+
+impl Ping {
+  /// Calls `check` every `interval` while the browser is connected to a
+  /// network, considering the browser offline whenever it returns `false`.
+  pub fn new<F, Fut>(interval: Duration, check: F) -> Self
+  where
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = bool> + 'static,
+  {
+    Self {
+      interval,
+      check: Rc::new(move || Box::pin(check())),
+    }
+  }
+}
+
+/// Returns whether the browser is online, which updates whenever it connects
+/// or disconnects, and whenever `ping` checks.
+///
+/// `true` is returned on the server.
+pub fn use_online(cx: Scope, ping: Option<Ping>) -> Signal<bool> {
+  let connected = create_rw_signal(cx, true);
+  let reachable = create_rw_signal(cx, true);
+
+  create_effect(cx, move |_| {
+    let Some(window) = try_window() else {
+      return;
+    };
+
+    connected.set(window.navigator().on_line());
+
+    let on_change = Closure::<dyn Fn()>::new({
+      let window = window.clone();
+
+      move || connected.set(window.navigator().on_line())
+    });
+
+    for event in ["online", "offline"] {
+      let _ = window.add_event_listener_with_callback(
+        event,
+        on_change.as_ref().unchecked_ref(),
+      );
+    }
+
+    on_cleanup(cx, move || {
+      for event in ["online", "offline"] {
+        let _ = window.remove_event_listener_with_callback(
+          event,
+          on_change.as_ref().unchecked_ref(),
+        );
+      }
+    });
+  });
+
+  if let Some(Ping { interval, check }) = ping {
+    let handle = store_value(cx, None::<IntervalHandle>);
+
+    let stop = move || {
+      handle.update_value(|handle| {
+        if let Some(handle) = handle.take() {
+          handle.clear();
+        }
+      })
+    };
+
+    // Pings start over whenever the browser reconnects
+    create_effect(cx, move |_| {
+      stop();
+
+      if try_window().is_none() || !connected.get() {
+        return;
+      }
+
+      let run = {
+        let check = check.clone();
+
+        move || {
+          let check = check();
+
+          spawn_local(async move { reachable.set(check.await) });
+        }
+      };
+
+      run();
+
+      handle.set_value(set_interval_with_handle(run, interval).ok());
+    });
+
+    on_cleanup(cx, stop);
+  }
+
+  Signal::derive(cx, move || connected.get() && reachable.get())
+}
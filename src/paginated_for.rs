@@ -0,0 +1,355 @@
+//! Paginated lists for [`leptos`].
+//!
+//! Please refer to [`PaginatedFor`] for usage examples.
+
+use crate::{
+  error::{
+    report,
+    Error,
+  },
+  for_each::{
+    EmptyBlock,
+    ItemBlock,
+  },
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  hash::Hash,
+  rc::Rc,
+};
+
+api_planning! {
+  view! { cx,
+    <PaginatedFor each=todos key=|todo| todo.id per_page=20>
+      <Item view=|cx, todo: Todo| view! { cx, <li>{todo.title}</li> } />
+      <Empty>"Nothing to do!"</Empty>
+      <Pager view=|cx, pager: PagerState| view! { cx,
+        <button on:click=move |_| pager.prev() disabled=move || !pager.has_prev()>"Previous"</button>
+        <span>{move || pager.page() + 1}" / "{move || pager.pages()}</span>
+        <button on:click=move |_| pager.next() disabled=move || !pager.has_next()>"Next"</button>
+      } />
+    </PaginatedFor>
+  }
+}
+
+/// Renders one page of a keyed list at a time, like
+/// [`ForEach`](crate::for_each::ForEach), followed by the [`Pager`] arm, which
+/// moves between pages.
+///
+/// Each item is rendered with the `view` of the
+/// [`Item`](crate::for_each::Item) child, and the
+/// [`Empty`](crate::for_each::Empty) arm is shown instead while the list has no
+/// items. The current page is kept within bounds as the list changes.
+///
+/// For more docs on allowed child components, check out
+/// [`PaginatedForProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (todos, _) = create_signal(cx, (0..100).collect::<Vec<usize>>());
+///
+/// view! { cx,
+/// <ul>
+///   <PaginatedFor each=todos key=|todo| *todo per_page=10>
+///     <Item view=|cx, todo: usize| view! { cx, <li>"Todo "{todo}</li> } />
+///     <Empty>"Nothing to do!"</Empty>
+///     <Pager view=|cx, pager: PagerState| view! { cx,
+///       <button on:click=move |_| pager.prev()>"Previous"</button>
+///       <span>{move || pager.page() + 1}" / "{move || pager.pages()}</span>
+///       <button on:click=move |_| pager.next()>"Next"</button>
+///     } />
+///   </PaginatedFor>
+/// </ul>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn PaginatedFor<T, KF, K>(
+  cx: Scope,
+  /// The items to paginate.
+  #[prop(into)]
+  each: Signal<Vec<T>>,
+  /// Returns a key which uniquely identifies an item, so items are only
+  /// re-rendered when they are added.
+  key: KF,
+  /// The number of items on each page.
+  #[prop(into)]
+  per_page: MaybeSignal<usize>,
+  /// Kept in sync with the index of the current page, starting at `0`.
+  /// Setting it moves to that page.
+  #[prop(optional)]
+  page: Option<RwSignal<usize>>,
+  /// The arms of the list.
+  ///
+  /// Children must be any
+  /// - [`Item`](crate::for_each::Item)
+  /// - [`Empty`](crate::for_each::Empty)
+  /// - [`Pager`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  ///
+  /// [`Item`](crate::for_each::Item) must be present.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  KF: Fn(&T) -> K + 'static,
+  K: Eq + Hash + 'static,
+  T: Clone + 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let item = blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<ItemBlock<T>>)
+    .map(|block| block.view.clone());
+
+  if item.is_none() {
+    report(Error::MissingItem);
+  }
+
+  let per_page = Signal::derive(cx, move || per_page.get().max(1));
+  let pages =
+    create_memo(cx, move |_| page_count(each.with(Vec::len), per_page.get()));
+
+  let pager = PagerState {
+    page: page.unwrap_or_else(|| create_rw_signal(cx, 0)),
+    pages: pages.into(),
+  };
+
+  let is_empty = create_memo(cx, move |_| each.with(Vec::is_empty));
+
+  let items = move || {
+    let start = pager.page() * per_page.get();
+    let end = start.saturating_add(per_page.get());
+
+    each.with(|items| {
+      let end = end.min(items.len());
+      let start = start.min(end);
+
+      items[start..end].to_vec()
+    })
+  };
+
+  let key = Rc::new(key);
+
+  move || {
+    if is_empty.get() {
+      return blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<EmptyBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+        .unwrap_or_else(|| ().into_view(cx));
+    }
+
+    let Some(item) = item.clone() else {
+      return ().into_view(cx);
+    };
+
+    let key = key.clone();
+
+    let list = view! { cx,
+      <For
+        each=items
+        key=move |data: &T| key(data)
+        view=move |cx, data| item(cx, data)
+      />
+    }
+    .into_view(cx);
+
+    let pagers = blocks
+      .iter()
+      .filter_map(Transparent::downcast_ref::<PagerBlock>)
+      .map(|block| (block.view)(cx, pager));
+
+    Fragment::new(std::iter::once(list).chain(pagers).collect()).into_view(cx)
+  }
+}
+
+/// Rendered after the items of the parent [`PaginatedFor`], with the
+/// [`PagerState`] used to move between pages.
+#[component(transparent)]
+pub fn Pager<F, IV>(
+  cx: Scope,
+  /// Renders the page controls.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, PagerState) -> IV + 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  PagerBlock {
+    view: Box::new(move |cx, pager| view(cx, pager).into_view(cx)),
+  }
+}
+
+transparent_block! {
+  /// The page controls arm returned by [`Pager`].
+  pub struct PagerBlock {
+    view: Box<dyn Fn(Scope, PagerState) -> View>,
+  }
+}
+
+/// Moves between the pages of a [`PaginatedFor`], given to the [`Pager`]
+/// arm.
+#[derive(Clone, Copy)]
+pub struct PagerState {
+  page: RwSignal<usize>,
+  pages: Signal<usize>,
+}
+
+impl PagerState {
+  /// The index of the current page, starting at `0`.
+  ///
+  /// This is reactive.
+  pub fn page(&self) -> usize {
+    self.page.get().min(self.pages() - 1)
+  }
+
+  /// The number of pages, which is at least `1`.
+  ///
+  /// This is reactive.
+  pub fn pages(&self) -> usize {
+    self.pages.get()
+  }
+
+  /// Whether there is a page before the current one.
+  ///
+  /// This is reactive.
+  pub fn has_prev(&self) -> bool {
+    self.page() > 0
+  }
+
+  /// Whether there is a page after the current one.
+  ///
+  /// This is reactive.
+  pub fn has_next(&self) -> bool {
+    self.page() + 1 < self.pages()
+  }
+
+  /// Moves to the previous page, unless the current page is the first one.
+  pub fn prev(&self) {
+    let page = self
+      .page
+      .get_untracked()
+      .min(self.pages.get_untracked() - 1);
+
+    self.page.set(page.saturating_sub(1));
+  }
+
+  /// Moves to the next page, unless the current page is the last one.
+  pub fn next(&self) {
+    let last = self.pages.get_untracked() - 1;
+
+    self.page.set((self.page.get_untracked() + 1).min(last));
+  }
+
+  /// Moves to the page at `index`, or the last page if there are fewer.
+  pub fn goto(&self, index: usize) {
+    self.page.set(index.min(self.pages.get_untracked() - 1));
+  }
+}
+
+/// The number of pages `len` items take with `per_page` items per page, which
+/// is at least `1`.
+fn page_count(len: usize, per_page: usize) -> usize {
+  len.div_ceil(per_page.max(1)).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn counts_pages() {
+    assert_eq!(page_count(0, 10), 1);
+    assert_eq!(page_count(1, 10), 1);
+    assert_eq!(page_count(10, 10), 1);
+    assert_eq!(page_count(11, 10), 2);
+    assert_eq!(page_count(5, 0), 5);
+    assert_eq!(page_count(usize::MAX, 2), usize::MAX / 2 + 1);
+    assert_eq!(page_count(usize::MAX, usize::MAX), 1);
+  }
+
+  fn pager(
+    cx: Scope,
+    page: usize,
+    pages: usize,
+  ) -> (PagerState, RwSignal<usize>) {
+    let pages = create_rw_signal(cx, pages);
+    let pager = PagerState {
+      page: create_rw_signal(cx, page),
+      pages: pages.into(),
+    };
+
+    (pager, pages)
+  }
+
+  #[test]
+  fn clamps_the_page() {
+    let _ = create_scope(create_runtime(), |cx| {
+      let (pager, pages) = pager(cx, 4, 5);
+
+      assert_eq!(pager.page(), 4);
+      assert!(!pager.has_next());
+
+      pages.set(2);
+      assert_eq!(pager.page(), 1);
+      assert!(pager.has_prev());
+
+      pages.set(1);
+      assert_eq!(pager.page(), 0);
+      assert!(!pager.has_prev());
+      assert!(!pager.has_next());
+    });
+  }
+
+  #[test]
+  fn moves_within_the_pages() {
+    let _ = create_scope(create_runtime(), |cx| {
+      let (pager, _) = pager(cx, 0, 3);
+
+      pager.prev();
+      assert_eq!(pager.page(), 0);
+
+      pager.next();
+      pager.next();
+      pager.next();
+      assert_eq!(pager.page(), 2);
+
+      pager.goto(10);
+      assert_eq!(pager.page(), 2);
+
+      pager.goto(1);
+      assert_eq!(pager.page(), 1);
+    });
+  }
+
+  #[test]
+  fn moves_from_a_clamped_page() {
+    let _ = create_scope(create_runtime(), |cx| {
+      let (pager, pages) = pager(cx, 4, 5);
+
+      pages.set(3);
+      pager.prev();
+      assert_eq!(pager.page(), 1);
+
+      pages.set(5);
+      pager.next();
+      assert_eq!(pager.page(), 2);
+    });
+  }
+}
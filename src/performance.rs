@@ -0,0 +1,175 @@
+//! Rendering instrumentation for [`leptos`].
+//!
+//! Please refer to [`PerformanceMark`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use std::rc::Rc;
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <PerformanceMark name="checkout" on_long_task=Box::new(report)>
+      <If signal=is_logged_in>
+        <Then><Checkout /></Then>
+        <Else><Login /></Else>
+      </If>
+    </PerformanceMark>
+  }
+}
+
+#[derive(Clone)]
+struct PerformanceMarkCtx(Rc<str>);
+
+/// Records `performance.mark` and `performance.measure` entries around the
+/// rendering of its children, so the cost of specific regions of your app
+/// can be attributed in production monitoring data.
+///
+/// The following entries are recorded, prefixed by `name`:
+/// - `{name}:mount`, measuring the initial render of the children
+/// - `{name}:switch`, measuring every branch switch of the following
+///   components, located anywhere below this component:
+///   - [`If`](crate::if_::If)
+///   - [`AnimatedIf`](crate::animated_if::AnimatedIf)
+///   - [`Await`](crate::await_::Await)
+///   - [`Gate`](crate::gate::Gate)
+///   - [`Tabs`](crate::tabs::Tabs), unless it keeps tabs alive
+///   - [`Wizard`](crate::wizard::Wizard)
+///
+/// Each measure has corresponding `:start` and `:end` marks.
+///
+/// Nothing is recorded when not running in a browser.
+///
+/// This component is only available with the `performance` feature.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (a, _) = create_signal(cx, true);
+///
+/// view! { cx,
+/// <PerformanceMark name="sidebar">
+///   <If signal=a>
+///     <Then>"A is true!"</Then>
+///     <Else>"A is false!"</Else>
+///   </If>
+/// </PerformanceMark>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn PerformanceMark(
+  cx: Scope,
+  /// The prefix of every recorded mark and measure.
+  #[prop(into)]
+  name: String,
+  /// Called for every long task reported by the browser while this
+  /// component is mounted.
+  #[prop(optional)]
+  on_long_task: Option<Box<dyn Fn(LongTask)>>,
+  /// The region you would like to instrument.
+  children: Children,
+) -> impl IntoView {
+  let name = Rc::<str>::from(name);
+
+  if let Some(on_long_task) = on_long_task {
+    observe_long_tasks(cx, on_long_task);
+  }
+
+  provide_context(cx, PerformanceMarkCtx(name.clone()));
+
+  let _measure = Measure::start(&name, "mount");
+
+  children(cx)
+}
+
+/// A long task reported to [`PerformanceMark`].
+#[derive(Clone, Copy, Debug)]
+pub struct LongTask {
+  /// When the task started, in milliseconds since the time origin.
+  pub start_time: f64,
+  /// How long the task took, in milliseconds.
+  pub duration: f64,
+}
+
+/// Records the `:start` mark when created, and the `:end` mark along with the
+/// measure when dropped.
+pub(crate) struct Measure(String);
+
+impl Measure {
+  fn start(prefix: &str, kind: &str) -> Self {
+    let name = format!("{prefix}:{kind}");
+
+    mark(&format!("{name}:start"));
+
+    Self(name)
+  }
+}
+
+impl Drop for Measure {
+  fn drop(&mut self) {
+    let start = format!("{}:start", self.0);
+    let end = format!("{}:end", self.0);
+
+    mark(&end);
+
+    if let Some(performance) = try_window().and_then(|w| w.performance()) {
+      let _ =
+        performance.measure_with_start_mark_and_end_mark(&self.0, &start, &end);
+    }
+  }
+}
+
+/// Starts measuring a branch switch if there is a [`PerformanceMark`] above
+/// `cx`. The measure is recorded when the returned value is dropped.
+pub(crate) fn measure_switch(cx: Scope) -> Option<Measure> {
+  use_context::<PerformanceMarkCtx>(cx)
+    .map(|PerformanceMarkCtx(name)| Measure::start(&name, "switch"))
+}
+
+fn mark(name: &str) {
+  if let Some(performance) = try_window().and_then(|w| w.performance()) {
+    let _ = performance.mark(name);
+  }
+}
+
+fn observe_long_tasks(cx: Scope, on_long_task: Box<dyn Fn(LongTask)>) {
+  if try_window().is_none() {
+    return;
+  }
+
+  let callback = Closure::<dyn Fn(web_sys::PerformanceObserverEntryList)>::new(
+    move |list: web_sys::PerformanceObserverEntryList| {
+      for entry in list.get_entries().iter() {
+        let entry = entry.unchecked_into::<web_sys::PerformanceEntry>();
+
+        on_long_task(LongTask {
+          start_time: entry.start_time(),
+          duration: entry.duration(),
+        });
+      }
+    },
+  );
+
+  let Ok(observer) =
+    web_sys::PerformanceObserver::new(callback.as_ref().unchecked_ref())
+  else {
+    return;
+  };
+
+  let entry_types = js_sys::Array::of1(&"longtask".into());
+
+  observer.observe(&web_sys::PerformanceObserverInit::new(&entry_types));
+
+  on_cleanup(cx, move || {
+    observer.disconnect();
+
+    drop(callback);
+  });
+}
@@ -0,0 +1,231 @@
+//! Role based authorization for [`leptos`].
+//!
+//! Please refer to [`PermissionProvider`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+
+api_planning! {
+  view! { cx,
+    <PermissionProvider roles=user_roles>
+      <RequireRole role="admin">
+        <Allowed><AdminPanel /></Allowed>
+        <Denied>"You can't see this"</Denied>
+      </RequireRole>
+      <RequireAny roles=vec!["editor".into(), "admin".into()]>
+        <Allowed><EditButton /></Allowed>
+      </RequireAny>
+    </PermissionProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<PermissionProvider />",
+  context: "Permissions",
+};
+
+/// Holds the roles of the current user, which [`RequireRole`] and
+/// [`RequireAny`] below it check.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (roles, _) = create_signal(cx, vec!["editor".to_string()]);
+///
+/// view! { cx,
+/// <PermissionProvider roles>
+///   <RequireRole role="admin">
+///     <Allowed>"Admin panel"</Allowed>
+///     <Denied>"You can't see this"</Denied>
+///   </RequireRole>
+///   <RequireAny roles=vec!["editor".into(), "admin".into()]>
+///     <Allowed><button>"Edit"</button></Allowed>
+///   </RequireAny>
+/// </PermissionProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn PermissionProvider(
+  cx: Scope,
+  /// The roles of the current user.
+  #[prop(into)]
+  roles: MaybeSignal<Vec<String>>,
+  /// The part of your app which checks the roles.
+  children: Children,
+) -> impl IntoView {
+  provide_context(
+    cx,
+    Permissions {
+      roles: Signal::derive(cx, move || roles.get()),
+    },
+  );
+
+  children(cx)
+}
+
+/// Renders the [`Allowed`] arm while the current user has `role`, and the
+/// [`Denied`] arm otherwise.
+///
+/// For more docs on allowed child components, check out
+/// [`RequireRoleProps::children`].
+///
+/// # Panics
+/// Panics if there is no [`PermissionProvider`] above.
+#[component]
+pub fn RequireRole(
+  cx: Scope,
+  /// The role the user must have.
+  #[prop(into)]
+  role: String,
+  /// The arms of the check.
+  ///
+  /// Children must be any
+  /// - [`Allowed`]
+  /// - [`Denied`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let permissions = use_permissions(cx);
+
+  render_arms(cx, move || permissions.has_role(&role), children)
+}
+
+/// Renders the [`Allowed`] arm while the current user has any of `roles`, and
+/// the [`Denied`] arm otherwise.
+///
+/// For more docs on allowed child components, check out
+/// [`RequireAnyProps::children`].
+///
+/// # Panics
+/// Panics if there is no [`PermissionProvider`] above.
+#[component]
+pub fn RequireAny(
+  cx: Scope,
+  /// The roles of which the user must have at least one.
+  roles: Vec<String>,
+  /// The arms of the check.
+  ///
+  /// Children must be any
+  /// - [`Allowed`]
+  /// - [`Denied`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let permissions = use_permissions(cx);
+
+  render_arms(cx, move || permissions.has_any(&roles), children)
+}
+
+/// Renders the [`Allowed`] or [`Denied`] arm of `children`, depending on
+/// `allowed`.
+fn render_arms(
+  cx: Scope,
+  allowed: impl Fn() -> bool + 'static,
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl Fn() -> View {
+  let allowed = create_memo(cx, move |_| allowed());
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if allowed.get() {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<AllowedBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<DeniedBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+    }
+    .unwrap_or_else(|| ().into_view(cx))
+  }
+}
+
+/// Shown while the current user passes the check of the parent
+/// [`RequireRole`] or [`RequireAny`].
+#[component(transparent)]
+pub fn Allowed(
+  cx: Scope,
+  /// What you want to show while the user is allowed.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  AllowedBlock { children }
+}
+
+/// Shown while the current user doesn't pass the check of the parent
+/// [`RequireRole`] or [`RequireAny`].
+#[component(transparent)]
+pub fn Denied(
+  cx: Scope,
+  /// What you want to show while the user is denied.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  DeniedBlock { children }
+}
+
+transparent_block! {
+  /// The allowed arm returned by [`Allowed`].
+  pub struct AllowedBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The denied arm returned by [`Denied`].
+  pub struct DeniedBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// The roles of a [`PermissionProvider`], returned by [`use_permissions`].
+#[derive(Clone, Copy)]
+pub struct Permissions {
+  roles: Signal<Vec<String>>,
+}
+
+impl Permissions {
+  /// Whether the current user has `role`.
+  ///
+  /// This is reactive.
+  pub fn has_role(&self, role: &str) -> bool {
+    self.roles.with(|roles| roles.iter().any(|r| r == role))
+  }
+
+  /// Whether the current user has any of `roles`.
+  ///
+  /// This is reactive.
+  pub fn has_any(&self, roles: &[String]) -> bool {
+    roles.iter().any(|role| self.has_role(role))
+  }
+}
+
+/// Gets the roles of the nearest [`PermissionProvider`].
+///
+/// # Panics
+/// Panics if there is no [`PermissionProvider`] above.
+pub fn use_permissions(cx: Scope) -> Permissions {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
@@ -0,0 +1,570 @@
+//! Persisting signals to `localStorage` and other storages for [`leptos`].
+//!
+//! Please refer to [`Persist`] for usage examples.
+
+use crate::util::try_window;
+use leptos::*;
+use serde::{
+  de::DeserializeOwned,
+  Serialize,
+};
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  future::Future,
+  pin::Pin,
+  rc::Rc,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+  JsValue,
+};
+
+api_planning! {
+  view! { cx,
+    <Persist key="sidebar-collapsed" signal=collapsed backend=SessionStorage>
+      <If signal=collapsed>
+        <Then><CollapsedSidebar /></Then>
+        <Else><Sidebar /></Else>
+      </If>
+    </Persist>
+  }
+}
+
+/// Keeps `signal` in sync with the `key` entry of a storage, and renders it's
+/// children.
+///
+/// When mounted, `signal` is set to the stored value, if any. From then on,
+/// every change of `signal` is stored. Backends which support it, such as
+/// [`LocalStorage`], also apply changes made by other tabs to `signal`, and
+/// reset `signal` to the value it had when this component was created when
+/// the entry is removed.
+///
+/// Values are stored as JSON. Entries which fail to deserialize are ignored.
+/// The browser storages don't store anything on the server, so the initial
+/// value of `signal` is rendered there.
+///
+/// This component is only available with the `persist` feature. Use
+/// [`use_persisted`] outside of views.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let collapsed = create_rw_signal(cx, false);
+///
+/// view! { cx,
+/// <Persist key="sidebar-collapsed" signal=collapsed>
+///   <button on:click=move |_| collapsed.update(|c| *c = !*c)>"Toggle"</button>
+///   <If signal=collapsed>
+///     <Then>"Collapsed"</Then>
+///     <Else>"Expanded"</Else>
+///   </If>
+/// </Persist>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Persist<T>(
+  cx: Scope,
+  /// The storage entry to store `signal` in.
+  #[prop(into)]
+  key: String,
+  /// The signal to persist.
+  signal: RwSignal<T>,
+  /// Where `signal` is stored. Defaults to [`LocalStorage`].
+  #[prop(optional, into)]
+  backend: Option<Backend>,
+  /// The content, which can use `signal` as usual.
+  children: Children,
+) -> impl IntoView
+where
+  T: Serialize + DeserializeOwned + Clone + 'static,
+{
+  persist(
+    cx,
+    key,
+    signal,
+    backend.unwrap_or_else(|| LocalStorage.into()),
+  );
+
+  children(cx)
+}
+
+/// Returns a signal which is kept in sync with the `key` entry of
+/// `localStorage`, starting out as `default`.
+///
+/// Please refer to [`Persist`] for how the entry is synced.
+pub fn use_persisted<T>(
+  cx: Scope,
+  key: impl Into<String>,
+  default: T,
+) -> RwSignal<T>
+where
+  T: Serialize + DeserializeOwned + Clone + 'static,
+{
+  use_persisted_with(cx, key, default, LocalStorage)
+}
+
+/// Returns a signal which is kept in sync with the `key` entry of `backend`,
+/// starting out as `default`.
+///
+/// Please refer to [`Persist`] for how the entry is synced.
+pub fn use_persisted_with<T>(
+  cx: Scope,
+  key: impl Into<String>,
+  default: T,
+  backend: impl Into<Backend>,
+) -> RwSignal<T>
+where
+  T: Serialize + DeserializeOwned + Clone + 'static,
+{
+  let signal = create_rw_signal(cx, default);
+
+  persist(cx, key.into(), signal, backend.into());
+
+  signal
+}
+
+/// Syncs `signal` with the `key` entry of `backend`.
+fn persist<T>(cx: Scope, key: String, signal: RwSignal<T>, backend: Backend)
+where
+  T: Serialize + DeserializeOwned + Clone + 'static,
+{
+  let key: Rc<str> = key.into();
+  let default = signal.get_untracked();
+  // Changes aren't stored until the stored value was loaded, so it isn't
+  // overwritten
+  let loaded = store_value(cx, false);
+
+  create_effect(cx, {
+    let key = key.clone();
+    let backend = backend.clone();
+
+    move |_| {
+      let load = backend.0.load(&key);
+
+      spawn_local(async move {
+        if let Some(value) =
+          load.await.and_then(|json| serde_json::from_str(&json).ok())
+        {
+          signal.set(value);
+        }
+
+        loaded.set_value(true);
+      });
+
+      let default = default.clone();
+
+      let unwatch = backend.0.watch(
+        &key,
+        Box::new(move |json| match json {
+          Some(json) => {
+            if let Ok(value) = serde_json::from_str(&json) {
+              signal.set(value);
+            }
+          }
+          None => signal.set(default.clone()),
+        }),
+      );
+
+      if let Some(unwatch) = unwatch {
+        on_cleanup(cx, unwatch);
+      }
+    }
+  });
+
+  create_effect(cx, move |_| {
+    let json = signal.with(|value| serde_json::to_string(value).ok());
+
+    if !loaded.get_value() {
+      return;
+    }
+
+    if let Some(json) = json {
+      spawn_local(backend.0.store(&key, json));
+    }
+  });
+}
+
+/// The future returned by a [`PersistBackend`].
+pub type PersistFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+/// A storage which [`Persist`] can store values in.
+///
+/// Synchronous storages can return their results with
+/// [`std::future::ready`].
+pub trait PersistBackend {
+  /// Loads the value stored at `key`, if any.
+  fn load(&self, key: &str) -> PersistFuture<Option<String>>;
+
+  /// Stores `value` at `key`.
+  fn store(&self, key: &str, value: String) -> PersistFuture<()>;
+
+  /// Calls `on_change` whenever the value stored at `key` is changed from
+  /// elsewhere, such as another tab, with the new value, or [`None`] when it
+  /// was removed.
+  ///
+  /// Returns a function which stops watching, or [`None`] if changes can't
+  /// be watched, which is the default.
+  fn watch(
+    &self,
+    key: &str,
+    on_change: Box<dyn Fn(Option<String>)>,
+  ) -> Option<Box<dyn FnOnce()>> {
+    let _ = (key, on_change);
+
+    None
+  }
+}
+
+/// A shared [`PersistBackend`], which any backend can be converted into.
+#[derive(Clone)]
+pub struct Backend(Rc<dyn PersistBackend>);
+
+impl<B: PersistBackend + 'static> From<B> for Backend {
+  fn from(backend: B) -> Self {
+    Self(Rc::new(backend))
+  }
+}
+
+/// Stores values in `localStorage`, which is shared by every tab.
+///
+/// Changes made by other tabs are watched.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LocalStorage;
+
+impl PersistBackend for LocalStorage {
+  fn load(&self, key: &str) -> PersistFuture<Option<String>> {
+    Box::pin(std::future::ready(load_web_storage(local_storage(), key)))
+  }
+
+  fn store(&self, key: &str, value: String) -> PersistFuture<()> {
+    store_web_storage(local_storage(), key, &value);
+
+    Box::pin(std::future::ready(()))
+  }
+
+  fn watch(
+    &self,
+    key: &str,
+    on_change: Box<dyn Fn(Option<String>)>,
+  ) -> Option<Box<dyn FnOnce()>> {
+    watch_web_storage(local_storage, key, on_change)
+  }
+}
+
+/// Stores values in `sessionStorage`, which only lives as long as the tab.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SessionStorage;
+
+impl PersistBackend for SessionStorage {
+  fn load(&self, key: &str) -> PersistFuture<Option<String>> {
+    Box::pin(std::future::ready(load_web_storage(session_storage(), key)))
+  }
+
+  fn store(&self, key: &str, value: String) -> PersistFuture<()> {
+    store_web_storage(session_storage(), key, &value);
+
+    Box::pin(std::future::ready(()))
+  }
+}
+
+/// Stores values in memory, which is handy for tests.
+///
+/// Clones share the same values.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage(Rc<RefCell<HashMap<String, String>>>);
+
+impl MemoryStorage {
+  /// Creates an empty storage.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the value stored at `key`, if any.
+  pub fn get(&self, key: &str) -> Option<String> {
+    self.0.borrow().get(key).cloned()
+  }
+}
+
+impl PersistBackend for MemoryStorage {
+  fn load(&self, key: &str) -> PersistFuture<Option<String>> {
+    Box::pin(std::future::ready(self.get(key)))
+  }
+
+  fn store(&self, key: &str, value: String) -> PersistFuture<()> {
+    self.0.borrow_mut().insert(key.to_string(), value);
+
+    Box::pin(std::future::ready(()))
+  }
+}
+
+thread_local! {
+  /// The last operation on each IndexedDB database, which the next one waits
+  /// for, so writes are applied in order and upgrades don't race.
+  static IDB_QUEUES: RefCell<HashMap<Rc<str>, js_sys::Promise>> =
+    RefCell::new(HashMap::new());
+}
+
+/// Stores values in an IndexedDB object store, which is created if it
+/// doesn't exist yet.
+///
+/// Multiple object stores can share a database, which is upgraded to a new
+/// version whenever one of them is missing. Operations on the same database
+/// run one after the other, in the order they were started.
+#[derive(Clone, Debug)]
+pub struct IndexedDb {
+  database: Rc<str>,
+  store: Rc<str>,
+}
+
+impl IndexedDb {
+  /// Stores values in the `store` object store of the `database` database.
+  pub fn new(database: impl Into<String>, store: impl Into<String>) -> Self {
+    Self {
+      database: database.into().into(),
+      store: store.into().into(),
+    }
+  }
+
+  /// Opens the database, upgrading it if the object store doesn't exist yet.
+  async fn open(&self) -> Option<web_sys::IdbDatabase> {
+    let database = self.open_version(None).await?;
+
+    if database.object_store_names().contains(&self.store) {
+      return Some(database);
+    }
+
+    // Object stores can only be created while upgrading
+    let version = database.version() as u32 + 1;
+
+    database.close();
+
+    self.open_version(Some(version)).await
+  }
+
+  /// Opens `version` of the database, or the current one, creating the
+  /// object store if the database is upgraded.
+  async fn open_version(
+    &self,
+    version: Option<u32>,
+  ) -> Option<web_sys::IdbDatabase> {
+    let factory = try_window()?.indexed_db().ok().flatten()?;
+
+    let request = match version {
+      Some(version) => factory.open_with_u32(&self.database, version),
+      None => factory.open(&self.database),
+    }
+    .ok()?;
+
+    let on_upgrade = Closure::once_into_js({
+      let request = request.clone();
+      let store = self.store.clone();
+
+      move || {
+        let Ok(database) = request.result() else {
+          return;
+        };
+
+        let database = database.unchecked_into::<web_sys::IdbDatabase>();
+
+        if !database.object_store_names().contains(&store) {
+          let _ = database.create_object_store(&store);
+        }
+      }
+    });
+
+    request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+    idb_request(&request).await.map(JsCast::unchecked_into)
+  }
+
+  /// Opens the object store within a new transaction.
+  async fn object_store(
+    &self,
+    mode: web_sys::IdbTransactionMode,
+  ) -> Option<(web_sys::IdbDatabase, web_sys::IdbObjectStore)> {
+    let database = self.open().await?;
+
+    let store = database
+      .transaction_with_str_and_mode(&self.store, mode)
+      .and_then(|transaction| transaction.object_store(&self.store))
+      .ok()?;
+
+    Some((database, store))
+  }
+
+  /// Runs `op` once every operation previously started on the same database
+  /// finished.
+  fn enqueue<T: 'static>(
+    &self,
+    op: impl Future<Output = Option<T>> + 'static,
+  ) -> PersistFuture<Option<T>> {
+    if try_window().is_none() {
+      return Box::pin(std::future::ready(None));
+    }
+
+    let result = Rc::new(RefCell::new(None));
+    let previous =
+      IDB_QUEUES.with(|queues| queues.borrow().get(&self.database).cloned());
+
+    let done = wasm_bindgen_futures::future_to_promise({
+      let result = result.clone();
+
+      async move {
+        if let Some(previous) = previous {
+          let _ = wasm_bindgen_futures::JsFuture::from(previous).await;
+        }
+
+        *result.borrow_mut() = op.await;
+
+        Ok(JsValue::UNDEFINED)
+      }
+    });
+
+    IDB_QUEUES.with(|queues| {
+      queues
+        .borrow_mut()
+        .insert(self.database.clone(), done.clone())
+    });
+
+    Box::pin(async move {
+      let _ = wasm_bindgen_futures::JsFuture::from(done).await;
+
+      result.borrow_mut().take()
+    })
+  }
+}
+
+impl PersistBackend for IndexedDb {
+  fn load(&self, key: &str) -> PersistFuture<Option<String>> {
+    let this = self.clone();
+    let key = JsValue::from_str(key);
+
+    self.enqueue(async move {
+      let (database, store) = this
+        .object_store(web_sys::IdbTransactionMode::Readonly)
+        .await?;
+
+      let value = match store.get(&key) {
+        Ok(request) => idb_request(&request).await,
+        Err(_) => None,
+      };
+
+      database.close();
+
+      value?.as_string()
+    })
+  }
+
+  fn store(&self, key: &str, value: String) -> PersistFuture<()> {
+    let this = self.clone();
+    let key = JsValue::from_str(key);
+
+    let stored = self.enqueue(async move {
+      let (database, store) = this
+        .object_store(web_sys::IdbTransactionMode::Readwrite)
+        .await?;
+
+      if let Ok(request) = store.put_with_key(&JsValue::from_str(&value), &key)
+      {
+        idb_request(&request).await;
+      }
+
+      database.close();
+
+      Some(())
+    });
+
+    Box::pin(async move {
+      stored.await;
+    })
+  }
+}
+
+/// Waits for an IndexedDB `request` to finish, returning it's result if it
+/// succeeded.
+async fn idb_request(request: &web_sys::IdbRequest) -> Option<JsValue> {
+  let promise = js_sys::Promise::new(&mut |resolve, reject| {
+    let on_success = Closure::once_into_js({
+      let request = request.clone();
+
+      move || {
+        let result = request.result().unwrap_or(JsValue::UNDEFINED);
+
+        let _ = resolve.call1(&JsValue::NULL, &result);
+      }
+    });
+
+    let on_error = Closure::once_into_js(move || {
+      let _ = reject.call0(&JsValue::NULL);
+    });
+
+    request.set_onsuccess(Some(on_success.unchecked_ref()));
+    request.set_onerror(Some(on_error.unchecked_ref()));
+  });
+
+  wasm_bindgen_futures::JsFuture::from(promise).await.ok()
+}
+
+/// Returns `localStorage`, if running in a browser which allows using it.
+fn local_storage() -> Option<web_sys::Storage> {
+  try_window()?.local_storage().ok().flatten()
+}
+
+/// Returns `sessionStorage`, if running in a browser which allows using it.
+fn session_storage() -> Option<web_sys::Storage> {
+  try_window()?.session_storage().ok().flatten()
+}
+
+fn load_web_storage(
+  storage: Option<web_sys::Storage>,
+  key: &str,
+) -> Option<String> {
+  storage?.get_item(key).ok().flatten()
+}
+
+fn store_web_storage(
+  storage: Option<web_sys::Storage>,
+  key: &str,
+  value: &str,
+) {
+  if let Some(storage) = storage {
+    let _ = storage.set_item(key, value);
+  }
+}
+
+/// Watches the `key` entry of the storage returned by `storage` using the
+/// `storage` event, which only fires for changes made by other tabs.
+fn watch_web_storage(
+  storage: fn() -> Option<web_sys::Storage>,
+  key: &str,
+  on_change: Box<dyn Fn(Option<String>)>,
+) -> Option<Box<dyn FnOnce()>> {
+  let window = try_window()?;
+  let key = key.to_string();
+
+  let on_storage = Closure::<dyn Fn(web_sys::StorageEvent)>::new(
+    move |ev: web_sys::StorageEvent| {
+      if ev.key().as_deref() == Some(&*key) && ev.storage_area() == storage() {
+        on_change(ev.new_value());
+      }
+    },
+  );
+
+  let _ = window.add_event_listener_with_callback(
+    "storage",
+    on_storage.as_ref().unchecked_ref(),
+  );
+
+  Some(Box::new(move || {
+    let _ = window.remove_event_listener_with_callback(
+      "storage",
+      on_storage.as_ref().unchecked_ref(),
+    );
+  }))
+}
@@ -0,0 +1,162 @@
+//! Periodically refreshed async data for [`leptos`].
+//!
+//! Please refer to [`Poll`] for usage examples.
+
+use crate::{
+  await_::{
+    render_pending,
+    RejectedBlock,
+    ResolvedBlock,
+  },
+  document_visible::use_document_visible,
+  skeleton::use_group_pending,
+  util::try_window,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  future::Future,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <Poll fetcher=load_status every=Duration::from_secs(10)>
+      <Pending>"Loading status..."</Pending>
+      <Resolved view=|cx, status: Status| view! { cx, <StatusView status /> } />
+      <Rejected view=|cx, error: String| view! { cx, <p>{error}</p> } />
+    </Poll>
+  }
+}
+
+/// Loads data with `fetcher`, and loads it again `every` so often while
+/// mounted, rendering the latest result.
+///
+/// The [`Pending`](crate::await_::Pending) arm is only shown until the first
+/// result is available. Afterwards, the previous result stays on screen
+/// while the next one loads, and is shown in either the
+/// [`Resolved`](crate::await_::Resolved) or the
+/// [`Rejected`](crate::await_::Rejected) arm, just like within an
+/// [`Await`](crate::await_::Await).
+///
+/// Polling pauses while the page is hidden, unless `pause_when_hidden` is
+/// `false`, and polls right away once the page is visible again. The data is
+/// only polled in the browser.
+///
+/// For more docs on allowed child components, check out
+/// [`PollProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let fetcher = || async { Ok::<_, String>(42) };
+///
+/// view! { cx,
+/// <Poll fetcher every=Duration::from_secs(10)>
+///   <Pending>"Loading..."</Pending>
+///   <Resolved view=|cx, online: i32| view! { cx, <p>{online}" online"</p> } />
+///   <Rejected view=|cx, error: String| view! { cx, <p>{error}</p> } />
+/// </Poll>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Poll<L, Fut, T, E>(
+  cx: Scope,
+  /// Asynchronously loads the data. This is called once on mount, and again
+  /// every time the data is polled.
+  fetcher: L,
+  /// How often the data is polled.
+  every: Duration,
+  /// Pauses polling while the page is hidden. Defaults to `true`.
+  #[prop(default = true)]
+  pause_when_hidden: bool,
+  /// The arms of each state of the data.
+  ///
+  /// Children must be any
+  /// - [`Pending`](crate::await_::Pending)
+  /// - [`Resolved`](crate::await_::Resolved)
+  /// - [`Rejected`](crate::await_::Rejected)
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  L: Fn() -> Fut + 'static,
+  Fut: Future<Output = Result<T, E>> + 'static,
+  T: Clone + 'static,
+  E: Clone + 'static,
+{
+  let tick = create_rw_signal(cx, 0usize);
+  let data = create_local_resource(cx, move || tick.get(), move |_| fetcher());
+
+  let visible = use_document_visible(cx);
+  let interval = store_value(cx, None::<IntervalHandle>);
+
+  let stop = move || {
+    interval.update_value(|interval| {
+      if let Some(interval) = interval.take() {
+        interval.clear();
+      }
+    })
+  };
+
+  create_effect(cx, move |was_polling: Option<bool>| {
+    stop();
+
+    let is_polling = !pause_when_hidden || visible.get();
+
+    if try_window().is_none() || !is_polling {
+      return false;
+    }
+
+    // The data is stale after being paused
+    if was_polling == Some(false) {
+      tick.update(|tick| *tick += 1);
+    }
+
+    interval.set_value(
+      set_interval_with_handle(move || tick.update(|tick| *tick += 1), every)
+        .ok(),
+    );
+
+    true
+  });
+
+  on_cleanup(cx, stop);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let pending = use_group_pending(cx, move || data.with(cx, |_| ()).is_none());
+
+  move || {
+    if pending.get() {
+      return render_pending(cx, &blocks);
+    }
+
+    match data.read(cx) {
+      Some(Ok(data)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<ResolvedBlock<T>>)
+        .map(|block| (block.view)(cx, data))
+        .unwrap_or_else(|| ().into_view(cx)),
+      Some(Err(error)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<RejectedBlock<E>>)
+        .map(|block| (block.view)(cx, error))
+        .unwrap_or_else(|| ().into_view(cx)),
+      None => render_pending(cx, &blocks),
+    }
+  }
+}
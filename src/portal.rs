@@ -5,10 +5,29 @@
 //!
 //! For usage examples, please refer to [`PortalInput`].
 
+use crate::{
+  reduced_motion::use_reduced_motion,
+  util::{
+    set_timeout,
+    try_window,
+    Timeout,
+  },
+};
 use leptos::*;
-use std::any::{
-  Any,
-  TypeId,
+use leptos_dom::HydrationCtx;
+use std::{
+  any::{
+    Any,
+    TypeId,
+  },
+  cell::Cell,
+  collections::HashMap,
+  hash::{
+    Hash,
+    Hasher,
+  },
+  rc::Rc,
+  time::Duration,
 };
 
 api_planning! {
@@ -40,18 +59,683 @@ api_planning! {
   }
 }
 
-const CONTEXT_NOT_FOUND_ERROR_MESSAGE: &str =
-  "failed to find `PortalCtx`, make sure you are using `<PortalProvider />` \
-   somewhere near the root of the app";
+#[cfg(not(feature = "global-portal"))]
+const CONTEXT_NOT_FOUND_ERROR: crate::error::Error =
+  crate::error::Error::MissingProvider {
+    provider: "<PortalProvider />",
+    context: "PortalCtx",
+  };
+
+#[cfg(feature = "global-portal")]
+thread_local! {
+  /// The portals used without a [`PortalProvider`], for each runtime.
+  static GLOBAL_PORTALS: std::cell::RefCell<HashMap<RuntimeId, PortalCtx>> =
+    Default::default();
+}
+
+#[derive(Clone, Copy)]
+struct PortalCtx {
+  /// The scope of the [`PortalProvider`], which owns the portal signals so
+  /// they outlive the inputs and outputs which use them.
+  cx: Scope,
+  next_key: StoredValue<usize>,
+  /// The owner of content set with [`portal_batch`].
+  batch_owner: usize,
+  portals: StoredValue<HashMap<PortalKey, Portal>>,
+  /// The outermost [`PortalProvider`], if this one is nested.
+  root: Option<StoredValue<PortalCtx>>,
+  /// The default policy of new portals.
+  policy: PortalPolicy,
+  /// The number of registered portals, so the inspector notices new ones.
+  #[cfg(feature = "devtools")]
+  registered: RwSignal<usize>,
+}
+
+impl PortalCtx {
+  fn new(cx: Scope, policy: PortalPolicy) -> Self {
+    let root =
+      use_context::<PortalCtx>(cx).map(|parent| store_value(cx, parent.root()));
+
+    Self {
+      cx,
+      next_key: store_value(cx, 1),
+      batch_owner: 0,
+      portals: store_value(cx, Default::default()),
+      root,
+      policy,
+      #[cfg(feature = "devtools")]
+      registered: create_rw_signal(cx, 0),
+    }
+  }
+
+  /// Gets the context of the nearest [`PortalProvider`], or of the outermost
+  /// one if `global` is set.
+  ///
+  /// With the `global-portal` feature, the runtime-level registry is used if
+  /// there is no provider.
+  fn from_context(cx: Scope, global: bool) -> Self {
+    #[cfg(feature = "global-portal")]
+    let portal_ctx =
+      use_context::<Self>(cx).unwrap_or_else(|| Self::runtime_global(cx));
+    #[cfg(not(feature = "global-portal"))]
+    let portal_ctx =
+      crate::error::expect_context::<Self>(cx, CONTEXT_NOT_FOUND_ERROR);
+
+    if global {
+      portal_ctx.root()
+    } else {
+      portal_ctx
+    }
+  }
+
+  /// Like [`from_context`](Self::from_context), but warns and returns `None`
+  /// instead of failing when there is no provider, unless `strict` is set.
+  fn try_from_context(cx: Scope, global: bool, strict: bool) -> Option<Self> {
+    #[cfg(not(feature = "global-portal"))]
+    if use_context::<Self>(cx).is_none() {
+      if strict {
+        crate::error::fail(CONTEXT_NOT_FOUND_ERROR);
+      }
+
+      warn!("{CONTEXT_NOT_FOUND_ERROR}");
+
+      return None;
+    }
+
+    #[cfg(feature = "global-portal")]
+    let _ = strict;
+
+    Some(Self::from_context(cx, global))
+  }
+
+  /// Like [`from_context`](Self::from_context), but reports the error and
+  /// returns `None` instead of failing when there is no provider.
+  fn report_from_context(cx: Scope, global: bool) -> Option<Self> {
+    #[cfg(not(feature = "global-portal"))]
+    if use_context::<Self>(cx).is_none() {
+      crate::error::report(CONTEXT_NOT_FOUND_ERROR);
+
+      return None;
+    }
+
+    Some(Self::from_context(cx, global))
+  }
+
+  /// Gets the registry shared by everything in the runtime of `cx` which is
+  /// not below a [`PortalProvider`], creating it if needed.
+  #[cfg(feature = "global-portal")]
+  fn runtime_global(cx: Scope) -> Self {
+    let runtime = cx.runtime;
+
+    GLOBAL_PORTALS.with(|globals| {
+      *globals.borrow_mut().entry(runtime).or_insert_with(|| {
+        let mut root = cx;
+
+        while let Some(parent) = root.parent() {
+          root = parent;
+        }
+
+        // The registry must outlive whichever component happened to use it
+        // first, so it belongs to the root scope, and is forgotten once the
+        // root scope is disposed
+        let (portal_ctx, _) =
+          root.run_child_scope(|cx| Self::new(cx, PortalPolicy::default()));
+
+        on_cleanup(root, move || {
+          let _ = GLOBAL_PORTALS.try_with(|globals| {
+            globals.borrow_mut().remove(&runtime);
+          });
+        });
+
+        portal_ctx
+      })
+    })
+  }
+
+  fn root(&self) -> Self {
+    self.root.map_or(*self, |root| root.get_value())
+  }
+
+  /// Gets the portal with the given `id`, registering it if it doesn't exist
+  /// yet.
+  fn portal(&self, id: PortalKey) -> Portal {
+    self.portals([id]).pop().unwrap()
+  }
+
+  /// Gets the portals with the given `ids`, in order, registering the ones
+  /// which don't exist yet all at once.
+  fn portals(&self, ids: impl IntoIterator<Item = PortalKey>) -> Vec<Portal> {
+    let cx = self.cx;
+    let policy = self.policy;
+    let mut found = Vec::new();
+
+    self.portals.update_value(|portals| {
+      found.extend(ids.into_iter().map(|id| {
+        #[cfg(feature = "tracing")]
+        let name = id.0.debug_name();
+
+        *portals.entry(id).or_insert_with(|| {
+          #[cfg(feature = "tracing")]
+          tracing::debug!(id = %name, "registered portal");
+
+          Portal {
+            contents: create_rw_signal(cx, Vec::new()),
+            outputs: create_rw_signal(cx, Vec::new()),
+            policy: store_value(cx, policy),
+            selections: store_value(cx, HashMap::new()),
+            #[cfg(feature = "devtools")]
+            last_changed: create_rw_signal(cx, None),
+            #[cfg(feature = "tracing")]
+            name: store_value(cx, name),
+          }
+        })
+      }));
+    });
+
+    // Notified outside of `update_value`, since the inspector reads the
+    // registry as soon as it is notified
+    #[cfg(feature = "devtools")]
+    if self.portals.with_value(HashMap::len) != self.registered.get_untracked()
+    {
+      self.registered.set(self.portals.with_value(HashMap::len));
+    }
+
+    found
+  }
+
+  /// Lists the state of every registered portal. This is reactive.
+  #[cfg(feature = "devtools")]
+  fn snapshot(&self) -> Vec<PortalSnapshot> {
+    self.registered.track();
+
+    let mut snapshot = self.portals.with_value(|portals| {
+      portals
+        .iter()
+        .map(|(key, portal)| PortalSnapshot {
+          id: key.0.debug_name(),
+          inputs: portal.contents.with(Vec::len),
+          outputs: portal.outputs.with(Vec::len),
+          last_changed: portal.last_changed.get(),
+        })
+        .collect::<Vec<_>>()
+    });
+
+    // The registry is unordered, so sort to keep the inspector stable
+    snapshot.sort_by(|a, b| a.id.cmp(&b.id));
+
+    snapshot
+  }
+
+  /// Returns a key which uniquely identifies a [`PortalContent`], or the
+  /// owner of some content.
+  fn next_key(&self) -> usize {
+    let key = self.next_key.get_value();
+
+    self.next_key.set_value(key + 1);
+
+    key
+  }
+}
+
+/// The state of a single portal `id`.
+#[derive(Clone, Copy)]
+struct Portal {
+  contents: RwSignal<Vec<PortalContent>>,
+  /// The keys of the mounted [`PortalOutput`]s, in the order they were
+  /// mounted.
+  outputs: RwSignal<Vec<usize>>,
+  /// The policy of outputs which don't set their own.
+  policy: StoredValue<PortalPolicy>,
+  /// What each mounted [`PortalOutput`] currently selects, by output key.
+  selections: StoredValue<HashMap<usize, PortalSelection>>,
+  /// When the contents last changed, in milliseconds since the epoch.
+  #[cfg(feature = "devtools")]
+  last_changed: RwSignal<Option<f64>>,
+  /// The [`PortalId::debug_name`] of the `id`, for tracing.
+  #[cfg(feature = "tracing")]
+  name: StoredValue<String>,
+}
+
+impl Portal {
+  /// Adds `content`, replacing any previous content from the same owner and
+  /// keeping contents sorted by their `order`.
+  fn set(&self, content: PortalContent) {
+    #[cfg(feature = "tracing")]
+    self.name.with_value(
+      |id| tracing::debug!(%id, owner = content.owner, "set portal content"),
+    );
+
+    self.contents.update(|contents| {
+      contents.retain(|c| c.owner != content.owner);
+      contents.push(content);
+
+      // The sort is stable, so insertion order is kept for equal `order`s
+      contents.sort_by_key(|content| content.order);
+    });
+
+    #[cfg(feature = "devtools")]
+    self.touch();
+  }
+
+  /// Removes the content of `owner`.
+  fn remove(&self, owner: usize) {
+    #[cfg(feature = "tracing")]
+    self
+      .name
+      .with_value(|id| tracing::debug!(%id, owner, "removed portal content"));
+
+    self
+      .contents
+      .update(|contents| contents.retain(|content| content.owner != owner));
+
+    #[cfg(feature = "devtools")]
+    self.touch();
+  }
+
+  /// Removes all content.
+  fn clear(&self) {
+    #[cfg(feature = "tracing")]
+    self
+      .name
+      .with_value(|id| tracing::debug!(%id, "cleared portal content"));
+
+    self.contents.update(Vec::clear);
+
+    #[cfg(feature = "devtools")]
+    self.touch();
+  }
+
+  /// Records that the contents changed just now.
+  #[cfg(feature = "devtools")]
+  fn touch(&self) {
+    if try_window().is_some() {
+      self.last_changed.set(Some(js_sys::Date::now()));
+    }
+  }
+
+  /// Whether any [`PortalOutput`] is mounted. This is reactive.
+  fn is_connected(&self) -> bool {
+    self.outputs.with(|outputs| !outputs.is_empty())
+  }
+
+  /// Whether there is any content to project. This is reactive.
+  fn has_content(&self) -> bool {
+    self.contents.with(|contents| !contents.is_empty())
+  }
+}
+
+/// Decides what a portal shows when multiple [`PortalInput`]s share the same
+/// `id`.
+///
+/// Content set with [`use_portal`] and [`portal_batch`] is treated the same
+/// as content from a [`PortalInput`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PortalPolicy {
+  /// Every input is shown, sorted by their `order`.
+  #[default]
+  All,
+  /// Only the most recently mounted input is shown. Previously mounted
+  /// inputs are discarded, so nothing is shown once it's unmounted.
+  ReplaceLatest,
+  /// Only the first mounted input is shown. Inputs mounted while it is shown
+  /// are discarded.
+  KeepFirst,
+  /// Only the most recently mounted input is shown. Once it's unmounted, the
+  /// previous one is shown again. This is what you want for layered modals.
+  Stack,
+  /// Only the first mounted input is shown. Once it's unmounted, the next one
+  /// is shown.
+  Queue,
+}
+
+/// Which contents a [`PortalOutput`] shows, as decided by a [`PortalSelection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Selected {
+  All,
+  Only(usize),
+  Nothing,
+}
+
+impl Selected {
+  fn contains(self, key: usize) -> bool {
+    match self {
+      Self::All => true,
+      Self::Only(only) => only == key,
+      Self::Nothing => false,
+    }
+  }
+}
+
+/// Applies a [`PortalPolicy`] for a single [`PortalOutput`].
+///
+/// Contents are never removed from the portal because of a policy, so that
+/// outputs with different policies can share it. Instead, each output
+/// remembers which contents it already discarded.
+#[derive(Clone, Copy, Debug)]
+struct PortalSelection {
+  policy: PortalPolicy,
+  /// The content shown by [`PortalPolicy::ReplaceLatest`] and
+  /// [`PortalPolicy::KeepFirst`].
+  shown: Option<usize>,
+  /// The highest key seen so far. Anything above it was added since the last
+  /// selection.
+  seen: Option<usize>,
+}
+
+impl PortalSelection {
+  fn new(policy: PortalPolicy) -> Self {
+    Self {
+      policy,
+      shown: None,
+      seen: None,
+    }
+  }
+
+  /// Decides which of `contents` are shown, and remembers it for next time.
+  fn select(&mut self, contents: &[PortalContent]) -> Selected {
+    // Keys are increasing, so they also reflect insertion order
+    let keys = || contents.iter().map(|content| content.key);
+    let is_present = |key: usize| keys().any(|k| k == key);
+
+    let seen = self.seen;
+    let added = keys().filter(move |key| seen.map_or(true, |seen| *key > seen));
+
+    self.seen = self.seen.max(keys().max());
+
+    match self.policy {
+      PortalPolicy::All => return Selected::All,
+      PortalPolicy::Stack => {
+        return keys().max().map_or(Selected::Nothing, Selected::Only)
+      }
+      PortalPolicy::Queue => {
+        return keys().min().map_or(Selected::Nothing, Selected::Only)
+      }
+      PortalPolicy::ReplaceLatest => {
+        if let Some(latest) = added.max() {
+          self.shown = Some(latest);
+        }
+      }
+      PortalPolicy::KeepFirst => {
+        if !self.shown.map_or(false, is_present) {
+          self.shown = added.min();
+        }
+      }
+    }
+
+    self
+      .shown
+      .filter(|key| is_present(*key))
+      .map_or(Selected::Nothing, Selected::Only)
+  }
+}
+
+/// Calls `on_connect` and `on_disconnect` whenever `connected` changes, and
+/// `on_disconnect` if still connected when `cx` is disposed.
+fn watch_connection(
+  cx: Scope,
+  connected: impl Fn() -> bool + 'static,
+  on_connect: Option<Box<dyn Fn()>>,
+  on_disconnect: Option<Box<dyn Fn()>>,
+) {
+  if on_connect.is_none() && on_disconnect.is_none() {
+    return;
+  }
+
+  let on_disconnect = Rc::new(on_disconnect);
+  let was_connected = Rc::new(Cell::new(false));
+
+  create_effect(cx, {
+    let on_disconnect = on_disconnect.clone();
+    let was_connected = was_connected.clone();
+
+    move |_| {
+      let is_connected = connected();
+
+      if is_connected == was_connected.replace(is_connected) {
+        return;
+      }
+
+      let callback = if is_connected {
+        &on_connect
+      } else {
+        &*on_disconnect
+      };
+
+      if let Some(callback) = callback {
+        cx.untrack(callback);
+      }
+    }
+  });
+
+  on_cleanup(cx, move || {
+    if was_connected.get() {
+      if let Some(on_disconnect) = &*on_disconnect {
+        on_disconnect();
+      }
+    }
+  });
+}
+
+/// Values which can be used as the `id` of a portal.
+///
+/// Two ids refer to the same portal if they are of the same type and are
+/// equal. This is implemented for strings, integers, [`bool`] and [`char`],
+/// which is handy for dynamically generated portals.
+///
+/// To use your own types, derive [`PortalId`](macro@PortalId). Marker
+/// structs, such as `struct MyPortal;`, need nothing else, while other types
+/// must also derive [`Hash`], [`PartialEq`] and [`Eq`]. Enums can derive
+/// [`PortalIds`] instead, which also names each variant in the portal
+/// inspector.
+///
+/// # Migrating from marker types
+/// Previously, any type could be used as an `id`, with every value of a type
+/// referring to the same portal. Marker structs used as ids now need
+/// `#[derive(PortalId)]`, and keep working as before:
+///
+/// ```rust
+/// use leptos_declarative::prelude::*;
+///
+/// #[derive(PortalId)]
+/// struct MyPortal;
+/// ```
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// #[derive(Hash, PartialEq, Eq, PortalId)]
+/// enum Slot {
+///   Header,
+///   Footer,
+/// }
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id=Slot::Header />
+///     <PortalOutput id="sidebar" />
+///
+///     <PortalInput id=Slot::Header>"Header"</PortalInput>
+///     <PortalInput id="sidebar">"Sidebar"</PortalInput>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Deriving for enums
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// #[derive(Hash, PartialEq, Eq, PortalIds)]
+/// enum Slots {
+///   Toolbar,
+///   Sidebar,
+/// }
+///
+/// assert_eq!(Slots::Toolbar.to_string(), "Slots::Toolbar");
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id=Slots::Toolbar />
+///
+///     <PortalInput id=Slots::Toolbar>"Toolbar"</PortalInput>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+pub trait PortalId: Hash + Eq + 'static {
+  /// A human readable name of this id, shown by the portal inspector of the
+  /// `devtools` feature. Defaults to the name of the type.
+  fn debug_name(&self) -> String {
+    std::any::type_name::<Self>().into()
+  }
+}
+
+pub use leptos_declarative_macros::{
+  PortalId,
+  PortalIds,
+};
+
+macro_rules! impl_portal_id {
+  ($($ty:ty),* $(,)?) => {
+    $(impl PortalId for $ty {
+      fn debug_name(&self) -> String {
+        format!("{self:?}")
+      }
+    })*
+  };
+}
+
+impl_portal_id!(
+  &'static str,
+  String,
+  bool,
+  char,
+  u8,
+  u16,
+  u32,
+  u64,
+  u128,
+  usize,
+  i8,
+  i16,
+  i32,
+  i64,
+  i128,
+  isize,
+);
 
+/// A type-erased [`PortalId`], used to key the portal registry.
 #[derive(Clone)]
-struct PortalCtx(StoredValue<Vec<(TypeId, RwSignal<Option<Children>>)>>);
+struct PortalKey(Rc<dyn DynPortalId>);
+
+impl PortalKey {
+  fn new<T: PortalId>(id: T) -> Self {
+    Self(Rc::new(id))
+  }
+}
+
+impl PartialEq for PortalKey {
+  fn eq(&self, other: &Self) -> bool {
+    self.0.dyn_eq(&*other.0)
+  }
+}
+
+impl Eq for PortalKey {}
+
+impl Hash for PortalKey {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.dyn_hash(state);
+  }
+}
+
+trait DynPortalId {
+  fn as_any(&self) -> &dyn Any;
+
+  fn debug_name(&self) -> String;
+
+  fn dyn_eq(&self, other: &dyn DynPortalId) -> bool;
+
+  fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+impl<T: PortalId> DynPortalId for T {
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+
+  fn debug_name(&self) -> String {
+    PortalId::debug_name(self)
+  }
+
+  fn dyn_eq(&self, other: &dyn DynPortalId) -> bool {
+    other.as_any().downcast_ref::<T>() == Some(self)
+  }
+
+  fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+    TypeId::of::<T>().hash(&mut state);
+    self.hash(&mut state);
+  }
+}
+
+/// Renders the children of a [`PortalContent`].
+type PortalChildren = Rc<dyn Fn(Scope) -> Fragment>;
+
+/// A single contribution to a portal, such as from a [`PortalInput`].
+struct PortalContent {
+  key: usize,
+  /// Identifies who set this content, so it can be replaced or removed.
+  owner: usize,
+  order: i32,
+  data: Option<Rc<dyn Any>>,
+  /// Rendered by every [`PortalOutput`], instead of only the one which was
+  /// mounted first.
+  mirror: bool,
+  /// Called by each [`PortalOutput`] which shows the content, so it can be
+  /// rendered again by outputs mounted later on.
+  children: PortalChildren,
+}
+
+impl PortalContent {
+  fn new(
+    portal_ctx: &PortalCtx,
+    owner: usize,
+    order: i32,
+    children: PortalChildren,
+  ) -> Self {
+    Self {
+      key: portal_ctx.next_key(),
+      owner,
+      order,
+      data: None,
+      mirror: false,
+      children,
+    }
+  }
+}
 
 /// The portal provider which allows to use [`PortalInput`] and [`PortalOutput`].
 ///
 /// This must be located somewhere near the root of your component tree, above
 /// anywhere you would like to use portals.
 ///
+/// With the `global-portal` feature, the provider is optional. Portals used
+/// outside of any provider share a single registry for the whole app, which
+/// is handy for small apps and for libraries which can't require the host app
+/// to mount a provider. This registry is dropped along with the root scope of
+/// the app, such as when a server rendered request finishes.
+///
+/// Providers can be nested, in which case each provider has it's own set of
+/// portals. [`PortalInput`]s and [`PortalOutput`]s only match others using
+/// the same provider, which is the nearest one unless they set `global`, in
+/// which case it is the outermost one.
+///
 /// # Examples
 /// ```rust
 /// use leptos::*;
@@ -59,30 +743,60 @@ struct PortalCtx(StoredValue<Vec<(TypeId, RwSignal<Option<Children>>)>>);
 ///
 /// # let _ = create_scope(create_runtime(), |cx| {
 ///
-/// struct PortalId;
+/// #[derive(PortalId)]
+/// struct MyPortal;
 ///
 /// view! { cx,
 ///   <PortalProvider>
 ///     <div>
 ///       <h1>"Portal goes here!"</h1>
-///       <PortalOutput id=PortalId />
+///       <PortalOutput id=MyPortal />
 ///     </div>
 ///
-///     <PortalInput id=PortalId>
+///     <PortalInput id=MyPortal>
 ///       <p>"I went through the portal!"</p>
 ///     </PortalInput>
 ///   </PortalProvider>
 /// };
 /// # });
 /// ```
+///
+/// ### Nested providers
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="toolbar" />
+///
+///     <PortalProvider>
+///       <PortalOutput id="toolbar" />
+///
+///       <PortalInput id="toolbar">"Goes to the inner output"</PortalInput>
+///       <PortalInput id="toolbar" global=true>
+///         "Goes to the outer output"
+///       </PortalInput>
+///     </PortalProvider>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
 #[component]
 pub fn PortalProvider(
   cx: Scope,
+  /// What portals show when multiple [`PortalInput`]s share the same `id`,
+  /// unless overridden by a [`PortalOutput`]. Defaults to
+  /// [`PortalPolicy::All`].
+  #[prop(optional)]
+  policy: PortalPolicy,
   /// The rest of your app. [`PortalInput`] and [`PortalOutput`] can be used
   /// anywhere below this point.
   children: Children,
 ) -> impl IntoView {
-  provide_context(cx, PortalCtx(store_value(cx, Default::default())));
+  provide_context(cx, PortalCtx::new(cx, policy));
 
   children(cx)
 }
@@ -91,6 +805,15 @@ pub fn PortalProvider(
 /// in the corresponding [`PortalOutput`] with the matching `id`, wherever in your
 /// app that may be.
 ///
+/// Set `active` to only project the children while a condition holds, rather
+/// than wrapping the whole input in an [`If`](crate::if_::If).
+///
+/// Content is rendered with the context of the [`PortalOutput`], unless
+/// `forward_context` is set.
+///
+/// Nothing is rendered where this component is declared. To show something
+/// there while no matching [`PortalOutput`] is mounted, bind `is_connected`.
+///
 /// # Examples
 /// ```rust
 /// use leptos::*;
@@ -98,119 +821,1216 @@ pub fn PortalProvider(
 ///
 /// # let _ = create_scope(create_runtime(), |cx| {
 ///
-/// struct PortalId;
+/// #[derive(PortalId)]
+/// struct MyPortal;
 ///
 /// view! { cx,
 ///   <PortalProvider>
 ///     <div>
 ///       <h1>"Portal goes here!"</h1>
-///       <PortalOutput id=PortalId />
+///       <PortalOutput id=MyPortal />
 ///     </div>
 ///
-///     <PortalInput id=PortalId>
+///     <PortalInput id=MyPortal>
 ///       <p>"I went through the portal!"</p>
 ///     </PortalInput>
 ///   </PortalProvider>
 /// };
 /// # });
 /// ```
+///
+/// ### Forwarding context
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// #[derive(Clone)]
+/// struct Theme(&'static str);
+///
+/// #[component]
+/// fn ThemedToolbar(cx: Scope) -> impl IntoView {
+///   provide_context(cx, Theme("dark"));
+///
+///   view! { cx,
+///     <PortalInput id="toolbar" forward_context=true>
+///       {move || use_context::<Theme>(cx).map(|theme| theme.0)}
+///     </PortalInput>
+///   }
+/// }
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="toolbar" />
+///     <ThemedToolbar />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
 #[component]
 pub fn PortalInput<T>(
   cx: Scope,
-  /// The type used as an `id`. This must match the `id` of the
+  /// The `id` of the portal. This must be equal to the `id` of the
   /// corresponding [`PortalOutput`].
   id: T,
+  /// Controls where these children are rendered relative to other
+  /// [`PortalInput`]s with the same `id`. Lower values are rendered first, and
+  /// inputs with the same `order` are rendered in the order they were mounted.
+  ///
+  /// Defaults to `0`.
+  #[prop(optional)]
+  order: i32,
+  /// Structured data sent along with the children, which the matching
+  /// [`PortalOutput`] can read with [`PortalItem::data`].
+  #[prop(optional)]
+  data: Option<Rc<dyn Any>>,
+  /// Renders the children in every matching [`PortalOutput`], instead of only
+  /// the first one.
+  #[prop(optional)]
+  mirror: bool,
+  /// Uses the outermost [`PortalProvider`] instead of the nearest one.
+  #[prop(optional)]
+  global: bool,
+  /// Renders the children with the context available where this input is
+  /// declared, instead of the context available where the matching
+  /// [`PortalOutput`] is. This keeps contexts such as a theme or locale
+  /// working through the portal.
+  ///
+  /// The reactive state of the children is then also owned by this input,
+  /// rather than by the output.
+  #[prop(optional)]
+  forward_context: bool,
+  /// The children are only projected while this is `true`, and are removed
+  /// from the matching [`PortalOutput`] otherwise.
+  ///
+  /// Defaults to `true`.
+  #[prop(into, default = true.into())]
+  active: MaybeSignal<bool>,
+  /// Removes the children from the matching [`PortalOutput`] once this much
+  /// time has passed since they were projected, which is handy for toast
+  /// notifications. Content is projected again when `active` becomes `true`
+  /// again.
+  #[prop(optional)]
+  ttl: Option<Duration>,
+  /// Only hands the children to the portal while a matching [`PortalOutput`]
+  /// is mounted. Until then, this input has no content in the portal, so it
+  /// isn't selected by a [`PortalPolicy`], and it's `ttl` only starts once an
+  /// output shows it.
+  #[prop(optional)]
+  lazy: bool,
+  /// Kept in sync with whether a matching [`PortalOutput`] is mounted, which
+  /// is handy for rendering a local fallback when nothing is showing the
+  /// content.
+  #[prop(optional)]
+  is_connected: Option<RwSignal<bool>>,
+  /// Called when a matching [`PortalOutput`] is mounted while this input is
+  /// mounted.
+  #[prop(optional)]
+  on_connect: Option<Box<dyn Fn()>>,
+  /// Called when the last matching [`PortalOutput`] is unmounted, or when this
+  /// input is unmounted while connected.
+  #[prop(optional)]
+  on_disconnect: Option<Box<dyn Fn()>>,
+  /// Panics when there is no [`PortalProvider`] above, instead of logging a
+  /// warning and projecting nothing.
+  #[prop(optional)]
+  strict: bool,
   /// The children you want to render anywhere the matching [`PortalOutput`]
   /// is located.
-  children: Children,
+  ///
+  /// These are only called once a matching [`PortalOutput`] shows them, so
+  /// heavy views cost nothing while no output is mounted.
+  children: Box<dyn Fn(Scope) -> Fragment>,
 ) -> impl IntoView
 where
-  T: Any,
+  T: PortalId,
 {
-  let portal_ctx =
-    use_context::<PortalCtx>(cx).expect(CONTEXT_NOT_FOUND_ERROR_MESSAGE);
+  let Some(portal_ctx) = PortalCtx::try_from_context(cx, global, strict) else {
+    return;
+  };
 
-  portal_ctx.0.update_value(|portals| {
-    if let Some(pos) = portals
-      .iter()
-      .position(|(type_id, _)| *type_id == id.type_id())
-    {
-      portals[pos].1.set(Some(children));
-    } else {
-      let children = create_rw_signal(cx, Some(children));
+  let portal = portal_ctx.portal(PortalKey::new(id));
+  let owner = portal_ctx.next_key();
 
-      portals.push((id.type_id(), children));
-    }
-  });
-}
+  let children: PortalChildren = if forward_context {
+    Rc::new(move |output_cx| {
+      let (children, disposer) = cx.run_child_scope(|cx| children(cx));
 
-/// The portal output point. Whatever children the corresponding [`Portal`} with
-/// matching `id` has, will be rendered here.
-///
-/// # Examples
-/// ```rust
-/// use leptos::*;
+      // The output disposes `output_cx` once it stops showing the content
+      on_cleanup(output_cx, move || disposer.dispose());
+
+      children
+    })
+  } else {
+    children.into()
+  };
+
+  let expiry = Rc::new(Cell::new(None::<Timeout>));
+
+  let connected = create_memo(cx, move |_| !lazy || portal.is_connected());
+
+  // Every activation sets new content, so outputs render it from scratch
+  create_isomorphic_effect(cx, {
+    let expiry = expiry.clone();
+
+    move |_| {
+      if let Some(timeout) = expiry.take() {
+        timeout.clear();
+      }
+
+      if !active.get() || !connected.get() {
+        portal.remove(owner);
+
+        return;
+      }
+
+      let mut content =
+        PortalContent::new(&portal_ctx, owner, order, children.clone());
+      content.data = data.clone();
+      content.mirror = mirror;
+
+      portal.set(content);
+
+      // Content is never expired on the server, as it would be gone before
+      // being rendered
+      if let Some(ttl) = ttl {
+        if try_window().is_some() {
+          expiry.set(set_timeout(cx, move || portal.remove(owner), ttl));
+        }
+      }
+    }
+  });
+
+  watch_connection(
+    cx,
+    move || portal.is_connected(),
+    on_connect,
+    on_disconnect,
+  );
+
+  if let Some(is_connected) = is_connected {
+    create_effect(cx, move |_| is_connected.set(portal.is_connected()));
+
+    on_cleanup(cx, move || is_connected.set(false));
+  }
+
+  on_cleanup(cx, move || {
+    if let Some(timeout) = expiry.take() {
+      timeout.clear();
+    }
+
+    portal.remove(owner);
+  });
+}
+
+/// The portal output point. Whatever children the corresponding [`PortalInput`]
+/// with matching `id` has, will be rendered here.
+///
+/// It does not matter whether the [`PortalInput`] is rendered before or after
+/// this component. The content is updated whenever a matching [`PortalInput`]
+/// is mounted, and removed when it is unmounted.
+///
+/// If there are multiple [`PortalInput`]s with the same `id`, all of them
+/// are rendered, sorted by their `order`, unless a different [`PortalPolicy`]
+/// is set. The children of this component are rendered as a fallback while
+/// there are none.
+///
+/// If there are multiple outputs with the same `id`, the content of each
+/// [`PortalInput`] is only rendered in the one which was mounted first, unless
+/// the input sets `mirror`. Once that output is unmounted, the content moves
+/// to the next one.
+///
+/// Set `enter_class` and `leave_class` to animate content in and out. Each
+/// piece of content is then wrapped in a `div` with one of these classes, and
+/// removed content is kept around until it's leave animation finishes.
+/// Transitions are skipped while the user prefers reduced motion.
+///
+/// # Hydration
+/// When hydrating, the content rendered by the server is claimed as is.
+/// Content from [`PortalInput`]s which are rendered after this component, and
+/// were therefore not part of the server rendered HTML, is only projected
+/// once [`HydrationCtx`] reports that hydration is done, so it is never
+/// duplicated or lost.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
 /// use leptos_declarative::prelude::*;
 ///
 /// # let _ = create_scope(create_runtime(), |cx| {
 ///
-/// struct PortalId;
+/// #[derive(PortalId)]
+/// struct MyPortal;
 ///
 /// view! { cx,
 ///   <PortalProvider>
 ///     <div>
 ///       <h1>"Portal goes here!"</h1>
-///       <PortalOutput id=PortalId />
+///       <PortalOutput id=MyPortal />
 ///     </div>
 ///
-///     <PortalInput id=PortalId>
+///     <PortalInput id=MyPortal>
 ///       <p>"I went through the portal!"</p>
 ///     </PortalInput>
 ///   </PortalProvider>
 /// };
 /// # });
 /// ```
+///
+/// ### Transitions
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="modal" enter_class="fade-in" leave_class="fade-out" />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Fallback
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <nav>
+///       <PortalOutput id="toolbar">"No toolbar actions"</PortalOutput>
+///     </nav>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Wrapper element
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput
+///       id="modal"
+///       tag="section"
+///       class="modal-root"
+///       attrs=vec![("aria-live", "polite".to_string())]
+///     />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Data
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::rc::Rc;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// struct Action {
+///   primary: bool,
+/// }
+///
+/// let view = Box::new(|cx, item: PortalItem| {
+///   let primary = item.data::<Action>().map_or(false, |a| a.primary);
+///
+///   view! { cx, <li class:primary=primary>{item.content}</li> }.into_view(cx)
+/// });
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <ul>
+///       <PortalOutput id="toolbar" view />
+///     </ul>
+///
+///     <PortalInput id="toolbar" data=Rc::new(Action { primary: true })>
+///       <button>"Save"</button>
+///     </PortalInput>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Mirroring
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <header><PortalOutput id="status" /></header>
+///     <footer><PortalOutput id="status" /></footer>
+///
+///     <PortalInput id="status" mirror=true>
+///       <span>"Online"</span>
+///     </PortalInput>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Stacking
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="modal" policy=PortalPolicy::Stack />
+///
+///     <PortalInput id="modal">"Shown again once the confirmation closes"</PortalInput>
+///     <PortalInput id="modal">"Are you sure?"</PortalInput>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// ### Multiple inputs
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// #[derive(PortalId)]
+/// struct Toolbar;
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <nav>
+///       <PortalOutput id=Toolbar />
+///     </nav>
+///
+///     <PortalInput id=Toolbar order=1>
+///       <button>"Save"</button>
+///     </PortalInput>
+///     <PortalInput id=Toolbar>
+///       <button>"New"</button>
+///     </PortalInput>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
 #[component]
 pub fn PortalOutput<T>(
   cx: Scope,
-  /// The type used as an `id`. This must match the `id` of the
+  /// The `id` of the portal. This must be equal to the `id` of the
   /// corresponding [`PortalInput`].
   id: T,
+  /// Uses the outermost [`PortalProvider`] instead of the nearest one.
+  #[prop(optional)]
+  global: bool,
+  /// What this portal shows when multiple [`PortalInput`]s share the same
+  /// `id`. Defaults to the `policy` of the [`PortalProvider`].
+  ///
+  /// This only affects this output, so other outputs of the same `id` can
+  /// use a different policy. Changing it starts over, as if the current
+  /// contents were added just now.
+  #[prop(optional, into)]
+  policy: Option<MaybeSignal<PortalPolicy>>,
+  /// Wraps the projected content in an element with this tag. Defaults to a
+  /// `div` if `class` or `attrs` are set, otherwise the content is rendered
+  /// without a wrapper.
+  #[prop(optional, into)]
+  tag: Option<String>,
+  /// The `class` of the wrapper element.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// Extra attributes of the wrapper element.
+  #[prop(optional)]
+  attrs: Vec<(&'static str, String)>,
+  /// Renders each projected [`PortalItem`], allowing it to be decorated based
+  /// on the `data` of it's [`PortalInput`]. Defaults to rendering the content
+  /// as is.
+  #[prop(optional)]
+  view: Option<Box<dyn Fn(Scope, PortalItem) -> View>>,
+  /// Added to the element wrapping each piece of projected content when it
+  /// is first shown, so it can animate in.
+  #[prop(optional, into)]
+  enter_class: Option<String>,
+  /// Replaces `enter_class` when the [`PortalInput`] of the content is
+  /// unmounted. The content is only removed once the CSS animation or
+  /// transition started by this class ends, so make sure it starts one.
+  #[prop(optional, into)]
+  leave_class: Option<String>,
+  /// Called when content becomes available for this output, such as when the
+  /// first matching [`PortalInput`] is mounted. This is handy for moving focus
+  /// into the projected content.
+  #[prop(optional)]
+  on_connect: Option<Box<dyn Fn()>>,
+  /// Called when no content is available for this output anymore, or when
+  /// this output is unmounted while connected.
+  #[prop(optional)]
+  on_disconnect: Option<Box<dyn Fn()>>,
+  /// Panics when there is no [`PortalProvider`] above, instead of logging a
+  /// warning and rendering the fallback children.
+  #[prop(optional)]
+  strict: bool,
+  /// Rendered whenever no [`PortalInput`] with a matching `id` is mounted, or
+  /// when there is no [`PortalProvider`] above.
+  #[prop(optional)]
+  children: Option<Box<dyn Fn(Scope) -> Fragment>>,
 ) -> impl IntoView
 where
-  T: Any,
+  T: PortalId,
 {
-  let portal_ctx =
-    use_context::<PortalCtx>(cx).expect(CONTEXT_NOT_FOUND_ERROR_MESSAGE);
+  let Some(portal_ctx) = PortalCtx::try_from_context(cx, global, strict) else {
+    return children.map(|children| children(cx)).into_view(cx);
+  };
+
+  let portal = portal_ctx.portal(PortalKey::new(id));
+  let fallback = children;
+
+  let output = portal_ctx.next_key();
+  let mounted = Rc::new(Cell::new(true));
 
-  let mut children = None;
+  portal.outputs.update(|outputs| outputs.push(output));
 
-  portal_ctx.0.update_value(|portals| {
-    let children_signal = if let Some(pos) = portals
+  #[cfg(feature = "tracing")]
+  portal
+    .name
+    .with_value(|id| tracing::debug!(%id, "mounted portal output"));
+
+  on_cleanup(cx, {
+    let mounted = mounted.clone();
+
+    move || {
+      #[cfg(feature = "tracing")]
+      portal
+        .name
+        .with_value(|id| tracing::debug!(%id, "unmounted portal output"));
+
+      // Otherwise we'd render again below, as the next output takes over
+      mounted.set(false);
+
+      portal
+        .outputs
+        .update(|outputs| outputs.retain(|key| *key != output));
+      portal.selections.update_value(|selections| {
+        selections.remove(&output);
+      });
+    }
+  });
+
+  watch_connection(cx, move || portal.has_content(), on_connect, on_disconnect);
+
+  let rendered = Cell::new(Vec::<RenderedContent>::new());
+
+  // Keys of content which was removed and finished it's leave transition
+  let left = create_rw_signal(cx, Vec::<usize>::new());
+  let transitions = enter_class.is_some() || leave_class.is_some();
+  let reduced_motion = use_reduced_motion(cx);
+
+  // While hydrating, only the content which existed when the server rendered
+  // this output can be claimed, so content arriving later must wait until
+  // hydration is done
+  let hydrating = HydrationCtx::is_hydrating();
+  let hydrated = create_rw_signal(cx, !hydrating);
+
+  if hydrating {
+    when_hydrated(move || hydrated.set(true), MAX_HYDRATION_FRAMES);
+  }
+
+  let content = move || {
+    if !mounted.get() {
+      return ().into_view(cx);
+    }
+
+    if hydrated.get() {
+      portal.contents.track();
+    }
+
+    // Content which isn't mirrored is only shown by the first mounted output
+    let is_primary = portal
+      .outputs
+      .with(|outputs| outputs.first() == Some(&output));
+
+    let left_keys = if transitions { left.get() } else { Vec::new() };
+
+    let mut available = rendered.take();
+    let prev_keys = available
       .iter()
-      .position(|(type_id, _)| *type_id == id.type_id())
-    {
-      portals[pos].1
-    } else {
-      let children = create_rw_signal(cx, None);
+      .map(|content| content.key)
+      .collect::<Vec<_>>();
 
-      portals.push((id.type_id(), children));
+    let mut new_children = Vec::new();
+    let mut keys = Vec::new();
 
-      children
-    };
+    let policy = policy
+      .as_ref()
+      .map_or_else(|| portal.policy.get_value(), |policy| policy.get());
+
+    portal.contents.with_untracked(|contents| {
+      let mut selected = Selected::All;
+
+      portal.selections.update_value(|selections| {
+        let selection = selections
+          .entry(output)
+          .or_insert_with(|| PortalSelection::new(policy));
+
+        if selection.policy != policy {
+          *selection = PortalSelection::new(policy);
+        }
+
+        selected = selection.select(contents);
+      });
+
+      for content in contents {
+        let is_visible =
+          (content.mirror || is_primary) && selected.contains(content.key);
+        let is_rendered =
+          available.iter().any(|rendered| rendered.key == content.key);
+
+        if is_visible && !is_rendered {
+          new_children.push((
+            content.key,
+            content.data.clone(),
+            content.children.clone(),
+          ));
+        }
+
+        keys.push((content.key, is_visible));
+      }
+    });
+
+    // Each content gets it's own scope, so it's effects and cleanups are
+    // disposed as soon as the content is removed, rather than with the output
+    available.extend(new_children.into_iter().map(|(key, data, children)| {
+      let ((view, leaving), disposer) = cx.run_child_scope(|cx| {
+        let content = children(cx).into_view(cx);
+
+        let view = if let Some(view) = &view {
+          view(cx, PortalItem { data, content })
+        } else {
+          content
+        };
+
+        if !transitions {
+          return (view, None);
+        }
+
+        let leaving = create_rw_signal(cx, false);
+        let enter_class = enter_class.clone();
+        let leave_class = leave_class.clone();
+
+        let finish = move |ev: web_sys::Event| {
+          if leaving.get_untracked() && ev.target() == ev.current_target() {
+            left.update(|left| left.push(key));
+          }
+        };
+
+        let view = html::div(cx)
+          .attr("class", move || {
+            if reduced_motion.get() {
+              None
+            } else if leaving.get() {
+              leave_class.clone()
+            } else {
+              enter_class.clone()
+            }
+          })
+          .on(ev::animationend, move |ev| finish(ev.into()))
+          .on(ev::transitionend, move |ev| finish(ev.into()))
+          .child(view)
+          .into_view(cx);
+
+        (view, Some(leaving))
+      });
+
+      RenderedContent {
+        key,
+        view,
+        visible: true,
+        leaving,
+        disposer,
+      }
+    }));
+
+    // Views of inputs which are no longer mounted are dropped here, while
+    // hidden ones are kept around in case they are shown again
+    let mut views = keys
+      .into_iter()
+      .filter_map(|(key, is_visible)| {
+        let pos = available.iter().position(|rendered| rendered.key == key)?;
+
+        let mut content = available.swap_remove(pos);
+        content.visible = is_visible;
+
+        Some(content)
+      })
+      .collect::<Vec<_>>();
+
+    // Removed content which was visible stays where it was until it's leave
+    // transition finishes
+    for content in available {
+      let leaving = content.leaving.filter(|_| {
+        leave_class.is_some()
+          && !reduced_motion.get_untracked()
+          && content.visible
+          && !left_keys.contains(&content.key)
+      });
+
+      let Some(leaving) = leaving else {
+        left.update_untracked(|left| left.retain(|key| *key != content.key));
+
+        content.dispose();
+
+        continue;
+      };
 
-    children = Some(children_signal);
+      leaving.set(true);
+
+      let next_key = prev_keys
+        .iter()
+        .skip_while(|key| **key != content.key)
+        .find(|key| views.iter().any(|rendered| rendered.key == **key));
+
+      let pos = next_key
+        .and_then(|next_key| {
+          views.iter().position(|rendered| rendered.key == *next_key)
+        })
+        .unwrap_or(views.len());
+
+      views.insert(pos, content);
+    }
+
+    let visible_views = views
+      .iter()
+      .filter(|content| content.visible)
+      .map(|content| content.view.clone())
+      .collect::<Vec<_>>();
+
+    let is_empty = views.is_empty();
+
+    rendered.set(views);
+
+    if is_empty {
+      return fallback
+        .as_ref()
+        .map(|fallback| fallback(cx).into_view(cx))
+        .unwrap_or_else(|| ().into_view(cx));
+    }
+
+    Fragment::new(visible_views).into_view(cx)
+  };
+
+  if tag.is_none() && class.is_none() && attrs.is_empty() {
+    return content.into_view(cx);
+  }
+
+  let mut wrapper =
+    html::custom(cx, html::Custom::new(tag.unwrap_or_else(|| "div".into())));
+
+  if let Some(class) = class {
+    wrapper = wrapper.attr("class", class);
+  }
+
+  for (name, value) in attrs {
+    wrapper = wrapper.attr(name, value);
+  }
+
+  wrapper.child(content).into_view(cx)
+}
+
+/// How many frames [`PortalOutput`]s wait for hydration to finish before
+/// projecting late content anyway.
+const MAX_HYDRATION_FRAMES: usize = 60;
+
+/// Calls `cb` once [`HydrationCtx`] reports that hydration is done, checking
+/// once per frame, or after `frames` frames at the latest, so an app which
+/// never reports being done can't keep portal content hidden forever.
+fn when_hydrated(cb: impl FnOnce() + 'static, frames: usize) {
+  request_animation_frame(move || {
+    if HydrationCtx::is_hydrating() && frames > 0 {
+      when_hydrated(cb, frames - 1);
+    } else {
+      cb();
+    }
   });
+}
+
+/// Updates the content of several portals at once, so that all of them change
+/// in the same frame.
+///
+/// This is useful for related portals, such as a header title and toolbar,
+/// which should never be shown out of sync.
+///
+/// Content set with [`PortalBatch::set`] is rendered alongside the content of
+/// any [`PortalInput`]s with the same `id`, and replaces content previously
+/// set with [`PortalBatch::set`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn SettingsPage(cx: Scope) -> impl IntoView {
+///   portal_batch(cx, |tx| {
+///     tx.set("title", |cx| view! { cx, <h1>"Settings"</h1> });
+///     tx.clear("toolbar");
+///   });
+///
+///   view! { cx, <p>"Settings go here"</p> }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <header>
+///       <PortalOutput id="title" />
+///       <PortalOutput id="toolbar" />
+///     </header>
+///     <SettingsPage />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// If there is no [`PortalProvider`] above `cx`,
+/// [`Error::MissingProvider`](crate::error::Error::MissingProvider) is
+/// reported and nothing
+/// changes.
+pub fn portal_batch(cx: Scope, f: impl FnOnce(&mut PortalBatch)) {
+  let Some(portal_ctx) = PortalCtx::report_from_context(cx, false) else {
+    return;
+  };
+
+  let mut batch = PortalBatch(Vec::new());
+
+  f(&mut batch);
 
-  let children = children.unwrap();
+  let (ids, changes): (Vec<_>, Vec<_>) = batch.0.into_iter().unzip();
+  let portals = portal_ctx.portals(ids);
 
-  move || {
-    children.with(|children| {
+  cx.batch(move || {
+    for (portal, children) in portals.into_iter().zip(changes) {
       if let Some(children) = children {
-        children(cx).into_view(cx)
+        portal.set(PortalContent::new(
+          &portal_ctx,
+          portal_ctx.batch_owner,
+          0,
+          children,
+        ));
       } else {
-        ().into_view(cx)
+        portal.clear();
       }
+    }
+  });
+}
+
+/// The changes applied by [`portal_batch`].
+pub struct PortalBatch(Vec<(PortalKey, Option<PortalChildren>)>);
+
+impl PortalBatch {
+  /// Sets the content of the portal with the given `id`.
+  pub fn set<T, F, IV>(&mut self, id: T, children: F)
+  where
+    T: PortalId,
+    F: Fn(Scope) -> IV + 'static,
+    IV: IntoView,
+  {
+    self
+      .0
+      .push((PortalKey::new(id), Some(into_children(children))));
+  }
+
+  /// Removes all content of the portal with the given `id`, including the
+  /// content of any [`PortalInput`]s.
+  pub fn clear<T: PortalId>(&mut self, id: T) {
+    self.0.push((PortalKey::new(id), None));
+  }
+}
+
+/// Removes all content of the portal with the given `id`, no matter which
+/// [`PortalInput`] it came from. This is handy for teardown flows, such as
+/// wiping every modal and toast when logging out.
+///
+/// Removed content is not shown again until it's [`PortalInput`] is mounted
+/// or activated again.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn LogoutButton(cx: Scope) -> impl IntoView {
+///   let on_click = move |_| {
+///     clear_portal(cx, "modal", false);
+///     clear_portal(cx, "toasts", false);
+///   };
+///
+///   view! { cx, <button on:click=on_click>"Log out"</button> }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="modal" />
+///     <PortalOutput id="toasts" />
+///     <LogoutButton />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// If there is no [`PortalProvider`] above `cx`,
+/// [`Error::MissingProvider`](crate::error::Error::MissingProvider) is
+/// reported and nothing
+/// is cleared.
+pub fn clear_portal<T: PortalId>(cx: Scope, id: T, global: bool) {
+  if let Some(portal_ctx) = PortalCtx::report_from_context(cx, global) {
+    portal_ctx.portal(PortalKey::new(id)).clear();
+  }
+}
+
+/// Removes all content of the portal with the given `id` when mounted. This
+/// is the declarative counterpart of [`clear_portal`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (logged_in, _) = create_signal(cx, true);
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="modal" />
+///
+///     <If signal=logged_in>
+///       <Then>"Welcome back!"</Then>
+///       <Else>
+///         <PortalClear id="modal" />
+///       </Else>
+///     </If>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn PortalClear<T>(
+  cx: Scope,
+  /// The `id` of the portal to clear.
+  id: T,
+  /// Uses the outermost [`PortalProvider`] instead of the nearest one.
+  #[prop(optional)]
+  global: bool,
+) -> impl IntoView
+where
+  T: PortalId,
+{
+  clear_portal(cx, id, global);
+}
+
+/// Content which was rendered by a [`PortalOutput`].
+struct RenderedContent {
+  key: usize,
+  view: View,
+  visible: bool,
+  /// Only set when the [`PortalOutput`] has transitions, and becomes `true`
+  /// once the content was removed and is leaving.
+  leaving: Option<RwSignal<bool>>,
+  /// Disposes the scope the content was rendered in.
+  disposer: ScopeDisposer,
+}
+
+impl RenderedContent {
+  fn dispose(self) {
+    self.disposer.dispose();
+  }
+}
+
+/// A single piece of content projected into a [`PortalOutput`], passed to it's
+/// `view` prop.
+pub struct PortalItem {
+  data: Option<Rc<dyn Any>>,
+  /// The rendered children of the [`PortalInput`].
+  pub content: View,
+}
+
+impl PortalItem {
+  /// The `data` of the [`PortalInput`], if it was set and is of type `T`.
+  pub fn data<T: 'static>(&self) -> Option<&T> {
+    self.data.as_deref()?.downcast_ref()
+  }
+}
+
+/// Gets a handle to the portal with the given `id`, which allows controlling
+/// it's content from outside of the view, such as from effects or event
+/// handlers.
+///
+/// Content set with the handle is rendered alongside the content of any
+/// [`PortalInput`]s with the same `id`, and is removed when `cx` is disposed.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn SaveButton(cx: Scope) -> impl IntoView {
+///   let Some(status) = use_portal(cx, "status") else {
+///     return ().into_view(cx);
+///   };
+///
+///   let on_click = move |_| {
+///     status.set(|cx| view! { cx, <p>"Saving..."</p> });
+///   };
+///
+///   view! { cx,
+///     <button on:click=on_click>"Save"</button>
+///     <If signal=status.is_connected()>
+///       <Then>"The status is shown above"</Then>
+///       <Else>"Nobody is showing the status!"</Else>
+///     </If>
+///   }
+///   .into_view(cx)
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalOutput id="status" />
+///     <SaveButton />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+///
+/// If there is no [`PortalProvider`] above `cx`,
+/// [`Error::MissingProvider`](crate::error::Error::MissingProvider) is
+/// reported and `None`
+/// is returned.
+pub fn use_portal<T: PortalId>(cx: Scope, id: T) -> Option<PortalHandle> {
+  let portal_ctx = PortalCtx::report_from_context(cx, false)?;
+
+  let portal = portal_ctx.portal(PortalKey::new(id));
+  let owner = portal_ctx.next_key();
+
+  on_cleanup(cx, move || portal.remove(owner));
+
+  Some(PortalHandle {
+    cx,
+    portal_ctx,
+    portal,
+    owner,
+  })
+}
+
+/// Returns the number of [`PortalInput`]s and mounted [`PortalOutput`]s of
+/// the portal `id` of the nearest [`PortalProvider`], without registering
+/// it.
+#[cfg(feature = "testing")]
+pub(crate) fn connections<T: PortalId>(cx: Scope, id: T) -> (usize, usize) {
+  PortalCtx::from_context(cx, false)
+    .portals
+    .with_value(|portals| portals.get(&PortalKey::new(id)).copied())
+    .map_or((0, 0), |portal| {
+      (
+        portal.contents.with_untracked(Vec::len),
+        portal.outputs.with_untracked(Vec::len),
+      )
     })
+}
+
+/// Returns the children the first mounted [`PortalOutput`] of the portal `id`
+/// of the nearest [`PortalProvider`] shows right now, without registering it.
+#[cfg(feature = "testing")]
+pub(crate) fn projected<T: PortalId>(cx: Scope, id: T) -> Vec<PortalChildren> {
+  let Some(portal) = PortalCtx::from_context(cx, false)
+    .portals
+    .with_value(|portals| portals.get(&PortalKey::new(id)).copied())
+  else {
+    return Vec::new();
+  };
+
+  // Selecting on a copy, so this doesn't discard anything for the output
+  let mut selection = portal
+    .outputs
+    .with_untracked(|outputs| outputs.first().copied())
+    .and_then(|output| {
+      portal
+        .selections
+        .with_value(|selections| selections.get(&output).copied())
+    })
+    .unwrap_or_else(|| PortalSelection::new(portal.policy.get_value()));
+
+  portal.contents.with_untracked(|contents| {
+    let selected = selection.select(contents);
+
+    contents
+      .iter()
+      .filter(|content| selected.contains(content.key))
+      .map(|content| content.children.clone())
+      .collect()
+  })
+}
+
+/// Controls the content of a portal, returned by [`use_portal`].
+#[derive(Clone, Copy)]
+pub struct PortalHandle {
+  cx: Scope,
+  portal_ctx: PortalCtx,
+  portal: Portal,
+  owner: usize,
+}
+
+impl PortalHandle {
+  /// Sets the content of the portal, replacing whatever was previously set
+  /// with this handle.
+  pub fn set<F, IV>(&self, children: F)
+  where
+    F: Fn(Scope) -> IV + 'static,
+    IV: IntoView,
+  {
+    self.portal.set(PortalContent::new(
+      &self.portal_ctx,
+      self.owner,
+      0,
+      into_children(children),
+    ));
+  }
+
+  /// Removes the content set with this handle.
+  pub fn clear(&self) {
+    self.portal.remove(self.owner);
+  }
+
+  /// Whether a matching [`PortalOutput`] is currently mounted.
+  pub fn is_connected(&self) -> Signal<bool> {
+    let portal = self.portal;
+
+    Signal::derive(self.cx, move || portal.is_connected())
+  }
+}
+
+fn into_children<F, IV>(children: F) -> PortalChildren
+where
+  F: Fn(Scope) -> IV + 'static,
+  IV: IntoView,
+{
+  Rc::new(move |cx| Fragment::new(vec![children(cx).into_view(cx)]))
+}
+
+/// The state of a single portal `id`, as returned by [`portal_snapshot`].
+///
+/// This is only available with the `devtools` feature.
+#[cfg(feature = "devtools")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortalSnapshot {
+  /// The [`PortalId::debug_name`] of the `id`.
+  pub id: String,
+  /// The number of [`PortalInput`]s, including content set with
+  /// [`use_portal`] and [`portal_batch`].
+  pub inputs: usize,
+  /// The number of mounted [`PortalOutput`]s.
+  pub outputs: usize,
+  /// When the content last changed, in milliseconds since the epoch. This is
+  /// only recorded in the browser.
+  pub last_changed: Option<f64>,
+}
+
+/// Lists the state of every portal registered with the nearest
+/// [`PortalProvider`], or the outermost one if `global` is set. This is
+/// reactive.
+///
+/// This is handy for finding out why a portal silently shows nothing, and is
+/// what [`PortalDebugger`] renders.
+///
+/// This is only available with the `devtools` feature.
+#[cfg(feature = "devtools")]
+pub fn portal_snapshot(cx: Scope, global: bool) -> Vec<PortalSnapshot> {
+  PortalCtx::from_context(cx, global).snapshot()
+}
+
+/// Renders a table listing every portal registered with the nearest
+/// [`PortalProvider`], whether it has any [`PortalInput`]s and
+/// [`PortalOutput`]s, and when it's content last changed.
+///
+/// This is only available with the `devtools` feature.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// view! { cx,
+///   <PortalProvider>
+///     <PortalInput id="toolbar">"Nobody sees me"</PortalInput>
+///
+///     <PortalDebugger />
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+#[cfg(feature = "devtools")]
+#[component]
+pub fn PortalDebugger(
+  cx: Scope,
+  /// Lists the portals of the outermost [`PortalProvider`] instead of the
+  /// nearest one.
+  #[prop(optional)]
+  global: bool,
+) -> impl IntoView {
+  let rows = move || {
+    portal_snapshot(cx, global)
+      .into_iter()
+      .map(|snapshot| {
+        let last_changed = snapshot
+          .last_changed
+          .map(|time| {
+            String::from(
+              js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(time))
+                .to_iso_string(),
+            )
+          })
+          .unwrap_or_else(|| "-".into());
+
+        view! { cx,
+          <tr>
+            <td>{snapshot.id}</td>
+            <td>{snapshot.inputs}</td>
+            <td>{snapshot.outputs}</td>
+            <td>{last_changed}</td>
+          </tr>
+        }
+      })
+      .collect::<Vec<_>>()
+  };
+
+  view! { cx,
+    <table class="portal-debugger">
+      <thead>
+        <tr>
+          <th>"Id"</th>
+          <th>"Inputs"</th>
+          <th>"Outputs"</th>
+          <th>"Last changed"</th>
+        </tr>
+      </thead>
+      <tbody>{rows}</tbody>
+    </table>
   }
 }
@@ -0,0 +1,306 @@
+//! Keeping state in URL query parameters for [`leptos`].
+//!
+//! Please refer to [`QueryParam`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use leptos_router::{
+  use_location,
+  use_navigate,
+  use_query_map,
+  NavigateOptions,
+};
+use std::rc::Rc;
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<QueryParam />",
+  context: "QueryParamHandle",
+};
+
+api_planning! {
+  view! { cx,
+    <QueryParam name="tab" default="overview">
+      <TabButtons />
+
+      <Case value="overview"><Overview /></Case>
+      <Case value="settings"><Settings /></Case>
+      <Otherwise><NotFound /></Otherwise>
+    </QueryParam>
+  }
+}
+
+/// Renders the [`Case`] arm matching the value of the `name` URL query
+/// parameter, or the [`Otherwise`] arm if none do.
+///
+/// This keeps state such as the selected tab or filter in the URL, so it
+/// survives reloads and can be shared. Any child can read and change the
+/// parameter with [`use_query_param`].
+///
+/// This component must be used within a leptos_router `<Router>`, and is
+/// only available with the `router` feature.
+///
+/// For more docs on allowed child components, check out
+/// [`QueryParamProps::children`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use leptos_router::*;
+///
+/// #[component]
+/// fn TabButtons(cx: Scope) -> impl IntoView {
+///   let tab = use_query_param(cx);
+///
+///   view! { cx,
+///     <button on:click=move |_| tab.set("overview")>"Overview"</button>
+///     <button on:click=move |_| tab.set("settings")>"Settings"</button>
+///   }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// view! { cx,
+/// <Router>
+///   <QueryParam name="tab" default="overview">
+///     <TabButtons />
+///
+///     <Case value="overview">"Overview"</Case>
+///     <Case value="settings">"Settings"</Case>
+///     <Otherwise>"No such tab"</Otherwise>
+///   </QueryParam>
+/// </Router>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn QueryParam(
+  cx: Scope,
+  /// The name of the query parameter.
+  #[prop(into)]
+  name: String,
+  /// The value used while the parameter is missing from the URL.
+  #[prop(optional, into)]
+  default: Option<String>,
+  /// Whether changing the parameter replaces the current history entry,
+  /// instead of adding a new one.
+  #[prop(optional)]
+  replace: bool,
+  /// The content, along with the arms.
+  ///
+  /// Arms can be any
+  /// - [`Case`]
+  /// - [`Otherwise`]
+  ///
+  /// Any other child is always rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let param = create_query_param(cx, name, default, replace);
+
+  provide_context(cx, param);
+
+  let children = children(cx);
+
+  let cases = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .filter_map(Transparent::downcast_ref::<CaseBlock>)
+    .map(|block| block.value.clone())
+    .collect::<Rc<[_]>>();
+
+  let is_otherwise = create_memo(cx, move |_| {
+    param
+      .value
+      .with(|value| !cases.iter().any(|case| Some(case) == value.as_ref()))
+  });
+
+  children
+    .as_children()
+    .iter()
+    .cloned()
+    .map(|child| {
+      let Some(block) = child.as_transparent() else {
+        return child;
+      };
+
+      let (is_active, children): (Rc<dyn Fn() -> bool>, _) =
+        if let Some(block) = block.downcast_ref::<CaseBlock>() {
+          let value = block.value.clone();
+
+          (
+            Rc::new(move || {
+              param.value.with(|param| param.as_ref() == Some(&value))
+            }),
+            block.children.clone(),
+          )
+        } else if let Some(block) = block.downcast_ref::<OtherwiseBlock>() {
+          (Rc::new(move || is_otherwise.get()), block.children.clone())
+        } else {
+          return child;
+        };
+
+      (move || {
+        if is_active() {
+          children(cx).into_view(cx)
+        } else {
+          ().into_view(cx)
+        }
+      })
+      .into_view(cx)
+    })
+    .collect::<Vec<_>>()
+}
+
+/// Shown while the parameter of the parent [`QueryParam`] equals `value`.
+#[component(transparent)]
+pub fn Case(
+  cx: Scope,
+  /// The value of the parameter for which this arm is shown.
+  #[prop(into)]
+  value: String,
+  /// What you want to show while the parameter equals `value`.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  CaseBlock {
+    value,
+    children: children.into(),
+  }
+}
+
+/// Shown while the parameter of the parent [`QueryParam`] matches no
+/// [`Case`].
+#[component(transparent)]
+pub fn Otherwise(
+  cx: Scope,
+  /// What you want to show while no case matches.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  OtherwiseBlock {
+    children: children.into(),
+  }
+}
+
+transparent_block! {
+  /// The arm returned by [`Case`].
+  pub struct CaseBlock {
+    value: String,
+    children: Rc<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The fallback arm returned by [`Otherwise`].
+  pub struct OtherwiseBlock {
+    children: Rc<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+/// Reads and changes a URL query parameter.
+#[derive(Clone, Copy)]
+pub struct QueryParamHandle {
+  value: Memo<Option<String>>,
+  navigate: StoredValue<Rc<dyn Fn(Option<String>)>>,
+}
+
+impl QueryParamHandle {
+  /// Returns the value of the parameter, or the default if it's missing.
+  pub fn get(&self) -> Option<String> {
+    self.value.get()
+  }
+
+  /// Returns the value of the parameter as a signal.
+  pub fn signal(&self) -> Signal<Option<String>> {
+    self.value.into()
+  }
+
+  /// Sets the parameter to `value`.
+  pub fn set(&self, value: impl Into<String>) {
+    self
+      .navigate
+      .with_value(|navigate| navigate(Some(value.into())));
+  }
+
+  /// Removes the parameter from the URL.
+  pub fn clear(&self) {
+    self.navigate.with_value(|navigate| navigate(None));
+  }
+}
+
+/// Reads and changes the `name` URL query parameter, which is `default`
+/// while it's missing from the URL.
+///
+/// Changing the parameter navigates to the current location with the new
+/// query, replacing the current history entry when `replace` is `true`.
+///
+/// This must be called within a leptos_router `<Router>`.
+pub fn create_query_param(
+  cx: Scope,
+  name: impl Into<String>,
+  default: Option<String>,
+  replace: bool,
+) -> QueryParamHandle {
+  let name: Rc<str> = name.into().into();
+  let query = use_query_map(cx);
+  let location = use_location(cx);
+  let go = use_navigate(cx);
+
+  let value = create_memo(cx, {
+    let name = name.clone();
+
+    move |_| {
+      query
+        .with(|query| query.get(&name).cloned())
+        .or_else(|| default.clone())
+    }
+  });
+
+  let navigate: Rc<dyn Fn(Option<String>)> = Rc::new(move |value| {
+    let mut query = query.get_untracked();
+
+    match value {
+      Some(value) => {
+        query.insert(name.to_string(), value);
+      }
+      None => {
+        query.remove(&name);
+      }
+    }
+
+    let query = query.to_query_string();
+    let query = if query == "?" { "" } else { &query };
+
+    let _ = go(
+      &format!(
+        "{}{}{}",
+        location.pathname.get_untracked(),
+        query,
+        location.hash.get_untracked()
+      ),
+      NavigateOptions {
+        replace,
+        ..Default::default()
+      },
+    );
+  });
+
+  QueryParamHandle {
+    value,
+    navigate: store_value(cx, navigate),
+  }
+}
+
+/// Returns the parameter of the closest parent [`QueryParam`].
+///
+/// # Panics
+/// Panics if not called within a [`QueryParam`].
+pub fn use_query_param(cx: Scope) -> QueryParamHandle {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
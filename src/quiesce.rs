@@ -0,0 +1,140 @@
+//! Waiting for the UI to settle.
+//!
+//! Please refer to [`quiesce`] for usage examples.
+
+use leptos::*;
+#[cfg(feature = "async")]
+use std::{
+  cell::Cell,
+  rc::Rc,
+};
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  future::{
+    poll_fn,
+    Future,
+  },
+  task::{
+    Poll,
+    Waker,
+  },
+};
+
+thread_local! {
+  /// What is pending within each runtime.
+  static PENDING: RefCell<HashMap<RuntimeId, Pending>> = Default::default();
+}
+
+/// The timers and async arms which are pending within a runtime.
+#[derive(Default)]
+struct Pending {
+  count: usize,
+  /// The [`quiesce`] calls waiting for `count` to reach `0`.
+  wakers: Vec<Waker>,
+}
+
+/// Resolves once every timer and async arm created by this crate within the
+/// runtime of `cx` has settled.
+///
+/// This allows SSR rendering and integration tests to deterministically
+/// reach the settled state of the UI, instead of sleeping for an arbitrary
+/// amount of time. It resolves right away if nothing is pending. Other
+/// runtimes, such as those of concurrent SSR requests, are not waited for.
+///
+/// Timers are awaited, not fast-forwarded. Repeating timers are not tracked,
+/// since they never settle.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::quiesce::quiesce;
+///
+/// async fn render_settled(cx: Scope) {
+///   // render the app...
+///
+///   quiesce(cx).await;
+///
+///   // inspect the settled UI...
+/// }
+/// ```
+pub fn quiesce(cx: Scope) -> impl Future<Output = ()> {
+  let runtime = cx.runtime;
+
+  poll_fn(move |task| {
+    PENDING.with(|pending| match pending.borrow_mut().get_mut(&runtime) {
+      Some(pending) if pending.count > 0 => {
+        pending.wakers.push(task.waker().clone());
+
+        Poll::Pending
+      }
+      _ => Poll::Ready(()),
+    })
+  })
+}
+
+/// Keeps [`quiesce`] from resolving for its runtime for as long as it's
+/// alive.
+pub(crate) struct PendingGuard(RuntimeId);
+
+impl PendingGuard {
+  pub(crate) fn new(runtime: RuntimeId) -> Self {
+    PENDING.with(|pending| {
+      pending.borrow_mut().entry(runtime).or_default().count += 1;
+    });
+
+    Self(runtime)
+  }
+}
+
+impl Drop for PendingGuard {
+  fn drop(&mut self) {
+    let settled = PENDING.try_with(|pending| {
+      let mut pending = pending.borrow_mut();
+
+      let Some(runtime) = pending.get_mut(&self.0) else {
+        return None;
+      };
+
+      runtime.count -= 1;
+
+      if runtime.count == 0 {
+        pending.remove(&self.0)
+      } else {
+        None
+      }
+    });
+
+    // Woken outside of the borrow, since wakers may poll right away
+    if let Ok(Some(settled)) = settled {
+      settled.wakers.into_iter().for_each(Waker::wake);
+    }
+  }
+}
+
+/// Keeps [`quiesce`] from resolving while `pending` is true, or until `cx` is
+/// disposed.
+///
+/// This also works on the server, where regular effects never run.
+#[cfg(feature = "async")]
+pub(crate) fn track_pending(cx: Scope, pending: impl Fn() -> bool + 'static) {
+  let guard = Rc::new(Cell::new(None::<PendingGuard>));
+
+  create_isomorphic_effect(cx, {
+    let guard = guard.clone();
+
+    move |_| {
+      if pending() {
+        let current = guard
+          .take()
+          .unwrap_or_else(|| PendingGuard::new(cx.runtime));
+
+        guard.set(Some(current));
+      } else {
+        guard.set(None);
+      }
+    }
+  });
+
+  on_cleanup(cx, move || guard.set(None));
+}
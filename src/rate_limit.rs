@@ -0,0 +1,317 @@
+//! Debounced and throttled signals for [`leptos`].
+//!
+//! Please refer to [`Debounced`], [`Throttled`] and [`Throttle`] for usage
+//! examples.
+
+use crate::util::{
+  set_timeout,
+  Timeout,
+};
+use leptos::*;
+use std::{
+  cell::Cell,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <Debounced signal=query wait=Duration::from_millis(300) view=|cx, query: Signal<String>| view! { cx,
+      <SearchResults query />
+    } />
+
+    <Throttle render_every=Duration::from_millis(100)>
+      {if x.get() > 100 { "Right" } else { "Left" }}
+    </Throttle>
+  }
+}
+
+/// Passes `view` a copy of `signal`, which only updates once `signal` stopped
+/// changing for `wait`.
+///
+/// This is handy for search as you type, where only the final query should
+/// be searched. `view` is only called once, so it should read the signal
+/// reactively. Use [`create_debounced_signal`] outside of views.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (query, set_query) = create_signal(cx, String::new());
+///
+/// view! { cx,
+/// <input on:input=move |ev| set_query(event_target_value(&ev)) />
+/// <Debounced
+///   signal=query
+///   wait=Duration::from_millis(300)
+///   view=|cx, query: Signal<String>| view! { cx,
+///     <p>"Searching for "{move || query.get()}</p>
+///   }
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Debounced<T, F, IV>(
+  cx: Scope,
+  /// The signal to debounce.
+  #[prop(into)]
+  signal: Signal<T>,
+  /// How long `signal` must stop changing before the debounced signal is
+  /// updated.
+  wait: Duration,
+  /// Renders the content, given the debounced signal.
+  view: F,
+) -> impl IntoView
+where
+  T: Clone + 'static,
+  F: FnOnce(Scope, Signal<T>) -> IV,
+  IV: IntoView,
+{
+  let debounced = create_debounced_signal(cx, signal, wait);
+
+  view(cx, debounced)
+}
+
+/// Passes `view` a copy of `signal`, which updates at most once per `wait`.
+///
+/// The first change is passed on right away, and the last change within
+/// `wait` is passed on once it elapsed, so the final value is never missed.
+/// `view` is only called once, so it should read the signal reactively. Use
+/// [`create_throttled_signal`] outside of views.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (x, set_x) = create_signal(cx, 0);
+///
+/// view! { cx,
+/// <div on:mousemove=move |ev| set_x(ev.client_x())>
+///   <Throttled
+///     signal=x
+///     wait=Duration::from_millis(100)
+///     view=|cx, x: Signal<i32>| view! { cx, <p>{move || x.get()}</p> }
+///   />
+/// </div>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Throttled<T, F, IV>(
+  cx: Scope,
+  /// The signal to throttle.
+  #[prop(into)]
+  signal: Signal<T>,
+  /// The minimum time between updates of the throttled signal.
+  wait: Duration,
+  /// Renders the content, given the throttled signal.
+  view: F,
+) -> impl IntoView
+where
+  T: Clone + 'static,
+  F: FnOnce(Scope, Signal<T>) -> IV,
+  IV: IntoView,
+{
+  let throttled = create_throttled_signal(cx, signal, wait);
+
+  view(cx, throttled)
+}
+
+/// Renders it's children again at most once per `render_every`, coalescing
+/// bursts of changes into a single render.
+///
+/// This is handy for views fed by high frequency sources, such as
+/// `mousemove` events or WebSocket messages. Only the signals read directly
+/// while rendering the children are throttled, such as `{x.get()}`, while
+/// closures such as `{move || x.get()}` keep updating on their own.
+///
+/// The first change is rendered right away, and the last change within
+/// `render_every` is rendered once it elapsed, so the final state is never
+/// missed. The children are rendered from scratch each time.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (x, set_x) = create_signal(cx, 0);
+///
+/// view! { cx,
+/// <div on:mousemove=move |ev| set_x(ev.client_x())>
+///   <Throttle render_every=Duration::from_millis(100)>
+///     {if x.get() > 100 { "Right" } else { "Left" }}
+///   </Throttle>
+/// </div>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Throttle(
+  cx: Scope,
+  /// The minimum time between renders of the children.
+  render_every: Duration,
+  /// What you want to render.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let tick = create_rw_signal(cx, ());
+  let timeout = store_value(cx, None::<Timeout>);
+  // Whether something changed while cooling down
+  let dirty = store_value(cx, false);
+  let rendered = Cell::new(None::<View>);
+
+  on_cleanup(cx, move || {
+    timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    })
+  });
+
+  move || {
+    tick.track();
+
+    // The children aren't rendered while cooling down, so nothing but `tick`
+    // is tracked until we render again
+    if timeout.with_value(Option::is_some) {
+      if let Some(view) = rendered.take() {
+        rendered.set(Some(view.clone()));
+        dirty.set_value(true);
+
+        return view;
+      }
+    }
+
+    let view = children(cx).into_view(cx);
+
+    rendered.set(Some(view.clone()));
+
+    timeout.set_value(set_timeout(
+      cx,
+      move || {
+        timeout.set_value(None);
+
+        if dirty.get_value() {
+          dirty.set_value(false);
+          tick.set(());
+        }
+      },
+      render_every,
+    ));
+
+    view
+  }
+}
+
+/// Returns a copy of `source`, which only updates once `source` stopped
+/// changing for `wait`.
+pub fn create_debounced_signal<T>(
+  cx: Scope,
+  source: Signal<T>,
+  wait: Duration,
+) -> Signal<T>
+where
+  T: Clone + 'static,
+{
+  let debounced = create_rw_signal(cx, source.get_untracked());
+  let timeout = store_value(cx, None::<Timeout>);
+
+  let clear = move || {
+    timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    })
+  };
+
+  create_effect(cx, move |is_initial: Option<()>| {
+    let value = source.get();
+
+    // The initial value is already set
+    if is_initial.is_none() {
+      return;
+    }
+
+    clear();
+
+    timeout.set_value(set_timeout(cx, move || debounced.set(value), wait));
+  });
+
+  on_cleanup(cx, clear);
+
+  debounced.into()
+}
+
+/// Returns a copy of `source`, which updates at most once per `wait`.
+///
+/// The first change is passed on right away, and the last change within
+/// `wait` is passed on once it elapsed.
+pub fn create_throttled_signal<T>(
+  cx: Scope,
+  source: Signal<T>,
+  wait: Duration,
+) -> Signal<T>
+where
+  T: Clone + 'static,
+{
+  let throttled = create_rw_signal(cx, source.get_untracked());
+  let timeout = store_value(cx, None::<Timeout>);
+  // The latest value which changed while cooling down
+  let pending = store_value(cx, None::<T>);
+
+  fn cool_down<T: Clone + 'static>(
+    cx: Scope,
+    throttled: RwSignal<T>,
+    timeout: StoredValue<Option<Timeout>>,
+    pending: StoredValue<Option<T>>,
+    wait: Duration,
+  ) {
+    timeout.set_value(set_timeout(
+      cx,
+      move || {
+        timeout.set_value(None);
+
+        if let Some(value) = pending.get_value() {
+          pending.set_value(None);
+          throttled.set(value);
+
+          cool_down(cx, throttled, timeout, pending, wait);
+        }
+      },
+      wait,
+    ));
+  }
+
+  create_effect(cx, move |is_initial: Option<()>| {
+    let value = source.get();
+
+    if is_initial.is_none() {
+      return;
+    }
+
+    if timeout.with_value(Option::is_some) {
+      pending.set_value(Some(value));
+    } else {
+      throttled.set(value);
+
+      cool_down(cx, throttled, timeout, pending, wait);
+    }
+  });
+
+  on_cleanup(cx, move || {
+    timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    })
+  });
+
+  throttled.into()
+}
@@ -0,0 +1,83 @@
+//! Rendering based on the preference for reduced motion for [`leptos`].
+//!
+//! Please refer to [`ReducedMotion`] for usage examples.
+
+use crate::media_query::use_media_query;
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <ReducedMotion><StaticHero /></ReducedMotion>
+    <FullMotion><AnimatedHero /></FullMotion>
+  }
+}
+
+/// Renders it's children only while the user prefers reduced motion, as
+/// reported by the `prefers-reduced-motion` media feature.
+///
+/// Use [`FullMotion`] for the opposite. The transitions of
+/// [`PortalOutput`](crate::portal::PortalOutput) and
+/// [`AnimatedFor`](crate::animated_for::AnimatedFor) are disabled
+/// automatically while the user prefers reduced motion.
+///
+/// Nothing is rendered on the server, as the preference can't be known
+/// there.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ReducedMotion><img src="hero.png" /></ReducedMotion>
+/// <FullMotion><video src="hero.mp4" autoplay=true /></FullMotion>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ReducedMotion(
+  cx: Scope,
+  /// What you want to render while the user prefers reduced motion.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let reduced_motion = use_reduced_motion(cx);
+
+  move || {
+    if reduced_motion.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Renders it's children only while the user doesn't prefer reduced motion.
+///
+/// This is the opposite of [`ReducedMotion`], and it's children are
+/// rendered on the server.
+#[component]
+pub fn FullMotion(
+  cx: Scope,
+  /// What you want to render while the user doesn't prefer reduced motion.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let reduced_motion = use_reduced_motion(cx);
+
+  move || {
+    if reduced_motion.get() {
+      ().into_view(cx)
+    } else {
+      children(cx).into_view(cx)
+    }
+  }
+}
+
+/// Returns whether the user prefers reduced motion, which updates whenever
+/// the preference changes.
+///
+/// `false` is returned on the server.
+pub fn use_reduced_motion(cx: Scope) -> Signal<bool> {
+  use_media_query(cx, "(prefers-reduced-motion: reduce)", false)
+}
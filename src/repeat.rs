@@ -0,0 +1,48 @@
+//! Rendering something a number of times for [`leptos`].
+//!
+//! Please refer to [`Repeat`] for usage examples.
+
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <Repeat times=rating view=|cx, i| view! { cx, <Star index=i /> } />
+  }
+}
+
+/// Renders `view` `times` times, passing it the index of each repetition.
+///
+/// When `times` changes, only the repetitions which were added or removed
+/// are rendered or dropped. This is handy for star ratings, skeleton rows and
+/// pagination dots, without building a dummy [`Vec`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (rating, _) = create_signal(cx, 3);
+///
+/// view! { cx,
+/// <Repeat times=rating view=|cx, i| view! { cx, <span>{i + 1}"★"</span> } />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Repeat<F, IV>(
+  cx: Scope,
+  /// How many times `view` is rendered.
+  #[prop(into)]
+  times: MaybeSignal<usize>,
+  /// Renders a single repetition, given it's index.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, usize) -> IV + 'static,
+  IV: IntoView,
+{
+  view! { cx,
+    <For each=move || 0..times.get() key=|i| *i view=move |cx, i| view(cx, i) />
+  }
+}
@@ -0,0 +1,245 @@
+//! Retrying fallible async views for [`leptos`].
+//!
+//! Please refer to [`Retry`] for usage examples.
+
+use crate::{
+  await_::{
+    render_pending,
+    ResolvedBlock,
+  },
+  skeleton::use_group_pending,
+  util::{
+    set_timeout,
+    Timeout,
+  },
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::{
+  future::Future,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <Retry fetcher=load_feed attempts=3 backoff=Duration::from_millis(500)>
+      <Pending>"Loading feed..."</Pending>
+      <Resolved view=|cx, feed: Feed| view! { cx, <FeedView feed /> } />
+      <Failed view=|cx, error: String, retry: RetryHandle| view! { cx,
+        <p>{error}</p>
+        <button on:click=move |_| retry.retry()>"Retry"</button>
+      } />
+    </Retry>
+  }
+}
+
+/// Loads data with `fetcher`, and shows the [`Failed`] arm when it fails,
+/// which is given a [`RetryHandle`] to call `fetcher` again.
+///
+/// The [`Pending`](crate::await_::Pending) arm is shown while loading, and
+/// the [`Resolved`](crate::await_::Resolved) arm once the data is available,
+/// just like within an [`Await`](crate::await_::Await).
+///
+/// Failures can also be retried automatically up to `attempts` times, waiting
+/// `backoff` before the first retry and twice as long before each following
+/// one. The [`Failed`] arm is only shown once all attempts failed.
+///
+/// When placed within a [`SkeletonGroup`](crate::skeleton::SkeletonGroup),
+/// the pending arm is shown until the whole group has settled.
+///
+/// For more docs on allowed child components, check out
+/// [`RetryProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let fetcher = || async { Err::<String, _>("offline".to_string()) };
+///
+/// view! { cx,
+/// <Retry fetcher attempts=3 backoff=Duration::from_millis(500)>
+///   <Pending>"Loading..."</Pending>
+///   <Resolved view=|cx, data: String| view! { cx, <p>{data}</p> } />
+///   <Failed view=|cx, error: String, retry: RetryHandle| view! { cx,
+///     <p>{error}</p>
+///     <button on:click=move |_| retry.retry()>"Retry"</button>
+///   } />
+/// </Retry>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Retry<L, Fut, T, E>(
+  cx: Scope,
+  /// Asynchronously loads the data. This is called once on mount, and again
+  /// on every retry.
+  fetcher: L,
+  /// How many times a failure is retried automatically before showing the
+  /// [`Failed`] arm. Defaults to `0`.
+  #[prop(optional)]
+  attempts: usize,
+  /// How long to wait before the first automatic retry. Each following retry
+  /// waits twice as long as the previous one. Defaults to retrying right
+  /// away.
+  #[prop(optional)]
+  backoff: Option<Duration>,
+  /// The arms of each state of the data.
+  ///
+  /// Children must be any
+  /// - [`Pending`](crate::await_::Pending)
+  /// - [`Resolved`](crate::await_::Resolved)
+  /// - [`Failed`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  L: Fn() -> Fut + 'static,
+  Fut: Future<Output = Result<T, E>> + 'static,
+  T: Clone + 'static,
+  E: Clone + 'static,
+{
+  let attempt = create_rw_signal(cx, 0usize);
+  let data =
+    create_local_resource(cx, move || attempt.get(), move |_| fetcher());
+
+  let retries = store_value(cx, 0u32);
+  let waiting = create_rw_signal(cx, false);
+  let timeout = store_value(cx, None::<Timeout>);
+
+  let handle = RetryHandle::new(attempt, retries);
+
+  create_effect(cx, move |_| {
+    let Some(failed) = data.with(cx, Result::is_err) else {
+      return;
+    };
+
+    if !failed {
+      retries.set_value(0);
+
+      return;
+    }
+
+    let retry = retries.get_value();
+
+    if retry as usize >= attempts {
+      return;
+    }
+
+    retries.set_value(retry + 1);
+    waiting.set(true);
+
+    let delay = backoff.map_or(Duration::ZERO, |backoff| {
+      backoff.saturating_mul(2u32.saturating_pow(retry))
+    });
+
+    timeout.set_value(set_timeout(
+      cx,
+      move || {
+        waiting.set(false);
+        attempt.update(|attempt| *attempt += 1);
+      },
+      delay,
+    ));
+  });
+
+  on_cleanup(cx, move || {
+    timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    })
+  });
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let pending =
+    use_group_pending(cx, move || data.loading().get() || waiting.get());
+
+  move || {
+    if pending.get() {
+      return render_pending(cx, &blocks);
+    }
+
+    match data.read(cx) {
+      Some(Ok(data)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<ResolvedBlock<T>>)
+        .map(|block| (block.view)(cx, data))
+        .unwrap_or_else(|| ().into_view(cx)),
+      Some(Err(error)) => blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<FailedBlock<E>>)
+        .map(|block| (block.view)(cx, error, handle))
+        .unwrap_or_else(|| ().into_view(cx)),
+      None => render_pending(cx, &blocks),
+    }
+  }
+}
+
+/// Shown when the `fetcher` of the parent [`Retry`] failed, and no automatic
+/// retries are left, or when the `loader` of the parent
+/// [`LazyModule`](crate::lazy_module::LazyModule) failed.
+///
+/// The type of the error must match the error returned by the `fetcher` or
+/// `loader`, otherwise this arm will never be rendered.
+#[component(transparent)]
+pub fn Failed<F, E, IV>(
+  cx: Scope,
+  /// Renders the error. The provided [`RetryHandle`] can be used to call
+  /// the `fetcher` again.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, E, RetryHandle) -> IV + 'static,
+  E: 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  FailedBlock {
+    view: Box::new(move |cx, error, retry| {
+      view(cx, error, retry).into_view(cx)
+    }),
+  }
+}
+
+/// Allows calling the `fetcher` of a [`Retry`], or the `loader` of a
+/// [`LazyModule`](crate::lazy_module::LazyModule), again after it failed.
+#[derive(Clone, Copy)]
+pub struct RetryHandle {
+  attempt: RwSignal<usize>,
+  retries: StoredValue<u32>,
+}
+
+impl RetryHandle {
+  pub(crate) fn new(
+    attempt: RwSignal<usize>,
+    retries: StoredValue<u32>,
+  ) -> Self {
+    Self { attempt, retries }
+  }
+
+  /// Calls the `fetcher` again, with all automatic retries available again.
+  pub fn retry(&self) {
+    self.retries.set_value(0);
+    self.attempt.update(|attempt| *attempt += 1);
+  }
+}
+
+transparent_block! {
+  /// The error arm returned by [`Failed`].
+  pub struct FailedBlock<E> {
+    pub(crate) view: Box<dyn Fn(Scope, E, RetryHandle) -> View>,
+  }
+}
@@ -0,0 +1,116 @@
+//! Rendering based on the current route for [`leptos`].
+//!
+//! Please refer to [`RouteIs`] for usage examples.
+
+use crate::if_::{
+  If,
+  IfProps,
+};
+use leptos::*;
+use leptos_router::use_location;
+
+api_planning! {
+  view! { cx,
+    <RouteIs path="/admin/*">
+      <Then><AdminSidebar /></Then>
+      <Else><Sidebar /></Else>
+    </RouteIs>
+  }
+}
+
+/// Renders the [`Then`](crate::if_::Then) arm while the current location
+/// matches `path`, and the [`Else`](crate::if_::Else) arm while it doesn't,
+/// just like an [`If`].
+///
+/// This is handy for showing chrome such as sidebars and breadcrumbs on
+/// some routes, without restructuring the route tree.
+///
+/// `path` is matched segment by segment, where
+/// - `:name` matches any single segment
+/// - a trailing `*` or `*name` matches any remaining segments, including none
+///
+/// This component must be used within a leptos_router `<Router>`, and is
+/// only available with the `router` feature.
+///
+/// For more docs on allowed child components, check out
+/// [`RouteIsProps::children`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use leptos_router::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// view! { cx,
+/// <Router>
+///   <RouteIs path="/admin/*">
+///     <Then>"Admin sidebar"</Then>
+///     <Else>"Sidebar"</Else>
+///   </RouteIs>
+/// </Router>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn RouteIs(
+  cx: Scope,
+  /// The pattern the current location must match.
+  #[prop(into)]
+  path: String,
+  /// The arms of the condition.
+  ///
+  /// Children must be any
+  /// - [`Then`](crate::if_::Then)
+  /// - [`ElseIf`](crate::if_::ElseIf)
+  /// - [`Else`](crate::if_::Else)
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let matches = use_route_is(cx, path);
+
+  If(
+    cx,
+    IfProps {
+      signal: matches.into(),
+      children,
+    },
+  )
+}
+
+/// Returns whether the current location matches `path`, which updates
+/// whenever the location changes.
+///
+/// Please refer to [`RouteIs`] for the syntax of `path`.
+///
+/// This must be called within a leptos_router `<Router>`.
+pub fn use_route_is(cx: Scope, path: impl Into<String>) -> Memo<bool> {
+  let path = path.into();
+  let location = use_location(cx);
+
+  create_memo(cx, move |_| {
+    location
+      .pathname
+      .with(|pathname| path_matches(&path, pathname))
+  })
+}
+
+/// Returns whether `pathname` matches the `pattern`.
+fn path_matches(pattern: &str, pathname: &str) -> bool {
+  let mut segments = pathname.split('/').filter(|segment| !segment.is_empty());
+
+  for part in pattern.split('/').filter(|part| !part.is_empty()) {
+    if part.starts_with('*') {
+      return true;
+    }
+
+    match segments.next() {
+      Some(_) if part.starts_with(':') => {}
+      Some(segment) if segment == part => {}
+      _ => return false,
+    }
+  }
+
+  segments.next().is_none()
+}
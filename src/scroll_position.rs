@@ -0,0 +1,209 @@
+//! Rendering based on the scroll position for [`leptos`].
+//!
+//! Please refer to [`ScrollPosition`] for usage examples.
+
+use crate::util::{
+  set_timeout,
+  try_window,
+  Timeout,
+};
+use leptos::{
+  html::Div,
+  *,
+};
+use std::{
+  cell::Cell,
+  rc::Rc,
+  time::Duration,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+};
+
+api_planning! {
+  view! { cx,
+    <ScrollPosition throttle=Duration::from_millis(100) view=|cx, scroll: Signal<ScrollOffset>| view! { cx,
+      <If signal=Signal::derive(cx, move || scroll.get().y > 400.0)>
+        <Then><BackToTop /></Then>
+      </If>
+    } />
+  }
+}
+
+/// How far something is scrolled, in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollOffset {
+  /// How far it's scrolled horizontally.
+  pub x: f64,
+  /// How far it's scrolled vertically.
+  pub y: f64,
+}
+
+/// Passes the scroll position of the window to `view`, or that of a
+/// scrollable `div` wrapping `view` when `container` is set.
+///
+/// `view` is only called once, so it should read the position reactively,
+/// such as with an [`If`](crate::if_::If). Set `throttle` to update the
+/// position at most once per duration while scrolling. The position is
+/// `0.0` by `0.0` on the server.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <ScrollPosition
+///   throttle=Duration::from_millis(100)
+///   view=|cx, scroll: Signal<ScrollOffset>| view! { cx,
+///     <If signal=Signal::derive(cx, move || scroll.get().y > 400.0)>
+///       <Then><a href="#top">"Back to top"</a></Then>
+///     </If>
+///   }
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ScrollPosition<F, IV>(
+  cx: Scope,
+  /// Renders the content, given the scroll position.
+  view: F,
+  /// Tracks the scroll position of a `div` wrapping `view`, instead of that
+  /// of the window.
+  #[prop(optional)]
+  container: bool,
+  /// The `class` of the wrapping `div`, such as to make it scrollable. Only
+  /// used with `container`.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// The minimum time between updates of the position.
+  #[prop(optional)]
+  throttle: Option<Duration>,
+) -> impl IntoView
+where
+  F: FnOnce(Scope, Signal<ScrollOffset>) -> IV,
+  IV: IntoView,
+{
+  if !container {
+    let scroll = use_window_scroll(cx, throttle);
+
+    return view(cx, scroll).into_view(cx);
+  }
+
+  let element = create_node_ref::<Div>(cx);
+  let scroll = create_rw_signal(cx, ScrollOffset::default());
+
+  create_effect(cx, move |_| {
+    let Some(element) = element.get() else {
+      return;
+    };
+
+    let target = (*element).clone();
+
+    track_scroll(cx, &target, scroll, throttle, move || ScrollOffset {
+      x: element.scroll_left().into(),
+      y: element.scroll_top().into(),
+    });
+  });
+
+  view! { cx,
+    <div class=class node_ref=element>
+      {view(cx, scroll.into())}
+    </div>
+  }
+  .into_view(cx)
+}
+
+/// Returns the scroll position of the window, which updates while it's
+/// scrolled, at most once per `throttle` if set.
+///
+/// The position is `0.0` by `0.0` on the server.
+pub fn use_window_scroll(
+  cx: Scope,
+  throttle: Option<Duration>,
+) -> Signal<ScrollOffset> {
+  let scroll = create_rw_signal(cx, ScrollOffset::default());
+
+  create_effect(cx, move |_| {
+    let Some(window) = try_window() else {
+      return;
+    };
+
+    let target = window.clone();
+
+    track_scroll(cx, &target, scroll, throttle, move || ScrollOffset {
+      x: window.scroll_x().unwrap_or_default(),
+      y: window.scroll_y().unwrap_or_default(),
+    });
+  });
+
+  scroll.into()
+}
+
+/// Sets `scroll` to the position `read` returns whenever `target` scrolls.
+fn track_scroll(
+  cx: Scope,
+  target: &web_sys::EventTarget,
+  scroll: RwSignal<ScrollOffset>,
+  throttle: Option<Duration>,
+  read: impl Fn() -> ScrollOffset + 'static,
+) {
+  let read = Rc::new(read);
+
+  scroll.set(read());
+
+  let pending = Rc::new(Cell::new(None::<Timeout>));
+  let throttled = Rc::new(Cell::new(false));
+
+  let on_scroll = Closure::<dyn Fn()>::new({
+    let pending = pending.clone();
+
+    move || {
+      let Some(throttle) = throttle else {
+        scroll.set(read());
+
+        return;
+      };
+
+      // The position is read once the throttle elapses, so the last scroll
+      // is never missed
+      if !throttled.replace(true) {
+        let read = read.clone();
+        let throttled = throttled.clone();
+
+        pending.set(set_timeout(
+          cx,
+          move || {
+            throttled.set(false);
+
+            scroll.set(read());
+          },
+          throttle,
+        ));
+      }
+    }
+  });
+
+  let _ = target.add_event_listener_with_callback(
+    "scroll",
+    on_scroll.as_ref().unchecked_ref(),
+  );
+
+  let target = target.clone();
+
+  on_cleanup(cx, move || {
+    let _ = target.remove_event_listener_with_callback(
+      "scroll",
+      on_scroll.as_ref().unchecked_ref(),
+    );
+
+    if let Some(timeout) = pending.take() {
+      timeout.clear();
+    }
+  });
+}
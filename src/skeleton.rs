@@ -0,0 +1,190 @@
+//! Coordinated loading states for [`leptos`].
+//!
+//! Please refer to [`SkeletonGroup`] for usage examples.
+
+use crate::{
+  quiesce::track_pending,
+  util::{
+    set_timeout,
+    Timeout,
+  },
+};
+use leptos::*;
+use std::{
+  cell::Cell,
+  rc::Rc,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <SkeletonGroup min_display=Duration::from_millis(300)>
+      <LazyModule loader=load_a>
+        <Loading><SkeletonA /></Loading>
+      </LazyModule>
+      <LazyModule loader=load_b>
+        <Loading><SkeletonB /></Loading>
+      </LazyModule>
+    </SkeletonGroup>
+  }
+}
+
+/// Groups the pending states of all async arm components below it, such as
+/// [`LazyModule`](crate::lazy_module::LazyModule), so they show their
+/// pending arms together until every one of them has settled.
+///
+/// Arms only wait for the group until it first settles. Afterwards, each arm
+/// only shows it's pending arm while it is loading itself, so an arm which
+/// refetches, or is mounted later, never hides content which is already
+/// shown.
+///
+/// The state of the group can be read with [`use_skeleton_group`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <SkeletonGroup min_display=Duration::from_millis(300)>
+///   <p>"Async arms go here"</p>
+/// </SkeletonGroup>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn SkeletonGroup(
+  cx: Scope,
+  /// Once any arm within the group becomes pending, the whole group is
+  /// considered pending for at least this long, which avoids flashing
+  /// skeletons for fast loads.
+  #[prop(optional)]
+  min_display: Option<Duration>,
+  /// The section of your app whose loading states should be coordinated.
+  children: Children,
+) -> impl IntoView {
+  let group = SkeletonGroupState {
+    pending: create_rw_signal(cx, 0),
+    min_elapsed: create_rw_signal(cx, true),
+  };
+
+  if let Some(min_display) = min_display {
+    let timeout = store_value(cx, None::<Timeout>);
+
+    create_effect(cx, move |was_pending: Option<bool>| {
+      let is_pending = group.pending.get() > 0;
+
+      if is_pending && !was_pending.unwrap_or_default() {
+        timeout.update_value(|timeout| {
+          if let Some(timeout) = timeout.take() {
+            timeout.clear();
+          }
+        });
+
+        group.min_elapsed.set(false);
+
+        timeout.set_value(set_timeout(
+          cx,
+          move || group.min_elapsed.set(true),
+          min_display,
+        ));
+      }
+
+      is_pending
+    });
+  }
+
+  provide_context(cx, group);
+
+  children(cx)
+}
+
+/// The state of the nearest [`SkeletonGroup`].
+#[derive(Clone, Copy)]
+pub struct SkeletonGroupState {
+  pending: RwSignal<usize>,
+  min_elapsed: RwSignal<bool>,
+}
+
+impl SkeletonGroupState {
+  /// Whether any arm within the group is pending, or the group's minimum
+  /// display time has not elapsed yet.
+  ///
+  /// This is reactive.
+  pub fn any_pending(&self) -> bool {
+    self.pending.get() > 0 || !self.min_elapsed.get()
+  }
+
+  /// Whether all arms within the group have settled. This is the opposite of
+  /// [`SkeletonGroupState::any_pending`].
+  ///
+  /// This is reactive.
+  pub fn all_settled(&self) -> bool {
+    !self.any_pending()
+  }
+}
+
+/// Gets the state of the nearest [`SkeletonGroup`], if any.
+pub fn use_skeleton_group(cx: Scope) -> Option<SkeletonGroupState> {
+  use_context(cx)
+}
+
+/// Registers the `pending` state of an async arm component with the nearest
+/// [`SkeletonGroup`] and with [`quiesce`](crate::quiesce::quiesce), returning
+/// whether the component should show it's pending arm.
+pub(crate) fn use_group_pending(
+  cx: Scope,
+  pending: impl Fn() -> bool + 'static,
+) -> Signal<bool> {
+  let pending = Rc::new(pending);
+
+  track_pending(cx, {
+    let pending = pending.clone();
+
+    move || pending()
+  });
+
+  let Some(group) = use_skeleton_group(cx) else {
+    return Signal::derive(cx, move || pending());
+  };
+
+  let registered = Rc::new(Cell::new(false));
+
+  create_effect(cx, {
+    let pending = pending.clone();
+    let registered = registered.clone();
+
+    move |_| {
+      let is_pending = pending();
+
+      if is_pending != registered.get() {
+        registered.set(is_pending);
+
+        group.pending.update(|count| {
+          if is_pending {
+            *count += 1;
+          } else {
+            *count -= 1;
+          }
+        });
+      }
+    }
+  });
+
+  on_cleanup(cx, move || {
+    if registered.get() {
+      group.pending.update(|count| *count -= 1);
+    }
+  });
+
+  // Once the group has settled, this arm only waits for itself, so loading or
+  // mounting its siblings doesn't tear down content which is already shown
+  let released = create_memo(cx, move |was_released: Option<&bool>| {
+    was_released.copied().unwrap_or_default() || group.all_settled()
+  });
+
+  Signal::derive(cx, move || pending() || !released.get())
+}
@@ -0,0 +1,151 @@
+//! Tabbed panels for [`leptos`].
+//!
+//! Please refer to [`Tabs`] for usage examples.
+
+use crate::once::Once;
+use leptos::*;
+use leptos_dom::Transparent;
+
+api_planning! {
+  view! { cx,
+    <Tabs selected=tab keep_alive=true>
+      <Tab id=Page::Profile><Profile /></Tab>
+      <Tab id=Page::Settings><Settings /></Tab>
+    </Tabs>
+  }
+}
+
+/// Renders the [`Tab`] whose `id` is `selected`.
+///
+/// Tabs are only rendered once they are first selected. By default, a tab is
+/// dropped once another one is selected, and rendered again from scratch
+/// when selected again. Set `keep_alive` to keep every tab which was
+/// selected around instead, hidden within a `div` while another tab is
+/// selected, so their state survives.
+///
+/// This is only the control flow of tabs, so the tab buttons and styles are
+/// up to you.
+///
+/// For more docs on allowed child components, check out
+/// [`TabsProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (tab, set_tab) = create_signal(cx, "profile");
+///
+/// view! { cx,
+/// <nav>
+///   <button on:click=move |_| set_tab("profile")>"Profile"</button>
+///   <button on:click=move |_| set_tab("settings")>"Settings"</button>
+/// </nav>
+/// <Tabs selected=tab keep_alive=true>
+///   <Tab id="profile">"Profile"</Tab>
+///   <Tab id="settings"><input placeholder="Kept while switching tabs" /></Tab>
+/// </Tabs>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Tabs<T>(
+  cx: Scope,
+  /// The `id` of the tab to render.
+  #[prop(into)]
+  selected: MaybeSignal<T>,
+  /// Keeps tabs which were selected rendered, but hidden, instead of
+  /// dropping them.
+  #[prop(optional)]
+  keep_alive: bool,
+  /// The tabs.
+  ///
+  /// Children must be [`Tab`]s whose `id`s are of the same type as
+  /// `selected`. Any other child will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  T: PartialEq + Clone + 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .filter(|block| block.downcast_ref::<TabBlock<T>>().is_some())
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let ids = blocks
+    .iter()
+    .filter_map(Transparent::downcast_ref::<TabBlock<T>>)
+    .map(|block| block.id.clone())
+    .collect::<Vec<_>>();
+
+  let selected = create_memo(cx, move |_| {
+    let selected = selected.get();
+
+    ids.iter().position(|id| *id == selected)
+  });
+
+  if !keep_alive {
+    return (move || {
+      #[cfg(feature = "performance")]
+      let _measure = crate::performance::measure_switch(cx);
+
+      selected
+        .get()
+        .and_then(|i| blocks[i].downcast_ref::<TabBlock<T>>())
+        .map(|block| (block.children)(cx).into_view(cx))
+        .unwrap_or_else(|| ().into_view(cx))
+    })
+    .into_view(cx);
+  }
+
+  blocks
+    .into_iter()
+    .enumerate()
+    .map(|(i, block)| {
+      let is_selected = Signal::derive(cx, move || selected.get() == Some(i));
+
+      view! { cx,
+        <div hidden=move || !is_selected.get()>
+          <Once when=is_selected>
+            {block
+              .downcast_ref::<TabBlock<T>>()
+              .map(|block| (block.children)(cx))}
+          </Once>
+        </div>
+      }
+    })
+    .collect::<Vec<_>>()
+    .into_view(cx)
+}
+
+/// A tab of the parent [`Tabs`].
+#[component(transparent)]
+pub fn Tab<T>(
+  cx: Scope,
+  /// Identifies the tab. This must be of the same type as the `selected` of
+  /// the parent [`Tabs`], otherwise this tab is ignored.
+  id: T,
+  /// What you want to show while this tab is selected.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  T: 'static,
+{
+  let _ = cx;
+
+  TabBlock { id, children }
+}
+
+transparent_block! {
+  /// The tab arm returned by [`Tab`].
+  pub struct TabBlock<T> {
+    id: T,
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
@@ -0,0 +1,490 @@
+//! Helpers for testing apps which use the components of this crate.
+//!
+//! This module is only available with the `testing` feature.
+
+#[cfg(feature = "if")]
+use crate::if_::{
+  If,
+  IfBlock,
+  IfProps,
+};
+#[cfg(feature = "portal")]
+use crate::portal::{
+  connections,
+  projected,
+  PortalId,
+  PortalProvider,
+  PortalProviderProps,
+};
+#[cfg(any(feature = "if", feature = "portal"))]
+use leptos::*;
+#[cfg(any(feature = "if", feature = "portal"))]
+use std::{
+  cell::Cell,
+  rc::Rc,
+};
+
+/// Returns the index of the arm an [`If`] with the given `signal` and
+/// `children` shows, or [`None`] if it shows nothing.
+///
+/// Arms are counted in order, so `0` is the [`Then`](crate::if_::Then) arm,
+/// followed by each [`ElseIf`](crate::if_::ElseIf) and the
+/// [`Else`](crate::if_::Else) arm. The [`If`] is rendered again whenever one
+/// of the signals changes, so the index updates along with them.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::{prelude::*, testing::render_if};
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (a, set_a) = create_signal(cx, false);
+/// let (b, _) = create_signal(cx, true);
+///
+/// let arm = render_if(cx, a, move |cx| view! { cx,
+///   <Then>"A"</Then>
+///   <ElseIf signal=b>"B"</ElseIf>
+///   <Else>"Neither"</Else>
+/// });
+///
+/// assert_eq!(arm.get(), Some(1));
+///
+/// set_a(true);
+///
+/// assert_eq!(arm.get(), Some(0));
+/// # });
+/// ```
+#[cfg(feature = "if")]
+pub fn render_if<F>(
+  cx: Scope,
+  signal: impl Into<MaybeSignal<bool>>,
+  children: F,
+) -> Memo<Option<usize>>
+where
+  F: Fn(Scope) -> Fragment + 'static,
+{
+  let signal = signal.into();
+  let children = Rc::new(children);
+  let rendered = store_value(cx, None::<ScopeDisposer>);
+
+  on_cleanup(cx, move || rendered.update_value(dispose));
+
+  create_memo(cx, move |_| {
+    rendered.update_value(dispose);
+
+    let children = children.clone();
+
+    let ((index, _), disposer) = cx.run_child_scope(|cx| {
+      probe_if(cx, signal.clone(), Box::new(move |cx| children(cx)))
+    });
+
+    rendered.set_value(Some(disposer));
+
+    index
+  })
+}
+
+/// The arm of an [`If`].
+#[cfg(feature = "if")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BranchKind {
+  /// The [`Then`](crate::if_::Then) arm.
+  Then,
+  /// An [`ElseIf`](crate::if_::ElseIf) arm.
+  ElseIf,
+  /// The [`Else`](crate::if_::Else) arm.
+  Else,
+}
+
+/// What an [`If`] shows, as returned by [`evaluate_if`].
+#[cfg(feature = "if")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Branch {
+  /// The index of the arm, as returned by [`render_if`], or [`None`] if
+  /// nothing is shown.
+  pub index: Option<usize>,
+  /// The kind of the arm, or [`None`] if nothing is shown.
+  pub kind: Option<BranchKind>,
+  /// The HTML rendered by the [`If`].
+  pub html: String,
+  /// The text of the arm, which is the HTML without any tags or comments.
+  pub text: String,
+}
+
+/// Renders an [`If`] with the given `signal` and `children`, and describes
+/// what it shows right now, without a browser.
+///
+/// This allows testing conditional rendering with plain `cargo test`. Use
+/// plain values as signals to test specific cases.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::{prelude::*, testing::*};
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let branch = evaluate_if(cx, false, |cx| view! { cx,
+///   <Then>"Welcome back"</Then>
+///   <ElseIf signal=true><p>"Please "<b>"log in"</b></p></ElseIf>
+///   <Else>"Loading"</Else>
+/// });
+///
+/// assert_eq!(branch.index, Some(1));
+/// assert_eq!(branch.kind, Some(BranchKind::ElseIf));
+/// assert_eq!(branch.text, "Please log in");
+/// # });
+/// ```
+#[cfg(all(feature = "if", not(target_arch = "wasm32")))]
+pub fn evaluate_if<F>(
+  cx: Scope,
+  signal: impl Into<MaybeSignal<bool>>,
+  children: F,
+) -> Branch
+where
+  F: Fn(Scope) -> Fragment + 'static,
+{
+  let signal = signal.into();
+
+  let (branch, disposer) = cx.run_child_scope(|cx| {
+    // Kept to find out the kind of the arm which was shown
+    let blocks = children(cx);
+
+    let (index, view) = probe_if(cx, signal, Box::new(children));
+
+    let html = view.render_to_string(cx).into_owned();
+
+    let kind = index
+      .and_then(|index| {
+        blocks
+          .as_children()
+          .iter()
+          .filter_map(View::as_transparent)
+          .filter_map(|block| block.downcast_ref::<IfBlock>())
+          .nth(index)
+      })
+      .map(|block| match block {
+        IfBlock::If { .. } => BranchKind::Then,
+        IfBlock::ElseIf { .. } => BranchKind::ElseIf,
+        IfBlock::Else { .. } => BranchKind::Else,
+      });
+
+    Branch {
+      index,
+      kind,
+      text: text_content(&html),
+      html,
+    }
+  });
+
+  disposer.dispose();
+
+  branch
+}
+
+/// Disposes the scope of the previously rendered [`If`], if any.
+#[cfg(feature = "if")]
+fn dispose(rendered: &mut Option<ScopeDisposer>) {
+  if let Some(disposer) = rendered.take() {
+    disposer.dispose();
+  }
+}
+
+/// Records the arm an [`If`] shows, when provided as context above it.
+#[cfg(feature = "if")]
+#[derive(Clone, Default)]
+pub(crate) struct IfProbe(Rc<Cell<Option<usize>>>);
+
+#[cfg(feature = "if")]
+impl IfProbe {
+  pub(crate) fn set(&self, arm: Option<usize>) {
+    self.0.set(arm);
+  }
+}
+
+/// Renders an [`If`] within `cx`, returning the index of the arm it shows
+/// along with it's view.
+#[cfg(feature = "if")]
+fn probe_if(
+  cx: Scope,
+  signal: MaybeSignal<bool>,
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> (Option<usize>, View) {
+  let probe = IfProbe::default();
+
+  provide_context(cx, probe.clone());
+
+  let view = If(cx, IfProps { signal, children }).into_view(cx);
+
+  (probe.0.get(), view)
+}
+
+/// Strips the tags and comments of `html`, and decodes it's entities.
+#[cfg(all(feature = "if", not(target_arch = "wasm32")))]
+fn text_content(html: &str) -> String {
+  let mut text = String::with_capacity(html.len());
+  let mut in_tag = false;
+
+  for c in html.chars() {
+    match c {
+      '<' => in_tag = true,
+      '>' if in_tag => in_tag = false,
+      c if !in_tag => text.push(c),
+      _ => {}
+    }
+  }
+
+  text
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&#39;", "'")
+    .replace("&amp;", "&")
+}
+
+/// Renders a view within a [`PortalProvider`], and inspects the portals
+/// used by it.
+///
+/// The view is rendered in it's own runtime, which is disposed when the
+/// harness is dropped.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::{prelude::*, testing::PortalTestHarness};
+///
+/// let harness = PortalTestHarness::new(|cx| view! { cx,
+///   <PortalOutput id="toolbar" />
+///   <PortalInput id="toolbar"><p>"Save"</p></PortalInput>
+/// });
+///
+/// assert_eq!(harness.inputs("toolbar"), 1);
+/// harness.assert_connected("toolbar");
+/// assert!(harness.projected_html("toolbar").contains("Save"));
+/// ```
+#[cfg(feature = "portal")]
+pub struct PortalTestHarness {
+  runtime: RuntimeId,
+  /// A scope within the [`PortalProvider`].
+  cx: Scope,
+  view: View,
+}
+
+#[cfg(feature = "portal")]
+impl PortalTestHarness {
+  /// Renders `view` within a [`PortalProvider`].
+  pub fn new<F, IV>(view: F) -> Self
+  where
+    F: FnOnce(Scope) -> IV + 'static,
+    IV: IntoView,
+  {
+    let runtime = create_runtime();
+    let inner = Rc::new(Cell::new(None));
+    let rendered = Rc::new(Cell::new(None));
+
+    let _ = create_scope(runtime, {
+      let inner = inner.clone();
+      let rendered = rendered.clone();
+
+      move |cx| {
+        let view = PortalProvider(
+          cx,
+          PortalProviderProps {
+            policy: Default::default(),
+            children: Box::new(move |cx| {
+              inner.set(Some(cx));
+
+              Fragment::new(vec![view(cx).into_view(cx)])
+            }),
+          },
+        )
+        .into_view(cx);
+
+        rendered.set(Some(view));
+      }
+    });
+
+    Self {
+      runtime,
+      cx: inner
+        .take()
+        .expect("`PortalProvider` to render it's children"),
+      view: rendered.take().expect("the view to be rendered"),
+    }
+  }
+
+  /// Returns a scope within the [`PortalProvider`], which can be used to
+  /// create signals and render more views.
+  pub fn scope(&self) -> Scope {
+    self.cx
+  }
+
+  /// Returns the number of [`PortalInput`](crate::portal::PortalInput)s of
+  /// the portal `id`, including content set with
+  /// [`use_portal`](crate::portal::use_portal) and
+  /// [`portal_batch`](crate::portal::portal_batch).
+  pub fn inputs<T: PortalId>(&self, id: T) -> usize {
+    connections(self.cx, id).0
+  }
+
+  /// Returns the number of mounted
+  /// [`PortalOutput`](crate::portal::PortalOutput)s of the portal `id`.
+  pub fn outputs<T: PortalId>(&self, id: T) -> usize {
+    connections(self.cx, id).1
+  }
+
+  /// Returns whether the portal `id` has both an input and a mounted output.
+  pub fn is_connected<T: PortalId>(&self, id: T) -> bool {
+    let (inputs, outputs) = connections(self.cx, id);
+
+    inputs > 0 && outputs > 0
+  }
+
+  /// Asserts that the portal `id` has both an input and a mounted output.
+  ///
+  /// # Panics
+  /// Panics, listing the number of inputs and outputs, if it doesn't.
+  #[track_caller]
+  pub fn assert_connected<T: PortalId>(&self, id: T) {
+    let name = id.debug_name();
+    let (inputs, outputs) = connections(self.cx, id);
+
+    assert!(
+      inputs > 0 && outputs > 0,
+      "expected portal `{name}` to be connected, but it has {inputs} inputs \
+       and {outputs} outputs"
+    );
+  }
+
+  /// Returns the HTML of the whole view.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn html(&self) -> String {
+    self.view.render_to_string(self.cx).into_owned()
+  }
+
+  /// Returns the HTML of the content the first mounted
+  /// [`PortalOutput`](crate::portal::PortalOutput) of the portal `id` shows
+  /// right now, without the element, fallback or `view` of the output.
+  ///
+  /// The content is rendered on it's own, so reading it never changes which
+  /// output shows it.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn projected_html<T: PortalId>(&self, id: T) -> String {
+    let (html, disposer) = self.cx.run_child_scope(|cx| {
+      projected(cx, id)
+        .into_iter()
+        .map(|children| {
+          children(cx).into_view(cx).render_to_string(cx).into_owned()
+        })
+        .collect::<String>()
+    });
+
+    disposer.dispose();
+
+    html
+  }
+}
+
+#[cfg(feature = "portal")]
+impl Drop for PortalTestHarness {
+  fn drop(&mut self) {
+    self.runtime.dispose();
+  }
+}
+
+#[cfg(all(test, feature = "if", not(target_arch = "wasm32")))]
+mod tests {
+  use super::*;
+  use crate::if_::{
+    Else,
+    ElseIf,
+    ElseIfProps,
+    ElseProps,
+    Then,
+    ThenProps,
+  };
+
+  #[test]
+  fn text_content_strips_tags_and_comments() {
+    assert_eq!(text_content("<p>Please <b>log in</b></p>"), "Please log in");
+    assert_eq!(text_content("<!-- <If/> -->Hi<!-- </If> -->"), "Hi");
+    assert_eq!(text_content("plain"), "plain");
+    assert_eq!(text_content(""), "");
+  }
+
+  #[test]
+  fn text_content_decodes_entities() {
+    assert_eq!(
+      text_content("&lt;b&gt; &amp; &quot;quoted&quot; &#39;single&#39;"),
+      "<b> & \"quoted\" 'single'"
+    );
+    // Decoded once, so escaped entities stay entities
+    assert_eq!(text_content("&amp;lt;"), "&lt;");
+  }
+
+  fn arms(cx: Scope, b: impl Into<MaybeSignal<bool>>) -> Fragment {
+    let b = b.into();
+
+    view! { cx,
+      <Then>"A"</Then>
+      <ElseIf signal=b><p>"B"</p></ElseIf>
+      <Else>"Neither"</Else>
+    }
+  }
+
+  #[test]
+  fn evaluate_if_describes_each_arm() {
+    let _ = create_scope(create_runtime(), |cx| {
+      let then = evaluate_if(cx, true, |cx| arms(cx, true));
+
+      assert_eq!(then.index, Some(0));
+      assert_eq!(then.kind, Some(BranchKind::Then));
+      assert_eq!(then.text, "A");
+
+      let else_if = evaluate_if(cx, false, |cx| arms(cx, true));
+
+      assert_eq!(else_if.index, Some(1));
+      assert_eq!(else_if.kind, Some(BranchKind::ElseIf));
+      assert_eq!(else_if.text, "B");
+      assert!(else_if.html.contains("<p"));
+
+      let else_ = evaluate_if(cx, false, |cx| arms(cx, false));
+
+      assert_eq!(else_.index, Some(2));
+      assert_eq!(else_.kind, Some(BranchKind::Else));
+      assert_eq!(else_.text, "Neither");
+    });
+  }
+
+  #[test]
+  fn evaluate_if_without_a_matching_arm() {
+    let _ = create_scope(create_runtime(), |cx| {
+      let branch = evaluate_if(cx, false, |cx| view! { cx, <Then>"A"</Then> });
+
+      assert_eq!(branch.index, None);
+      assert_eq!(branch.kind, None);
+      assert_eq!(branch.text, "");
+    });
+  }
+
+  #[test]
+  fn render_if_follows_signals() {
+    let _ = create_scope(create_runtime(), |cx| {
+      let (a, set_a) = create_signal(cx, false);
+      let (b, set_b) = create_signal(cx, false);
+
+      let arm = render_if(cx, a, move |cx| arms(cx, b));
+
+      assert_eq!(arm.get(), Some(2));
+
+      set_b(true);
+      assert_eq!(arm.get(), Some(1));
+
+      set_a(true);
+      assert_eq!(arm.get(), Some(0));
+
+      set_a(false);
+      set_b(false);
+      assert_eq!(arm.get(), Some(2));
+    });
+  }
+}
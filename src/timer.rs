@@ -0,0 +1,323 @@
+//! Timers and countdowns for [`leptos`].
+//!
+//! Please refer to [`Timer`] and [`Countdown`] for usage examples.
+
+use crate::util::{
+  set_timeout,
+  try_window,
+  Timeout,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+use std::time::Duration;
+
+api_planning! {
+  view! { cx,
+    <Timer after=Duration::from_secs(5)>
+      <Running>"Undo"</Running>
+      <Done>"Saved"</Done>
+    </Timer>
+
+    <Countdown from=Duration::from_secs(60) done=expired>
+      <Remaining view=|cx, remaining: Signal<Duration>| view! { cx,
+        <p>{move || remaining.get().as_secs()}" seconds left"</p>
+      } />
+      <Done>
+        <button on:click=move |_| expired.set(false)>"Try again"</button>
+      </Done>
+    </Countdown>
+  }
+}
+
+/// Renders the [`Running`] arm until `after` has elapsed since it was
+/// mounted, and the [`Done`] arm afterwards.
+///
+/// Bind `done` to know when the timer is done, and set it to `false` to
+/// restart the timer. The timer only runs in the browser, so the
+/// [`Running`] arm is what gets rendered on the server.
+///
+/// For more docs on allowed child components, check out
+/// [`TimerProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <Timer after=Duration::from_secs(5)>
+///   <Running><button>"Undo"</button></Running>
+///   <Done>"Saved"</Done>
+/// </Timer>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Timer(
+  cx: Scope,
+  /// How long the timer runs for.
+  after: Duration,
+  /// Set to `true` once the timer is done. Setting it to `false` restarts
+  /// the timer.
+  #[prop(optional)]
+  done: Option<RwSignal<bool>>,
+  /// The arms of the timer.
+  ///
+  /// Children must be any
+  /// - [`Running`]
+  /// - [`Done`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let done = done.unwrap_or_else(|| create_rw_signal(cx, false));
+
+  use_countdown(cx, after, None, done);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if done.get() {
+      render_done(cx, &blocks)
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<RunningBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+        .unwrap_or_else(|| ().into_view(cx))
+    }
+  }
+}
+
+/// Counts down `from` the given duration once mounted, rendering the
+/// [`Remaining`] arm with the remaining time until it reaches zero, and the
+/// [`Done`] arm afterwards.
+///
+/// The remaining time is updated every `tick`. Bind `done` to know when the
+/// countdown is done, and set it to `false` to restart it. The countdown
+/// only runs in the browser, so the [`Remaining`] arm is rendered with the
+/// full duration on the server.
+///
+/// For more docs on allowed child components, check out
+/// [`CountdownProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::time::Duration;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let expired = create_rw_signal(cx, false);
+///
+/// view! { cx,
+/// <Countdown from=Duration::from_secs(60) done=expired>
+///   <Remaining view=|cx, remaining: Signal<Duration>| view! { cx,
+///     <p>{move || remaining.get().as_secs()}" seconds left"</p>
+///   } />
+///   <Done>
+///     <button on:click=move |_| expired.set(false)>"Try again"</button>
+///   </Done>
+/// </Countdown>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Countdown(
+  cx: Scope,
+  /// How long the countdown runs for.
+  from: Duration,
+  /// How often the remaining time is updated. Defaults to every second.
+  #[prop(optional)]
+  tick: Option<Duration>,
+  /// Set to `true` once the countdown is done. Setting it to `false`
+  /// restarts the countdown.
+  #[prop(optional)]
+  done: Option<RwSignal<bool>>,
+  /// The arms of the countdown.
+  ///
+  /// Children must be any
+  /// - [`Remaining`]
+  /// - [`Done`]
+  ///
+  /// Any other child not in the above list will not be rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let done = done.unwrap_or_else(|| create_rw_signal(cx, false));
+  let tick = tick.unwrap_or(Duration::from_secs(1));
+
+  let remaining = use_countdown(cx, from, Some(tick), done);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  move || {
+    if done.get() {
+      render_done(cx, &blocks)
+    } else {
+      blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<RemainingBlock>)
+        .map(|block| (block.view)(cx, remaining))
+        .unwrap_or_else(|| ().into_view(cx))
+    }
+  }
+}
+
+/// Renders the [`Done`] arm, if any.
+fn render_done(cx: Scope, blocks: &[Transparent]) -> View {
+  blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<DoneBlock>)
+    .map(|block| (block.children)(cx).into_view(cx))
+    .unwrap_or_else(|| ().into_view(cx))
+}
+
+/// Sets `done` to `true` once `duration` has elapsed, returning the remaining
+/// time, which is updated every `tick`.
+///
+/// The countdown restarts whenever `done` is set to `false`.
+fn use_countdown(
+  cx: Scope,
+  duration: Duration,
+  tick: Option<Duration>,
+  done: RwSignal<bool>,
+) -> Signal<Duration> {
+  let remaining = create_rw_signal(cx, duration);
+  let timeout = store_value(cx, None::<Timeout>);
+  let interval = store_value(cx, None::<IntervalHandle>);
+
+  let stop = move || {
+    timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    });
+    interval.update_value(|interval| {
+      if let Some(interval) = interval.take() {
+        interval.clear();
+      }
+    });
+  };
+
+  create_effect(cx, move |_| {
+    stop();
+
+    if done.get() {
+      remaining.set(Duration::ZERO);
+
+      return;
+    }
+
+    remaining.set(duration);
+
+    timeout.set_value(set_timeout(cx, move || done.set(true), duration));
+
+    // Without a browser, the timeout fired right away, and there is no clock
+    // to tick with
+    if try_window().is_none() {
+      return;
+    }
+
+    if let Some(tick) = tick {
+      let start = js_sys::Date::now();
+
+      interval.set_value(
+        set_interval_with_handle(
+          move || {
+            let elapsed = (js_sys::Date::now() - start).max(0.0) / 1000.0;
+
+            remaining
+              .set(duration.saturating_sub(Duration::from_secs_f64(elapsed)));
+          },
+          tick,
+        )
+        .ok(),
+      );
+    }
+  });
+
+  on_cleanup(cx, stop);
+
+  remaining.into()
+}
+
+/// Shown while the parent [`Timer`] is running.
+#[component(transparent)]
+pub fn Running(
+  cx: Scope,
+  /// What you want to show while the timer is running.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  RunningBlock { children }
+}
+
+/// Shown while the parent [`Countdown`] is running.
+#[component(transparent)]
+pub fn Remaining<F, IV>(
+  cx: Scope,
+  /// Renders the content, given the remaining time. This is only called
+  /// once, so it should read the remaining time reactively.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, Signal<Duration>) -> IV + 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  RemainingBlock {
+    view: Box::new(move |cx, remaining| view(cx, remaining).into_view(cx)),
+  }
+}
+
+/// Shown once the parent [`Timer`] or [`Countdown`] is done.
+#[component(transparent)]
+pub fn Done(
+  cx: Scope,
+  /// What you want to show once done.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  DoneBlock { children }
+}
+
+transparent_block! {
+  /// The running arm returned by [`Running`].
+  pub struct RunningBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The remaining time arm returned by [`Remaining`].
+  pub struct RemainingBlock {
+    view: Box<dyn Fn(Scope, Signal<Duration>) -> View>,
+  }
+}
+
+transparent_block! {
+  /// The done arm returned by [`Done`].
+  pub struct DoneBlock {
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
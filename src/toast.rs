@@ -0,0 +1,339 @@
+//! Toast notifications for [`leptos`], built on top of
+//! [portals](crate::portal).
+//!
+//! Please refer to [`ToastProvider`] for usage examples.
+
+use crate::{
+  error::{
+    context_or_report,
+    Error,
+  },
+  portal::{
+    use_portal,
+    PortalId,
+    PortalOutput,
+  },
+  util::{
+    set_timeout,
+    try_window,
+    Timeout,
+  },
+};
+use leptos::*;
+use std::time::Duration;
+
+api_planning! {
+  view! { cx,
+    <PortalProvider>
+      <ToastProvider max_visible=3>
+        <ToastOutlet class="toasts" />
+        // rest of your app
+      </ToastProvider>
+    </PortalProvider>
+  }
+
+  let toast = use_toast(cx)?;
+
+  let handle = toast.push(|cx, handle| view! { cx,
+    <p>"Saved!"</p>
+    <button on:click=move |_| handle.dismiss()>"Close"</button>
+  });
+}
+
+/// The portal toasts are projected through.
+#[derive(Hash, PartialEq, Eq)]
+struct ToastRegion;
+
+impl PortalId for ToastRegion {}
+
+/// Provides [`use_toast`] to the rest of your app.
+///
+/// Toasts are shown in the [`ToastOutlet`], which can be placed anywhere
+/// below the same [`PortalProvider`](crate::portal::PortalProvider) as this
+/// component. Each toast is removed after the provider's `duration`, unless
+/// pushed with a different one, or when it's [`ToastHandle`] is dismissed.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn SaveButton(cx: Scope) -> impl IntoView {
+///   let toast = use_toast(cx);
+///
+///   let on_click = move |_| {
+///     let Some(toast) = toast else {
+///       return;
+///     };
+///
+///     toast.push(|cx, handle| view! { cx,
+///       <p>"Saved!"</p>
+///       <button on:click=move |_| handle.dismiss()>"Close"</button>
+///     });
+///   };
+///
+///   view! { cx, <button on:click=on_click>"Save"</button> }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+///   <PortalProvider>
+///     <ToastProvider max_visible=3>
+///       <ToastOutlet class="toasts" />
+///       <SaveButton />
+///     </ToastProvider>
+///   </PortalProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn ToastProvider(
+  cx: Scope,
+  /// How long toasts are shown for, unless pushed with
+  /// [`Toaster::push_for`]. Defaults to 5 seconds.
+  #[prop(optional)]
+  duration: Option<Duration>,
+  /// The maximum number of toasts shown at once. Toasts pushed while this
+  /// many are shown wait until older ones are removed. Defaults to no limit.
+  #[prop(optional)]
+  max_visible: Option<usize>,
+  /// The rest of your app. [`use_toast`] can be used anywhere below this
+  /// point.
+  children: Children,
+) -> impl IntoView {
+  let toaster = Toaster {
+    cx,
+    next_id: store_value(cx, 0),
+    duration: duration.unwrap_or(Duration::from_secs(5)),
+    max_visible,
+    toasts: store_value(cx, Vec::new()),
+  };
+
+  provide_context(cx, toaster);
+
+  // Pending timeouts would otherwise fire after the provider is gone
+  on_cleanup(cx, move || toaster.dismiss_all());
+
+  children(cx)
+}
+
+/// Where the toasts of the nearest [`ToastProvider`] are shown.
+///
+/// Toasts are rendered in the order they were pushed, within a `div` which
+/// announces new toasts to assistive technologies.
+#[component]
+pub fn ToastOutlet(
+  cx: Scope,
+  /// The `class` of the element containing the toasts.
+  #[prop(optional, into)]
+  class: Option<String>,
+) -> impl IntoView {
+  let mut attrs =
+    vec![("role", "status".into()), ("aria-live", "polite".into())];
+
+  if let Some(class) = class {
+    attrs.push(("class", class));
+  }
+
+  view! { cx, <PortalOutput id=ToastRegion attrs /> }
+}
+
+/// Gets the [`Toaster`] of the nearest [`ToastProvider`].
+///
+/// If there is no [`ToastProvider`] above `cx`,
+/// [`Error::MissingProvider`](crate::error::Error::MissingProvider) is
+/// reported and `None` is returned.
+pub fn use_toast(cx: Scope) -> Option<Toaster> {
+  context_or_report(
+    cx,
+    Error::MissingProvider {
+      provider: "<ToastProvider />",
+      context: "Toaster",
+    },
+  )
+}
+
+/// Pushes toasts to the [`ToastOutlet`] of a [`ToastProvider`]. Returned by
+/// [`use_toast`].
+#[derive(Clone, Copy)]
+pub struct Toaster {
+  /// The scope of the [`ToastProvider`], which owns the toasts.
+  cx: Scope,
+  next_id: StoredValue<usize>,
+  duration: Duration,
+  max_visible: Option<usize>,
+  /// Every toast which was pushed and not removed yet, oldest first.
+  toasts: StoredValue<Vec<ToastEntry>>,
+}
+
+impl Toaster {
+  /// Shows a toast for the duration of the [`ToastProvider`].
+  ///
+  /// The view is given a [`ToastHandle`], so it can render a button
+  /// dismissing itself.
+  pub fn push<F, IV>(&self, view: F) -> ToastHandle
+  where
+    F: Fn(Scope, ToastHandle) -> IV + 'static,
+    IV: IntoView,
+  {
+    self.push_for(Some(self.duration), view)
+  }
+
+  /// Shows a toast for `duration`, or until it's dismissed if `duration` is
+  /// `None`.
+  pub fn push_for<F, IV>(
+    &self,
+    duration: Option<Duration>,
+    view: F,
+  ) -> ToastHandle
+  where
+    F: Fn(Scope, ToastHandle) -> IV + 'static,
+    IV: IntoView,
+  {
+    let id = self.next_id.get_value();
+
+    self.next_id.set_value(id + 1);
+
+    let handle = ToastHandle { toaster: *self, id };
+
+    self.toasts.update_value(|toasts| {
+      toasts.push(ToastEntry {
+        id,
+        duration,
+        view: Some(Box::new(move |cx| view(cx, handle).into_view(cx))),
+        shown: None,
+      })
+    });
+
+    self.show_waiting();
+
+    handle
+  }
+
+  /// Removes every toast, including the ones waiting to be shown.
+  pub fn dismiss_all(&self) {
+    let mut toasts = Vec::new();
+
+    self.toasts.update_value(|all| toasts = std::mem::take(all));
+
+    toasts.into_iter().for_each(ToastEntry::remove);
+  }
+
+  /// Removes the toast with the given `id`, showing the next waiting one.
+  fn dismiss(&self, id: usize) {
+    let mut removed = None;
+
+    self.toasts.update_value(|toasts| {
+      if let Some(pos) = toasts.iter().position(|toast| toast.id == id) {
+        removed = Some(toasts.remove(pos));
+      }
+    });
+
+    if let Some(toast) = removed {
+      toast.remove();
+
+      self.show_waiting();
+    }
+  }
+
+  /// Shows waiting toasts until `max_visible` is reached.
+  fn show_waiting(&self) {
+    let max_visible = self.max_visible.unwrap_or(usize::MAX);
+    let mut to_show = Vec::new();
+
+    self.toasts.update_value(|toasts| {
+      let shown = toasts.iter().filter(|toast| toast.view.is_none()).count();
+
+      to_show = toasts
+        .iter_mut()
+        .filter_map(|toast| {
+          Some((toast.id, toast.duration, toast.view.take()?))
+        })
+        .take(max_visible.saturating_sub(shown))
+        .collect::<Vec<_>>();
+    });
+
+    // Projected outside of `update_value`, since projecting can run effects
+    // which push more toasts
+    for (id, duration, view) in to_show {
+      let handle = ToastHandle { toaster: *self, id };
+
+      let (_, disposer) = self.cx.run_child_scope(|cx| {
+        if let Some(portal) = use_portal(cx, ToastRegion) {
+          portal.set(view);
+        }
+      });
+
+      // Toasts are never expired on the server, as they would be gone before
+      // being rendered
+      let timeout =
+        duration
+          .filter(|_| try_window().is_some())
+          .and_then(|duration| {
+            set_timeout(self.cx, move || handle.dismiss(), duration)
+          });
+
+      let mut shown = Some(ToastShown { disposer, timeout });
+
+      self.toasts.update_value(|toasts| {
+        if let Some(toast) = toasts.iter_mut().find(|toast| toast.id == id) {
+          toast.shown = shown.take();
+        }
+      });
+
+      // Dismissed while being projected
+      if let Some(shown) = shown {
+        shown.remove();
+      }
+    }
+  }
+}
+
+/// Allows dismissing a toast pushed with [`Toaster::push`].
+#[derive(Clone, Copy)]
+pub struct ToastHandle {
+  toaster: Toaster,
+  id: usize,
+}
+
+impl ToastHandle {
+  /// Removes the toast, whether it's shown or still waiting to be shown.
+  pub fn dismiss(&self) {
+    self.toaster.dismiss(self.id);
+  }
+}
+
+struct ToastEntry {
+  id: usize,
+  duration: Option<Duration>,
+  /// Taken once the toast is shown.
+  view: Option<Box<dyn Fn(Scope) -> View>>,
+  shown: Option<ToastShown>,
+}
+
+impl ToastEntry {
+  fn remove(self) {
+    if let Some(shown) = self.shown {
+      shown.remove();
+    }
+  }
+}
+
+/// A toast which is being projected into the [`ToastOutlet`].
+struct ToastShown {
+  /// Disposing the scope removes the toast from the portal.
+  disposer: ScopeDisposer,
+  timeout: Option<Timeout>,
+}
+
+impl ToastShown {
+  fn remove(self) {
+    if let Some(timeout) = self.timeout {
+      timeout.clear();
+    }
+
+    self.disposer.dispose();
+  }
+}
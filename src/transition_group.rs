@@ -0,0 +1,322 @@
+//! Enter and leave transitions of keyed lists for [`leptos`].
+//!
+//! Please refer to [`TransitionGroup`] for usage examples.
+
+use crate::{
+  reduced_motion::use_reduced_motion,
+  util::{
+    set_timeout,
+    try_window,
+    Timeout,
+  },
+};
+use leptos::*;
+use std::{
+  cell::{
+    Cell,
+    RefCell,
+  },
+  hash::Hash,
+  rc::Rc,
+  time::Duration,
+};
+
+api_planning! {
+  view! { cx,
+    <TransitionGroup
+      each=notifications
+      key=|n| n.id
+      view=|cx, n| view! { cx, <Notification n /> }
+      enter_class="slide-in"
+      leave_class="slide-out"
+    />
+  }
+}
+
+/// Renders a keyed list, like leptos' [`For`], adding `enter_class` to items
+/// which are added, and `leave_class` to items which are removed, only
+/// removing them once the CSS animation or transition started by
+/// `leave_class` ends, or after `duration`.
+///
+/// Each item is wrapped in an element, which gets the classes. Leaving items
+/// stay where they were until they are removed. Items rendered initially
+/// only get `enter_class` if `appear` is set, and no classes are added while
+/// the user prefers reduced motion.
+///
+/// Conditional content can be animated the same way, with a list of at most
+/// one item.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (notifications, _) = create_signal(cx, vec![(0, "Saved")]);
+/// let (show_hint, _) = create_signal(cx, true);
+///
+/// view! { cx,
+/// <TransitionGroup
+///   each=move || notifications.get()
+///   key=|n| n.0
+///   view=|cx, n| view! { cx, <p>{n.1}</p> }
+///   enter_class="slide-in"
+///   leave_class="slide-out"
+/// />
+///
+/// <TransitionGroup
+///   each=move || show_hint.get().then_some(())
+///   key=|_| ()
+///   view=|cx, _| view! { cx, <p>"Psst!"</p> }
+///   leave_class="fade-out"
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn TransitionGroup<IF, I, T, EF, N, KF, K>(
+  cx: Scope,
+  /// Returns the items to render. This should be reactive.
+  each: IF,
+  /// Returns a key which uniquely identifies an item, so items are only
+  /// re-rendered when they are added.
+  key: KF,
+  /// Renders a single item.
+  view: EF,
+  /// The tag of the element wrapping each item. Defaults to `div`.
+  #[prop(optional, into)]
+  tag: Option<String>,
+  /// Added to the element wrapping each item when it's added.
+  #[prop(optional, into)]
+  enter_class: Option<String>,
+  /// Replaces `enter_class` when an item is removed. The item is only
+  /// removed once the CSS animation or transition started by this class
+  /// ends, so make sure it starts one.
+  #[prop(optional, into)]
+  leave_class: Option<String>,
+  /// Adds `enter_class` to the items rendered initially as well.
+  #[prop(optional)]
+  appear: bool,
+  /// How long the leave transition takes. When set, removed items are
+  /// removed after this long, instead of when their transition ends.
+  #[prop(optional)]
+  duration: Option<Duration>,
+) -> impl IntoView
+where
+  IF: Fn() -> I + 'static,
+  I: IntoIterator<Item = T>,
+  EF: Fn(Scope, T) -> N + 'static,
+  N: IntoView,
+  KF: Fn(&T) -> K + 'static,
+  K: Eq + Hash + Clone + 'static,
+  T: Clone + 'static,
+{
+  let tag = tag.unwrap_or_else(|| "div".into());
+  let reduced_motion = use_reduced_motion(cx);
+  let key = Rc::new(key);
+
+  let list = Rc::new(RefCell::new(LeavingList::<K, T>::default()));
+  let is_mounted = Rc::new(Cell::new(false));
+  // Whether items which are rendered now are entering
+  let entering = Rc::new(Cell::new(appear));
+
+  // Keys of removed items whose leave transition is running, and of those
+  // whose transition finished
+  let leaving = create_rw_signal(cx, Vec::<K>::new());
+  let left = create_rw_signal(cx, Vec::<K>::new());
+
+  let each = {
+    let key = key.clone();
+    let list = list.clone();
+    let entering = entering.clone();
+    let leave = leave_class.is_some();
+
+    move || {
+      let left_keys = left.get();
+
+      let mut items = each()
+        .into_iter()
+        .map(|item| (key(&item), item))
+        .collect::<Vec<_>>();
+
+      // Without a browser, transitions never end
+      let animate = leave
+        && is_mounted.get()
+        && try_window().is_some()
+        && !reduced_motion.get_untracked();
+
+      let still_leaving = {
+        let mut list = list.borrow_mut();
+
+        list.update(&mut items, animate, &left_keys, |removed| {
+          let removed = removed.clone();
+
+          set_timeout(
+            cx,
+            move || left.update(|left| left.push(removed)),
+            duration?,
+          )
+        });
+
+        list.leaving_keys()
+      };
+
+      // Finished transitions were handled above
+      left.update_untracked(|left| {
+        left.retain(|left| still_leaving.contains(left))
+      });
+
+      if leaving.with_untracked(|leaving| *leaving != still_leaving) {
+        leaving.set(still_leaving);
+      }
+
+      entering.set(is_mounted.replace(true) || appear);
+
+      items.into_iter().map(|(_, item)| item).collect::<Vec<_>>()
+    }
+  };
+
+  on_cleanup(cx, move || list.borrow_mut().clear());
+
+  let view = {
+    let key = key.clone();
+
+    move |cx, item: T| {
+      let item_key = key(&item);
+      let is_entering = entering.get();
+      let enter_class = enter_class.clone();
+      let leave_class = leave_class.clone();
+
+      let is_leaving = create_memo(cx, {
+        let item_key = item_key.clone();
+
+        move |_| leaving.with(|leaving| leaving.contains(&item_key))
+      });
+
+      let finish = move |ev: web_sys::Event| {
+        if is_leaving.get_untracked() && ev.target() == ev.current_target() {
+          left.update(|left| left.push(item_key.clone()));
+        }
+      };
+
+      html::custom(cx, html::Custom::new(tag.clone()))
+        .attr("class", move || {
+          if reduced_motion.get() {
+            None
+          } else if is_leaving.get() {
+            leave_class.clone()
+          } else if is_entering {
+            enter_class.clone()
+          } else {
+            None
+          }
+        })
+        .on(ev::animationend, {
+          let finish = finish.clone();
+
+          move |ev| finish(ev.into())
+        })
+        .on(ev::transitionend, move |ev| finish(ev.into()))
+        .child(view(cx, item))
+    }
+  };
+
+  view! { cx, <For each key=move |item: &T| key(item) view /> }
+}
+
+/// The items of a keyed list as last rendered, which keeps removed items where
+/// they were while they leave. Shared by [`TransitionGroup`] and
+/// [`AnimatedFor`](crate::animated_for::AnimatedFor).
+pub(crate) struct LeavingList<K, T> {
+  /// The items rendered last, including the ones which are leaving.
+  rendered: Vec<(K, T)>,
+  /// The keys of the items which are leaving, with the timeout removing them,
+  /// if any.
+  leaving: Vec<(K, Option<Timeout>)>,
+}
+
+impl<K, T> Default for LeavingList<K, T> {
+  fn default() -> Self {
+    Self {
+      rendered: Vec::new(),
+      leaving: Vec::new(),
+    }
+  }
+}
+
+impl<K: Eq + Clone, T: Clone> LeavingList<K, T> {
+  /// Inserts the removed items which are still leaving into `items`, after
+  /// the item they followed before, and remembers `items` as rendered.
+  ///
+  /// Removed items only leave when `animate` is set, until their key is in
+  /// `left`. `start` is called once for each item which starts leaving, and
+  /// returns the timeout which removes it.
+  pub(crate) fn update(
+    &mut self,
+    items: &mut Vec<(K, T)>,
+    animate: bool,
+    left: &[K],
+    mut start: impl FnMut(&K) -> Option<Timeout>,
+  ) {
+    // Items which come back while leaving stay
+    self.stop_leaving(|key| items.iter().any(|(item, _)| item == key));
+
+    let previous = std::mem::take(&mut self.rendered);
+
+    for (i, (removed, item)) in previous.iter().enumerate() {
+      if items.iter().any(|(key, _)| key == removed) {
+        continue;
+      }
+
+      if !animate || left.contains(removed) {
+        self.stop_leaving(|key| key == removed);
+
+        continue;
+      }
+
+      if !self.is_leaving(removed) {
+        let timeout = start(removed);
+
+        self.leaving.push((removed.clone(), timeout));
+      }
+
+      let pos = previous[..i]
+        .iter()
+        .rev()
+        .find_map(|(prev, _)| items.iter().position(|(key, _)| key == prev))
+        .map_or(0, |pos| pos + 1);
+
+      items.insert(pos, (removed.clone(), item.clone()));
+    }
+
+    self.rendered = items.clone();
+  }
+
+  /// Whether the item with `key` is leaving.
+  pub(crate) fn is_leaving(&self, key: &K) -> bool {
+    self.leaving.iter().any(|(leaving, _)| leaving == key)
+  }
+
+  /// The keys of the items which are leaving.
+  pub(crate) fn leaving_keys(&self) -> Vec<K> {
+    self.leaving.iter().map(|(key, _)| key.clone()).collect()
+  }
+
+  /// Cancels the timeouts of every leaving item, once the list is disposed.
+  pub(crate) fn clear(&mut self) {
+    self.stop_leaving(|_| true);
+  }
+
+  fn stop_leaving(&mut self, mut stop: impl FnMut(&K) -> bool) {
+    let (stopped, leaving): (Vec<_>, Vec<_>) =
+      std::mem::take(&mut self.leaving)
+        .into_iter()
+        .partition(|(key, _)| stop(key));
+
+    self.leaving = leaving;
+
+    for timeout in stopped.into_iter().filter_map(|(_, timeout)| timeout) {
+      timeout.clear();
+    }
+  }
+}
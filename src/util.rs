@@ -1,3 +1,169 @@
+// Which of these helpers are used depends on the enabled features
+#![allow(dead_code)]
+
+use crate::quiesce::PendingGuard;
+use std::{
+  cell::Cell,
+  rc::Rc,
+};
+
 macro_rules! api_planning {
   ($($tt:tt)*) => {};
 }
+
+/// Declares the block returned by an arm component, such as [`Then`], which
+/// is passed to it's parent as a [`Transparent`] view and downcast back there.
+///
+/// [`Then`]: crate::if_::Then
+/// [`Transparent`]: leptos::Transparent
+macro_rules! transparent_block {
+  (
+    $(#[$meta:meta])*
+    $vis:vis $kind:tt $name:ident $(<$param:ident>)? { $($body:tt)* }
+  ) => {
+    $(#[$meta])*
+    $vis $kind $name $(<$param>)? { $($body)* }
+
+    impl $(<$param: 'static>)? ::leptos::IntoView for $name $(<$param>)? {
+      fn into_view(self, _: ::leptos::Scope) -> ::leptos::View {
+        ::leptos::View::Transparent(::leptos::Transparent::new(self))
+      }
+    }
+  };
+}
+
+/// Returns the browser's `window`, or `None` when not running in a browser,
+/// such as during SSR.
+pub(crate) fn try_window() -> Option<web_sys::Window> {
+  #[cfg(target_arch = "wasm32")]
+  {
+    web_sys::window()
+  }
+
+  #[cfg(not(target_arch = "wasm32"))]
+  {
+    None
+  }
+}
+
+/// Calls `cb` after `duration`, or right away when not running in a browser.
+///
+/// [`quiesce`](crate::quiesce::quiesce) waits for the timeout until it either
+/// fires or is cleared, when called with a scope of the same runtime as `cx`.
+pub(crate) fn set_timeout(
+  cx: leptos::Scope,
+  cb: impl FnOnce() + 'static,
+  duration: std::time::Duration,
+) -> Option<Timeout> {
+  if try_window().is_some() {
+    let guard = Rc::new(Cell::new(Some(PendingGuard::new(cx.runtime))));
+
+    let handle = leptos::set_timeout_with_handle(
+      {
+        let guard = guard.clone();
+
+        move || {
+          cb();
+
+          guard.set(None);
+        }
+      },
+      duration,
+    )
+    .ok()?;
+
+    Some(Timeout { handle, guard })
+  } else {
+    cb();
+
+    None
+  }
+}
+
+/// A pending timeout created with [`set_timeout`].
+pub(crate) struct Timeout {
+  handle: leptos::TimeoutHandle,
+  guard: Rc<Cell<Option<PendingGuard>>>,
+}
+
+impl Timeout {
+  /// Cancels the timeout.
+  pub(crate) fn clear(self) {
+    self.handle.clear();
+    self.guard.set(None);
+  }
+}
+
+/// Elements which can receive focus with the keyboard.
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), \
+                         input:not([disabled]), select:not([disabled]), \
+                         textarea:not([disabled]), \
+                         [tabindex]:not([tabindex=\"-1\"])";
+
+/// Keeps focus within `container` when `ev` is a <kbd>Tab</kbd> press, by
+/// moving focus to it's first focusable element when tabbing past the last
+/// one, and vice versa.
+pub(crate) fn wrap_focus(
+  ev: &web_sys::KeyboardEvent,
+  container: &web_sys::Element,
+) {
+  use wasm_bindgen::JsCast;
+
+  if ev.key() != "Tab" {
+    return;
+  }
+
+  let Ok(focusable) = container.query_selector_all(FOCUSABLE) else {
+    return;
+  };
+
+  if focusable.length() == 0 {
+    ev.prevent_default();
+
+    return;
+  }
+
+  let first = focusable.get(0);
+  let last = focusable.get(focusable.length() - 1);
+
+  let (edge, target) = if ev.shift_key() {
+    (first, last)
+  } else {
+    (last, first)
+  };
+
+  let active = active_element().map(web_sys::Node::from);
+
+  let container: &web_sys::Node = container;
+
+  if active == edge || active.as_ref() == Some(container) {
+    ev.prevent_default();
+
+    if let Some(target) =
+      target.and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+      let _ = target.focus();
+    }
+  }
+}
+
+/// Moves focus to the first focusable element within `container`, or to
+/// `container` itself if there is none.
+pub(crate) fn focus_first(container: &web_sys::HtmlElement) {
+  use wasm_bindgen::JsCast;
+
+  let first = container
+    .query_selector(FOCUSABLE)
+    .ok()
+    .flatten()
+    .and_then(|first| first.dyn_into::<web_sys::HtmlElement>().ok());
+
+  let _ = first.as_ref().unwrap_or(container).focus();
+}
+
+/// Returns the element which currently has focus, if any.
+pub(crate) fn active_element() -> Option<web_sys::HtmlElement> {
+  use wasm_bindgen::JsCast;
+
+  try_window()?.document()?.active_element()?.dyn_into().ok()
+}
@@ -0,0 +1,469 @@
+//! Virtualized lists for [`leptos`].
+//!
+//! Please refer to [`VirtualFor`] for usage examples.
+
+use crate::{
+  element_size::use_element_size,
+  error::{
+    report,
+    Error,
+  },
+  for_each::{
+    EmptyBlock,
+    ItemBlock,
+  },
+};
+use leptos::{
+  html::Div,
+  *,
+};
+use leptos_dom::Transparent;
+use std::{
+  hash::Hash,
+  ops::Range,
+  rc::Rc,
+};
+
+api_planning! {
+  view! { cx,
+    <VirtualFor each=rows key=|row| row.id row_height=32.0 overscan=5 class="table">
+      <Item view=|cx, row: Row| view! { cx, <RowView row /> } />
+      <Empty>"No rows"</Empty>
+    </VirtualFor>
+  }
+}
+
+/// Renders a keyed list which only mounts the rows which are visible within
+/// it's scroll container, so lists with many thousands of rows stay fast.
+///
+/// The list is rendered within a scrollable `div`, which must be given a
+/// height, such as with `class`. Rows are positioned absolutely within it,
+/// based on their `row_height`, and `overscan` extra rows are mounted above
+/// and below the visible ones, so fast scrolling doesn't flash empty space.
+///
+/// Each row is rendered with the `view` of the [`Item`](crate::for_each::Item)
+/// child, and the [`Empty`](crate::for_each::Empty) arm is shown while there
+/// are no rows.
+///
+/// Bind `scroll_top` to read or set the scroll position, and `node_ref` to
+/// access the scroll container itself.
+///
+/// For more docs on allowed child components, check out
+/// [`VirtualForProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (rows, _) = create_signal(cx, (0..10_000).collect::<Vec<usize>>());
+///
+/// view! { cx,
+/// <VirtualFor each=rows key=|row| *row row_height=32.0 class="h-96">
+///   <Item view=|cx, row: usize| view! { cx, <p>"Row "{row}</p> } />
+///   <Empty>"No rows"</Empty>
+/// </VirtualFor>
+/// };
+/// # });
+/// ```
+///
+/// ### Variable row heights
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+/// use std::rc::Rc;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (messages, _) = create_signal(cx, vec!["Hi".to_string()]);
+///
+/// let row_height = RowHeight::Variable(Rc::new(|message: &String| {
+///   if message.len() > 80 { 64.0 } else { 32.0 }
+/// }));
+///
+/// view! { cx,
+/// <VirtualFor each=messages key=|message| message.clone() row_height class="h-96">
+///   <Item view=|cx, message: String| view! { cx, <p>{message}</p> } />
+/// </VirtualFor>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn VirtualFor<T, KF, K>(
+  cx: Scope,
+  /// The items to render.
+  #[prop(into)]
+  each: Signal<Vec<T>>,
+  /// Returns a key which uniquely identifies an item, so rows are only
+  /// re-rendered when they are added or move.
+  key: KF,
+  /// The height of the rows, in CSS pixels.
+  #[prop(into)]
+  row_height: RowHeight<T>,
+  /// The number of rows mounted above and below the visible ones. Defaults
+  /// to `3`.
+  #[prop(default = 3)]
+  overscan: usize,
+  /// The `class` of the scroll container.
+  #[prop(optional, into)]
+  class: Option<String>,
+  /// Kept in sync with the scroll position of the container. Setting it
+  /// scrolls the container.
+  #[prop(optional)]
+  scroll_top: Option<RwSignal<f64>>,
+  /// The scroll container.
+  #[prop(optional)]
+  node_ref: Option<NodeRef<Div>>,
+  /// The arms of the list.
+  ///
+  /// Children must be any
+  /// - [`Item`](crate::for_each::Item)
+  /// - [`Empty`](crate::for_each::Empty)
+  ///
+  /// Any other child not in the above list will not be rendered.
+  ///
+  /// [`Item`](crate::for_each::Item) must be present.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView
+where
+  KF: Fn(&T) -> K + 'static,
+  K: Eq + Hash + 'static,
+  T: Clone + 'static,
+{
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let item = blocks
+    .iter()
+    .find_map(Transparent::downcast_ref::<ItemBlock<T>>)
+    .map(|block| block.view.clone());
+
+  if item.is_none() {
+    report(Error::MissingItem);
+  }
+
+  let container = node_ref.unwrap_or_else(|| create_node_ref::<Div>(cx));
+  let scroll = scroll_top.unwrap_or_else(|| create_rw_signal(cx, 0.0));
+  let viewport = use_element_size(cx, container);
+  let row_height = store_value(cx, row_height);
+  let key = Rc::new(key);
+
+  // The top of each row, followed by the total height, which is only needed
+  // when rows vary in height
+  let offsets = create_memo(cx, move |_| {
+    row_height
+      .with_value(|row_height| each.with(|items| row_height.offsets(items)))
+  });
+
+  // The rows which are mounted
+  let window = create_memo(cx, move |_| {
+    let len = each.with(Vec::len);
+    let top = scroll.get();
+    let bottom = top + viewport.get().height;
+
+    offsets.with(|offsets| {
+      row_height.with_value(|row_height| {
+        row_height.window(offsets, len, top..bottom, overscan)
+      })
+    })
+  });
+
+  let offset_of = move |i: usize| {
+    offsets.with(|offsets| {
+      row_height.with_value(|row_height| row_height.offset(offsets, i))
+    })
+  };
+
+  let rows = move || {
+    let Range { start, end } = window.get();
+
+    each.with(|items| {
+      let end = end.min(items.len());
+      let start = start.min(end);
+
+      items[start..end].to_vec()
+    })
+  };
+
+  // The index of the row with the given key, which is kept while the row is
+  // about to be removed
+  let index_of = {
+    let key = key.clone();
+
+    move |cx: Scope, row_key: K| {
+      let key = key.clone();
+
+      create_memo(cx, move |prev: Option<&usize>| {
+        let Range { start, end } = window.get();
+
+        each
+          .with(|items| {
+            let end = end.min(items.len());
+            let start = start.min(end);
+
+            items[start..end]
+              .iter()
+              .position(|item| key(item) == row_key)
+              .map(|pos| start + pos)
+          })
+          .or(prev.copied())
+          .unwrap_or_default()
+      })
+    }
+  };
+
+  let is_empty = create_memo(cx, move |_| each.with(Vec::is_empty));
+
+  let on_scroll = move |_| {
+    if let Some(container) = container.get() {
+      scroll.set(container.scroll_top().into());
+    }
+  };
+
+  // Scrolls the container when `scroll_top` is set from outside
+  create_effect(cx, move |_| {
+    let top = scroll.get();
+
+    if let Some(container) = container.get() {
+      if (f64::from(container.scroll_top()) - top).abs() >= 1.0 {
+        container.set_scroll_top(top as i32);
+      }
+    }
+  });
+
+  let content = move || {
+    if is_empty.get() {
+      return blocks
+        .iter()
+        .find_map(Transparent::downcast_ref::<EmptyBlock>)
+        .map(|block| (block.children)(cx).into_view(cx))
+        .unwrap_or_else(|| ().into_view(cx));
+    }
+
+    let Some(item) = item.clone() else {
+      return ().into_view(cx);
+    };
+
+    let key = key.clone();
+    let row_key = key.clone();
+    let index_of = index_of.clone();
+
+    // Rows are only keyed by their key, so they are kept when rows are added
+    // or removed above them, and their index is looked up as the list changes
+    view! { cx,
+      <div style=move || {
+        format!("position: relative; height: {}px", offset_of(each.with(Vec::len)))
+      }>
+        <For
+          each=rows
+          key=move |data: &T| key(data)
+          view=move |cx, data| {
+            let index = index_of(cx, row_key(&data));
+
+            view! { cx,
+              <div style=move || {
+                let i = index.get();
+                let top = offset_of(i);
+                let height = offset_of(i + 1) - top;
+
+                format!(
+                  "position: absolute; top: {top}px; left: 0; right: 0; \
+                   height: {height}px"
+                )
+              }>
+                {item(cx, data)}
+              </div>
+            }
+          }
+        />
+      </div>
+    }
+    .into_view(cx)
+  };
+
+  view! { cx,
+    <div
+      class=class
+      style="overflow-y: auto; position: relative"
+      node_ref=container
+      on:scroll=on_scroll
+    >
+      {content}
+    </div>
+  }
+}
+
+/// The height of the rows of a [`VirtualFor`], in CSS pixels.
+pub enum RowHeight<T> {
+  /// Every row is this tall.
+  Fixed(f64),
+  /// Each row is as tall as this returns for it's item.
+  Variable(Rc<dyn Fn(&T) -> f64>),
+}
+
+impl<T> Clone for RowHeight<T> {
+  fn clone(&self) -> Self {
+    match self {
+      Self::Fixed(height) => Self::Fixed(*height),
+      Self::Variable(height) => Self::Variable(height.clone()),
+    }
+  }
+}
+
+impl<T> From<f64> for RowHeight<T> {
+  fn from(height: f64) -> Self {
+    Self::Fixed(height)
+  }
+}
+
+impl<T> RowHeight<T> {
+  /// The top of each row of `items`, followed by the total height, which is
+  /// only needed when rows vary in height.
+  fn offsets(&self, items: &[T]) -> Vec<f64> {
+    let Self::Variable(height) = self else {
+      return Vec::new();
+    };
+
+    let mut offsets = Vec::with_capacity(items.len() + 1);
+    let mut offset = 0.0;
+
+    offsets.push(offset);
+
+    for item in items {
+      offset += height(item);
+
+      offsets.push(offset);
+    }
+
+    offsets
+  }
+
+  /// The rows of a list of `len` rows which should be mounted while
+  /// `viewport` is visible, along with `overscan` rows on either side.
+  fn window(
+    &self,
+    offsets: &[f64],
+    len: usize,
+    viewport: Range<f64>,
+    overscan: usize,
+  ) -> Range<usize> {
+    let start = self
+      .index_at(offsets, viewport.start)
+      .saturating_sub(overscan);
+    let end = (self.index_at(offsets, viewport.end) + 1 + overscan).min(len);
+
+    start.min(end)..end
+  }
+
+  /// The top of row `i`, given the `offsets` of variable rows.
+  fn offset(&self, offsets: &[f64], i: usize) -> f64 {
+    match self {
+      Self::Fixed(height) => i as f64 * height,
+      Self::Variable(_) => offsets
+        .get(i)
+        .or_else(|| offsets.last())
+        .copied()
+        .unwrap_or_default(),
+    }
+  }
+
+  /// The index of the row at `y`, given the `offsets` of variable rows.
+  fn index_at(&self, offsets: &[f64], y: f64) -> usize {
+    match self {
+      Self::Fixed(height) if *height > 0.0 => (y / height).max(0.0) as usize,
+      Self::Fixed(_) => 0,
+      Self::Variable(_) => offsets
+        .partition_point(|offset| *offset <= y)
+        .saturating_sub(1),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn variable(heights: &[f64]) -> (RowHeight<f64>, Vec<f64>) {
+    let row_height = RowHeight::Variable(Rc::new(|height: &f64| *height));
+    let offsets = row_height.offsets(heights);
+
+    (row_height, offsets)
+  }
+
+  #[test]
+  fn fixed_offsets() {
+    let row_height = RowHeight::<()>::Fixed(20.0);
+
+    assert_eq!(row_height.offsets(&[(), ()]), Vec::<f64>::new());
+    assert_eq!(row_height.offset(&[], 0), 0.0);
+    assert_eq!(row_height.offset(&[], 3), 60.0);
+    assert_eq!(row_height.index_at(&[], 0.0), 0);
+    assert_eq!(row_height.index_at(&[], 45.0), 2);
+    assert_eq!(row_height.index_at(&[], 60.0), 3);
+    assert_eq!(row_height.index_at(&[], -5.0), 0);
+  }
+
+  #[test]
+  fn zero_fixed_height() {
+    let row_height = RowHeight::<()>::Fixed(0.0);
+
+    assert_eq!(row_height.offset(&[], 5), 0.0);
+    assert_eq!(row_height.index_at(&[], 100.0), 0);
+    assert_eq!(row_height.window(&[], 5, 0.0..100.0, 1), 0..2);
+  }
+
+  #[test]
+  fn variable_offsets() {
+    let (row_height, offsets) = variable(&[10.0, 30.0, 0.0, 20.0]);
+
+    assert_eq!(offsets, [0.0, 10.0, 40.0, 40.0, 60.0]);
+    assert_eq!(row_height.offset(&offsets, 2), 40.0);
+    // Past the end, rows are below the last one
+    assert_eq!(row_height.offset(&offsets, 10), 60.0);
+    assert_eq!(row_height.index_at(&offsets, 0.0), 0);
+    assert_eq!(row_height.index_at(&offsets, 10.0), 1);
+    assert_eq!(row_height.index_at(&offsets, 39.9), 1);
+    // The row without height is skipped
+    assert_eq!(row_height.index_at(&offsets, 40.0), 3);
+    assert_eq!(row_height.index_at(&offsets, 100.0), 4);
+    assert_eq!(row_height.window(&offsets, 4, 10.0..40.0, 0), 1..4);
+  }
+
+  #[test]
+  fn empty_list() {
+    let (row_height, offsets) = variable(&[]);
+
+    assert_eq!(offsets, [0.0]);
+    assert_eq!(row_height.offset(&offsets, 5), 0.0);
+    assert_eq!(row_height.index_at(&offsets, 50.0), 0);
+    assert_eq!(row_height.window(&offsets, 0, 0.0..100.0, 2), 0..0);
+    assert_eq!(
+      RowHeight::<()>::Fixed(20.0).window(&[], 0, 0.0..100.0, 2),
+      0..0
+    );
+  }
+
+  #[test]
+  fn window_with_overscan() {
+    let row_height = RowHeight::<()>::Fixed(20.0);
+
+    assert_eq!(row_height.window(&[], 100, 0.0..50.0, 0), 0..3);
+    assert_eq!(row_height.window(&[], 100, 100.0..200.0, 2), 3..13);
+    // Overscan never goes past either end
+    assert_eq!(row_height.window(&[], 100, 0.0..50.0, 5), 0..8);
+    assert_eq!(row_height.window(&[], 12, 100.0..200.0, 5), 0..12);
+  }
+
+  #[test]
+  fn window_scrolled_past_the_end() {
+    let row_height = RowHeight::<()>::Fixed(20.0);
+
+    assert_eq!(row_height.window(&[], 10, 1000.0..1100.0, 2), 10..10);
+  }
+}
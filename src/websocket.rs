@@ -0,0 +1,393 @@
+//! Declarative WebSocket connections for [`leptos`].
+//!
+//! Please refer to [`WebSocketProvider`] for usage examples.
+
+use crate::{
+  error::{
+    expect_context,
+    Error,
+  },
+  util::{
+    set_timeout,
+    try_window,
+    Timeout,
+  },
+};
+use leptos::*;
+use serde::{
+  de::DeserializeOwned,
+  Serialize,
+};
+use std::{
+  rc::Rc,
+  time::Duration,
+};
+use wasm_bindgen::{
+  prelude::Closure,
+  JsCast,
+  JsValue,
+};
+
+api_planning! {
+  view! { cx,
+    <WebSocketProvider url="wss://example.com/live">
+      <Connected><LiveScores /></Connected>
+      <Reconnecting>"Connection lost, reconnecting..."</Reconnecting>
+      <Disconnected>"Offline"</Disconnected>
+    </WebSocketProvider>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<WebSocketProvider />",
+  context: "WebSocketHandle",
+};
+
+/// The state of the connection of a [`WebSocketProvider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SocketState {
+  /// The first connection is being opened. This is also the state on the
+  /// server, where no connection is made.
+  Connecting,
+  /// The connection is open.
+  Connected,
+  /// The connection was lost, and is being reopened.
+  Reconnecting,
+  /// The connection was lost, and won't be reopened.
+  Disconnected,
+}
+
+/// Connects to the WebSocket at `url` for as long as it's mounted, and
+/// provides a [`WebSocketHandle`] to it's children, which receives and sends
+/// messages.
+///
+/// When the connection is lost, it's reopened after `backoff`, waiting twice
+/// as long before each following attempt, up to 30 seconds. The
+/// [`Connected`], [`Reconnecting`] and [`Disconnected`] components below it
+/// render their children depending on the state of the connection.
+///
+/// No connection is made on the server.
+///
+/// This component is only available with the `websocket` feature.
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn Scores(cx: Scope) -> impl IntoView {
+///   let socket = use_web_socket(cx);
+///   let score = socket.message::<u32>(cx);
+///
+///   view! { cx,
+///     <p>"Score: "{move || score.get()}</p>
+///     <button on:click=move |_| socket.send(&"refresh")>"Refresh"</button>
+///   }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+///
+/// view! { cx,
+/// <WebSocketProvider url="wss://example.com/live">
+///   <Connected><Scores /></Connected>
+///   <Reconnecting>"Connection lost, reconnecting..."</Reconnecting>
+///   <Disconnected>"Offline"</Disconnected>
+/// </WebSocketProvider>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn WebSocketProvider(
+  cx: Scope,
+  /// The URL to connect to.
+  #[prop(into)]
+  url: String,
+  /// How long to wait before the first reconnection attempt. Defaults to 1
+  /// second.
+  #[prop(optional)]
+  backoff: Option<Duration>,
+  /// How many reconnection attempts are made before giving up. Defaults to
+  /// retrying forever.
+  #[prop(optional)]
+  max_retries: Option<u32>,
+  /// The part of your app which uses the connection.
+  children: Children,
+) -> impl IntoView {
+  let handle = WebSocketHandle {
+    state: create_rw_signal(cx, SocketState::Connecting),
+    message: create_rw_signal(cx, None),
+    socket: store_value(cx, None),
+  };
+
+  let connection = Connection {
+    cx,
+    url: url.into(),
+    handle,
+    backoff: backoff.unwrap_or(Duration::from_secs(1)),
+    max_retries,
+    retries: store_value(cx, 0),
+    timeout: store_value(cx, None),
+    disposed: store_value(cx, false),
+  };
+
+  create_effect(cx, {
+    let connection = connection.clone();
+
+    move |_| {
+      if try_window().is_some() {
+        connection.connect();
+      }
+    }
+  });
+
+  on_cleanup(cx, move || connection.dispose());
+
+  provide_context(cx, handle);
+
+  children(cx)
+}
+
+/// Renders it's children while the connection of the nearest
+/// [`WebSocketProvider`] is open.
+///
+/// # Panics
+/// Panics if there is no [`WebSocketProvider`] above.
+#[component]
+pub fn Connected(
+  cx: Scope,
+  /// What you want to render while connected.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  render_in_state(cx, SocketState::Connected, children)
+}
+
+/// Renders it's children while the connection of the nearest
+/// [`WebSocketProvider`] is being reopened.
+///
+/// # Panics
+/// Panics if there is no [`WebSocketProvider`] above.
+#[component]
+pub fn Reconnecting(
+  cx: Scope,
+  /// What you want to render while reconnecting.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  render_in_state(cx, SocketState::Reconnecting, children)
+}
+
+/// Renders it's children once the nearest [`WebSocketProvider`] gave up on
+/// reconnecting.
+///
+/// # Panics
+/// Panics if there is no [`WebSocketProvider`] above.
+#[component]
+pub fn Disconnected(
+  cx: Scope,
+  /// What you want to render while disconnected.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  render_in_state(cx, SocketState::Disconnected, children)
+}
+
+fn render_in_state(
+  cx: Scope,
+  state: SocketState,
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let socket = use_web_socket(cx);
+  let is_in_state = create_memo(cx, move |_| socket.state.get() == state);
+
+  move || {
+    if is_in_state.get() {
+      children(cx).into_view(cx)
+    } else {
+      ().into_view(cx)
+    }
+  }
+}
+
+/// Receives and sends the messages of a [`WebSocketProvider`].
+#[derive(Clone, Copy)]
+pub struct WebSocketHandle {
+  state: RwSignal<SocketState>,
+  message: RwSignal<Option<String>>,
+  socket: StoredValue<Option<Socket>>,
+}
+
+impl WebSocketHandle {
+  /// Returns the state of the connection.
+  pub fn state(&self) -> Signal<SocketState> {
+    self.state.into()
+  }
+
+  /// Returns the latest text message received, if any.
+  pub fn text(&self) -> Signal<Option<String>> {
+    self.message.into()
+  }
+
+  /// Returns the latest text message received, deserialized from JSON.
+  ///
+  /// Messages which fail to deserialize are ignored, so the previous message
+  /// is kept.
+  pub fn message<T>(&self, cx: Scope) -> Signal<Option<T>>
+  where
+    T: DeserializeOwned + Clone + PartialEq + 'static,
+  {
+    let message = self.message;
+
+    create_memo(cx, move |previous: Option<&Option<T>>| {
+      message
+        .with(|message| serde_json::from_str(message.as_deref()?).ok())
+        .or_else(|| previous.cloned().flatten())
+    })
+    .into()
+  }
+
+  /// Sends a text message.
+  ///
+  /// Messages sent while not connected are dropped.
+  pub fn send_text(&self, text: &str) {
+    self.socket.with_value(|socket| {
+      if let Some(socket) = socket {
+        let _ = socket.socket.send_with_str(text);
+      }
+    });
+  }
+
+  /// Sends `message` serialized as JSON.
+  ///
+  /// Messages sent while not connected are dropped.
+  pub fn send<T: Serialize + ?Sized>(&self, message: &T) {
+    if let Ok(json) = serde_json::to_string(message) {
+      self.send_text(&json);
+    }
+  }
+}
+
+/// Gets the [`WebSocketHandle`] of the nearest [`WebSocketProvider`].
+///
+/// # Panics
+/// Panics if there is no [`WebSocketProvider`] above.
+pub fn use_web_socket(cx: Scope) -> WebSocketHandle {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
+
+/// An open WebSocket, along with it's event handlers.
+struct Socket {
+  socket: web_sys::WebSocket,
+  _handlers: [Closure<dyn Fn(JsValue)>; 3],
+}
+
+impl Drop for Socket {
+  fn drop(&mut self) {
+    self.socket.set_onopen(None);
+    self.socket.set_onmessage(None);
+    self.socket.set_onclose(None);
+
+    let _ = self.socket.close();
+  }
+}
+
+/// Opens and reopens the connection of a [`WebSocketProvider`].
+#[derive(Clone)]
+struct Connection {
+  cx: Scope,
+  url: Rc<str>,
+  handle: WebSocketHandle,
+  backoff: Duration,
+  max_retries: Option<u32>,
+  retries: StoredValue<u32>,
+  timeout: StoredValue<Option<Timeout>>,
+  /// Set once the provider is cleaned up, so the connection isn't reopened.
+  disposed: StoredValue<bool>,
+}
+
+impl Connection {
+  fn connect(&self) {
+    if self.disposed.get_value() {
+      return;
+    }
+
+    let Ok(socket) = web_sys::WebSocket::new(&self.url) else {
+      self.reconnect();
+
+      return;
+    };
+
+    let state = self.handle.state;
+    let message = self.handle.message;
+    let retries = self.retries;
+
+    let on_open = Closure::<dyn Fn(JsValue)>::new(move |_| {
+      retries.set_value(0);
+      state.set(SocketState::Connected);
+    });
+
+    let on_message = Closure::<dyn Fn(JsValue)>::new(move |ev: JsValue| {
+      let ev = ev.unchecked_into::<web_sys::MessageEvent>();
+
+      if let Some(text) = ev.data().as_string() {
+        message.set(Some(text));
+      }
+    });
+
+    let on_close = Closure::<dyn Fn(JsValue)>::new({
+      let this = self.clone();
+
+      // The socket isn't dropped here, as that would drop this closure
+      // while it's running
+      move |_| this.reconnect()
+    });
+
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+    self.handle.socket.set_value(Some(Socket {
+      socket,
+      _handlers: [on_open, on_message, on_close],
+    }));
+  }
+
+  /// Reopens the connection after the backoff, unless out of retries.
+  fn reconnect(&self) {
+    if self.disposed.get_value() {
+      return;
+    }
+
+    let retry = self.retries.get_value();
+
+    if self.max_retries.map_or(false, |max| retry >= max) {
+      self.handle.state.set(SocketState::Disconnected);
+
+      return;
+    }
+
+    self.retries.set_value(retry + 1);
+    self.handle.state.set(SocketState::Reconnecting);
+
+    let delay = self
+      .backoff
+      .saturating_mul(2u32.saturating_pow(retry))
+      .min(Duration::from_secs(30));
+
+    let this = self.clone();
+
+    self
+      .timeout
+      .set_value(set_timeout(self.cx, move || this.connect(), delay));
+  }
+
+  fn dispose(&self) {
+    self.disposed.set_value(true);
+
+    self.timeout.update_value(|timeout| {
+      if let Some(timeout) = timeout.take() {
+        timeout.clear();
+      }
+    });
+
+    self.handle.socket.set_value(None);
+  }
+}
@@ -0,0 +1,62 @@
+//! Memoizing derived values within views for [`leptos`].
+//!
+//! Please refer to [`WithMemo`] for usage examples.
+
+use leptos::*;
+
+api_planning! {
+  view! { cx,
+    <WithMemo
+      value=move || expensive(a.get(), b.get())
+      view=|cx, v: Memo<Total>| view! { cx, <p>{move || v.get().to_string()}</p> }
+    />
+  }
+}
+
+/// Memoizes `value` and passes it to `view`, saving you from calling
+/// [`create_memo`] in the component body just to use it in a view.
+///
+/// `value` is only re-run when the signals it reads change, and whatever
+/// reads the memo is only notified when the result changes. `view` is only
+/// called once, so it should read the memo reactively, such as with an
+/// [`If`](crate::if_::If).
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (items, _) = create_signal(cx, vec![1, 2, 3]);
+///
+/// view! { cx,
+/// <WithMemo
+///   value=move || items.with(|items| items.iter().sum::<i32>())
+///   view=|cx, total: Memo<i32>| view! { cx,
+///     <p>"Total: "{move || total.get()}</p>
+///     <If signal=Signal::derive(cx, move || total.get() > 100)>
+///       <Then>"That's a lot!"</Then>
+///     </If>
+///   }
+/// />
+/// };
+/// # });
+/// ```
+#[component]
+pub fn WithMemo<V, T, F, IV>(
+  cx: Scope,
+  /// Derives the memoized value. This should be reactive.
+  value: V,
+  /// Renders the content, given the memoized value.
+  view: F,
+) -> impl IntoView
+where
+  V: Fn() -> T + 'static,
+  T: PartialEq + 'static,
+  F: FnOnce(Scope, Memo<T>) -> IV,
+  IV: IntoView,
+{
+  let memo = create_memo(cx, move |_| value());
+
+  view(cx, memo)
+}
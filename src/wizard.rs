@@ -0,0 +1,372 @@
+//! Multi-step flows for [`leptos`].
+//!
+//! Please refer to [`Wizard`] for usage examples.
+
+use crate::error::{
+  expect_context,
+  Error,
+};
+use leptos::*;
+use leptos_dom::Transparent;
+
+api_planning! {
+  view! { cx,
+    <Wizard current=step>
+      <Progress view=|cx, wizard: WizardHandle| view! { cx,
+        <p>"Step "{move || wizard.current() + 1}" of "{move || wizard.len()}</p>
+      } />
+
+      <Step title="Account" valid=account_valid><AccountForm /></Step>
+      <Step title="Address"><AddressForm /></Step>
+      <Step title="Confirm"><Summary /></Step>
+
+      <WizardNav />
+    </Wizard>
+  }
+}
+
+const CONTEXT_NOT_FOUND_ERROR: Error = Error::MissingProvider {
+  provider: "<Wizard />",
+  context: "WizardHandle",
+};
+
+/// Renders one of it's [`Step`]s at a time, in order, like a stepper.
+///
+/// The [`WizardHandle`] returned by [`use_wizard`] within the wizard, and
+/// passed to [`Progress`], moves between the steps. A step can block moving
+/// past it until it's `valid`, such as while it's form has errors.
+///
+/// Children which aren't steps are always rendered, such as navigation
+/// buttons, and the current step is rendered where the first step is.
+///
+/// For more docs on allowed child components, check out
+/// [`WizardProps::children`].
+///
+/// # Examples
+/// ```rust
+/// use leptos::*;
+/// use leptos_declarative::prelude::*;
+///
+/// #[component]
+/// fn WizardNav(cx: Scope) -> impl IntoView {
+///   let wizard = use_wizard(cx);
+///
+///   view! { cx,
+///     <button on:click=move |_| wizard.prev()>"Back"</button>
+///     <button
+///       on:click=move |_| wizard.next()
+///       disabled=move || !wizard.can_next()
+///     >
+///       "Next"
+///     </button>
+///   }
+/// }
+///
+/// # let _ = create_scope(create_runtime(), |cx| {
+/// let (name, set_name) = create_signal(cx, String::new());
+///
+/// view! { cx,
+/// <Wizard>
+///   <Progress view=|cx, wizard: WizardHandle| view! { cx,
+///     <p>"Step "{move || wizard.current() + 1}" of "{move || wizard.len()}</p>
+///   } />
+///
+///   <Step
+///     title="Name"
+///     valid=Signal::derive(cx, move || !name.with(String::is_empty))
+///   >
+///     <input on:input=move |ev| set_name(event_target_value(&ev)) />
+///   </Step>
+///   <Step title="Confirm">
+///     <p>"Hello, "{move || name.get()}</p>
+///   </Step>
+///
+///   <WizardNav />
+/// </Wizard>
+/// };
+/// # });
+/// ```
+#[component]
+pub fn Wizard(
+  cx: Scope,
+  /// Binds the index of the current step to this signal. Defaults to
+  /// starting at the first step.
+  #[prop(optional)]
+  current: Option<RwSignal<usize>>,
+  /// The steps of the wizard.
+  ///
+  /// Arms can be any
+  /// - [`Step`]
+  /// - [`Progress`]
+  ///
+  /// Any other child is always rendered.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let wizard = WizardHandle {
+    current: current.unwrap_or_else(|| create_rw_signal(cx, 0)),
+    steps: create_rw_signal(cx, Vec::new()),
+  };
+
+  provide_context(cx, wizard);
+
+  let children = children(cx);
+
+  let blocks = children
+    .as_children()
+    .iter()
+    .filter_map(View::as_transparent)
+    .cloned()
+    .collect::<Vec<_>>();
+
+  // The steps are only known once the children are rendered, so anything
+  // which read them while rendering is notified now
+  wizard.steps.set(
+    blocks
+      .iter()
+      .filter_map(Transparent::downcast_ref::<StepBlock>)
+      .map(|block| StepState {
+        title: block.title.clone(),
+        valid: block.valid.clone(),
+      })
+      .collect(),
+  );
+
+  let current = create_memo(cx, move |_| wizard.current());
+
+  let mut rendered_steps = false;
+
+  children
+    .as_children()
+    .iter()
+    .cloned()
+    .filter_map(|child| {
+      let Some(block) = child.as_transparent() else {
+        return Some(child);
+      };
+
+      if let Some(block) = block.downcast_ref::<ProgressBlock>() {
+        return Some((block.view)(cx, wizard));
+      }
+
+      if block.downcast_ref::<StepBlock>().is_none() {
+        return Some(child);
+      }
+
+      if std::mem::replace(&mut rendered_steps, true) {
+        return None;
+      }
+
+      let blocks = blocks.clone();
+
+      Some(
+        (move || {
+          #[cfg(feature = "performance")]
+          let _measure = crate::performance::measure_switch(cx);
+
+          blocks
+            .iter()
+            .filter_map(Transparent::downcast_ref::<StepBlock>)
+            .nth(current.get())
+            .map(|block| (block.children)(cx).into_view(cx))
+            .unwrap_or_else(|| ().into_view(cx))
+        })
+        .into_view(cx),
+      )
+    })
+    .collect::<Vec<_>>()
+}
+
+/// A step of the parent [`Wizard`].
+#[component(transparent)]
+pub fn Step(
+  cx: Scope,
+  /// The title of the step, which can be read with
+  /// [`WizardHandle::title`].
+  #[prop(optional, into)]
+  title: Option<String>,
+  /// Whether the wizard can move past this step. Defaults to `true`.
+  #[prop(into, default = true.into())]
+  valid: MaybeSignal<bool>,
+  /// What you want to show while this is the current step.
+  children: Box<dyn Fn(Scope) -> Fragment>,
+) -> impl IntoView {
+  let _ = cx;
+
+  StepBlock {
+    title,
+    valid,
+    children,
+  }
+}
+
+/// Renders the progress of the parent [`Wizard`], wherever it's placed among
+/// it's steps.
+#[component(transparent)]
+pub fn Progress<F, IV>(
+  cx: Scope,
+  /// Renders the progress, given the wizard. This is only called once, so
+  /// it should read the wizard reactively.
+  view: F,
+) -> impl IntoView
+where
+  F: Fn(Scope, WizardHandle) -> IV + 'static,
+  IV: IntoView,
+{
+  let _ = cx;
+
+  ProgressBlock {
+    view: Box::new(move |cx, wizard| view(cx, wizard).into_view(cx)),
+  }
+}
+
+transparent_block! {
+  /// The step arm returned by [`Step`].
+  pub struct StepBlock {
+    title: Option<String>,
+    valid: MaybeSignal<bool>,
+    children: Box<dyn Fn(Scope) -> Fragment>,
+  }
+}
+
+transparent_block! {
+  /// The progress arm returned by [`Progress`].
+  pub struct ProgressBlock {
+    view: Box<dyn Fn(Scope, WizardHandle) -> View>,
+  }
+}
+
+struct StepState {
+  title: Option<String>,
+  valid: MaybeSignal<bool>,
+}
+
+/// Moves between the steps of a [`Wizard`], returned by [`use_wizard`].
+#[derive(Clone, Copy)]
+pub struct WizardHandle {
+  current: RwSignal<usize>,
+  steps: RwSignal<Vec<StepState>>,
+}
+
+impl WizardHandle {
+  /// The index of the current step.
+  ///
+  /// This is reactive.
+  pub fn current(&self) -> usize {
+    self.current.get().min(self.len().saturating_sub(1))
+  }
+
+  /// The number of steps.
+  ///
+  /// This is reactive.
+  pub fn len(&self) -> usize {
+    self.steps.with(Vec::len)
+  }
+
+  /// Whether the wizard has no steps.
+  ///
+  /// This is reactive.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// The title of the step at `index`, if it has one.
+  ///
+  /// This is reactive.
+  pub fn title(&self, index: usize) -> Option<String> {
+    self.steps.with(|steps| steps.get(index)?.title.clone())
+  }
+
+  /// Whether the current step is the first one.
+  ///
+  /// This is reactive.
+  pub fn is_first(&self) -> bool {
+    self.current() == 0
+  }
+
+  /// Whether the current step is the last one.
+  ///
+  /// This is reactive.
+  pub fn is_last(&self) -> bool {
+    self.current() + 1 >= self.len()
+  }
+
+  /// Whether the step at `index` is valid.
+  ///
+  /// This is reactive.
+  pub fn is_valid(&self, index: usize) -> bool {
+    self
+      .steps
+      .with(|steps| steps.get(index).map_or(false, |step| step.valid.get()))
+  }
+
+  /// Whether [`WizardHandle::next`] would move to the next step.
+  ///
+  /// This is reactive.
+  pub fn can_next(&self) -> bool {
+    !self.is_last() && self.is_valid(self.current())
+  }
+
+  /// Moves to the next step, unless the current step is the last one, or
+  /// isn't valid.
+  pub fn next(&self) {
+    let current = self.current_untracked();
+
+    if current + 1 < self.len_untracked() && self.is_valid_untracked(current) {
+      self.current.set(current + 1);
+    }
+  }
+
+  /// Moves to the previous step, unless the current step is the first one.
+  pub fn prev(&self) {
+    let current = self.current_untracked();
+
+    if current > 0 {
+      self.current.set(current - 1);
+    }
+  }
+
+  /// Moves to the step at `index`.
+  ///
+  /// Moving back is always possible, while moving forward is only possible
+  /// if every step before `index` is valid.
+  pub fn goto(&self, index: usize) {
+    if index >= self.len_untracked() {
+      return;
+    }
+
+    let current = self.current_untracked();
+
+    if index <= current
+      || (current..index).all(|step| self.is_valid_untracked(step))
+    {
+      self.current.set(index);
+    }
+  }
+
+  fn current_untracked(&self) -> usize {
+    self
+      .current
+      .get_untracked()
+      .min(self.len_untracked().saturating_sub(1))
+  }
+
+  fn len_untracked(&self) -> usize {
+    self.steps.with_untracked(Vec::len)
+  }
+
+  fn is_valid_untracked(&self, index: usize) -> bool {
+    self.steps.with_untracked(|steps| {
+      steps
+        .get(index)
+        .map_or(false, |step| step.valid.get_untracked())
+    })
+  }
+}
+
+/// Gets the nearest [`Wizard`].
+///
+/// # Panics
+/// Panics if there is no [`Wizard`] above.
+pub fn use_wizard(cx: Scope) -> WizardHandle {
+  expect_context(cx, CONTEXT_NOT_FOUND_ERROR)
+}
@@ -0,0 +1,117 @@
+//! Renders a portal on the server and hydrates the result in the browser.
+//!
+//! Both halves share the server markup in `tests/fixtures`. The server half
+//! checks that it's up to date, and rewrites it when `UPDATE_FIXTURES` is
+//! set, such as after upgrading `leptos`:
+//! `UPDATE_FIXTURES=1 cargo test --features ssr --test portal_hydration`.
+
+use leptos::*;
+use leptos_declarative::prelude::*;
+
+#[derive(PortalId)]
+struct Projected;
+
+fn app(cx: Scope) -> impl IntoView {
+  view! { cx,
+    <PortalProvider>
+      <PortalInput id=Projected>"before"</PortalInput>
+      <div id="output">
+        <PortalOutput id=Projected />
+      </div>
+      <PortalInput id=Projected>"after"</PortalInput>
+    </PortalProvider>
+  }
+}
+
+#[cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+#[test]
+fn server_renders_content_declared_before_the_output() {
+  let html = leptos::ssr::render_to_string(|cx| app(cx).into_view(cx));
+
+  let output = html
+    .split("id=\"output\"")
+    .nth(1)
+    .expect("the output container is rendered");
+
+  assert!(output.contains("before"));
+  // Declared after the output, so only projected once hydrated
+  assert!(!output.contains("after"));
+
+  let fixture = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/portal_hydration.html"
+  );
+
+  if std::env::var_os("UPDATE_FIXTURES").is_some() {
+    std::fs::write(fixture, &html).unwrap();
+  }
+
+  assert_eq!(
+    html,
+    std::fs::read_to_string(fixture).unwrap(),
+    "the server markup changed, rerun with `UPDATE_FIXTURES=1` to update \
+     the fixture the browser half hydrates"
+  );
+}
+
+#[cfg(all(feature = "hydrate", target_arch = "wasm32"))]
+mod hydrate {
+  use super::app;
+  use leptos::*;
+  use wasm_bindgen::JsCast;
+  use wasm_bindgen_test::*;
+
+  wasm_bindgen_test_configure!(run_in_browser);
+
+  #[wasm_bindgen_test]
+  async fn hydrating_keeps_the_server_markup() {
+    let server = include_str!("fixtures/portal_hydration.html");
+
+    let root = document()
+      .create_element("div")
+      .unwrap()
+      .unchecked_into::<web_sys::HtmlElement>();
+
+    root.set_inner_html(server);
+    document().body().unwrap().append_child(&root).unwrap();
+
+    let output = || {
+      root
+        .query_selector("#output")
+        .unwrap()
+        .unwrap()
+        .text_content()
+        .unwrap_or_default()
+    };
+
+    let server_output = output();
+
+    leptos::hydrate(root.clone(), |cx| app(cx).into_view(cx));
+
+    // The server rendered content is claimed, not rendered again
+    assert_eq!(output(), server_output);
+
+    // Content declared after the output is projected once hydrated, which
+    // is checked once per frame
+    for _ in 0..10 {
+      if output().trim() == "beforeafter" {
+        break;
+      }
+
+      next_frame().await;
+    }
+
+    assert_eq!(output().trim(), "beforeafter");
+  }
+
+  /// Resolves on the next animation frame.
+  async fn next_frame() {
+    let frame = js_sys::Promise::new(&mut |resolve, _| {
+      request_animation_frame(move || {
+        let _ = resolve.call0(&wasm_bindgen::JsValue::UNDEFINED);
+      });
+    });
+
+    wasm_bindgen_futures::JsFuture::from(frame).await.unwrap();
+  }
+}